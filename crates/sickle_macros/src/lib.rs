@@ -107,6 +107,7 @@ pub fn event_handler_macro_derive(input: TokenStream) -> TokenStream {
         skip_enity_command,
         skip_ui_style_ext,
         skip_lockable_enum,
+        reflect_eq,
         animatable,
         target_enum,
         target_tupl,
@@ -31,6 +31,7 @@ struct StyleAttribute {
     skip_enity_command: bool,
     skip_ui_style_ext: bool,
     skip_lockable_enum: bool,
+    reflect_eq: bool,
     cmd_struct_name: String,
     cmd_struct_ident: Ident,
     target_attr_name: String,
@@ -55,6 +56,7 @@ impl StyleAttribute {
             skip_enity_command: false,
             skip_ui_style_ext: false,
             skip_lockable_enum: false,
+            reflect_eq: false,
             cmd_struct_name,
             cmd_struct_ident,
             target_attr_name,
@@ -185,6 +187,8 @@ fn parse_variant(variant: &Variant) -> Result<StyleAttribute, (proc_macro2::Span
                 attribute.skip_ui_style_ext = true;
             } else if attr.path().is_ident("skip_lockable_enum") {
                 attribute.skip_lockable_enum = true;
+            } else if attr.path().is_ident("reflect_eq") {
+                attribute.reflect_eq = true;
             } else if attr.path().is_ident("target_tupl") {
                 let token_stream = target_component(attr, ParseError::InvalidTargetTuplType)?;
                 attribute.target_tupl = Some(token_stream);
@@ -319,6 +323,8 @@ fn prepare_static_style_attribute(
         variants.clone().map(to_static_style_variant).collect();
     let eq_variants: Vec<proc_macro2::TokenStream> =
         variants.clone().map(to_eq_style_variant).collect();
+    let value_eq_variants: Vec<proc_macro2::TokenStream> =
+        variants.clone().map(to_value_eq_style_variant).collect();
     let apply_variants: Vec<proc_macro2::TokenStream> = variants
         .clone()
         .map(to_static_style_apply_variant)
@@ -343,6 +349,16 @@ fn prepare_static_style_attribute(
             }
         }
 
+        impl PartialEq for StaticStyleAttribute {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    #(#value_eq_variants)*
+                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    _ => false,
+                }
+            }
+        }
+
         impl StaticStyleAttribute {
             pub fn apply(&self, ui_style: &mut UiStyle) {
                 match self {
@@ -368,6 +384,8 @@ fn prepare_interactive_style_attribute(
         variants.clone().map(to_interactive_style_variant).collect();
     let eq_variants: Vec<proc_macro2::TokenStream> =
         variants.clone().map(to_eq_style_variant).collect();
+    let value_eq_variants: Vec<proc_macro2::TokenStream> =
+        variants.clone().map(to_value_eq_style_variant).collect();
     let apply_variants: Vec<proc_macro2::TokenStream> = variants
         .clone()
         .map(to_interactive_style_appl_variant)
@@ -394,6 +412,16 @@ fn prepare_interactive_style_attribute(
             }
         }
 
+        impl PartialEq for InteractiveStyleAttribute {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    #(#value_eq_variants)*
+                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    _ => false,
+                }
+            }
+        }
+
         impl InteractiveStyleAttribute {
             fn to_attribute(&self, flux_interaction: FluxInteraction) -> StaticStyleAttribute {
                 match self {
@@ -609,6 +637,18 @@ fn to_eq_style_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenSt
     }
 }
 
+fn to_value_eq_style_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    match style_attribute.reflect_eq {
+        true => quote! {
+            (Self::#ident(l0), Self::#ident(r0)) => l0.reflect_partial_eq(r0).unwrap_or(false),
+        },
+        false => quote! {
+            (Self::#ident(l0), Self::#ident(r0)) => l0 == r0,
+        },
+    }
+}
+
 fn to_eq_static_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let ident = &style_attribute.ident;
     quote! {
@@ -0,0 +1,340 @@
+use bevy::{
+    a11y::{
+        accesskit::{Action, ActionRequest as AccessKitActionRequest, NodeBuilder, Role, Toggled},
+        AccessibilityNode, ActionRequest, Focus,
+    },
+    prelude::*,
+};
+
+use crate::{
+    flux_interaction::{FluxInteraction, FluxInteractionUpdate},
+    theme::pseudo_state::{PseudoState, PseudoStates},
+};
+
+pub struct AccessibleWidgetPlugin;
+
+impl Plugin for AccessibleWidgetPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            AccessibleWidgetPreUpdate.before(FluxInteractionUpdate),
+        )
+        .add_systems(
+            Update,
+            (
+                reset_accessibility_clicks,
+                handle_accessibility_actions.run_if(resource_exists::<Events<ActionRequest>>),
+            )
+                .chain()
+                .in_set(AccessibleWidgetPreUpdate),
+        )
+        .add_systems(Last, update_accessibility_nodes);
+    }
+}
+
+/// Accessibility action requests are converted to interactions in this set.
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct AccessibleWidgetPreUpdate;
+
+/// Returns the entity targeted by an AccessKit action request.
+pub fn action_target(request: &AccessKitActionRequest) -> Option<Entity> {
+    Entity::try_from_bits(request.target.0).ok()
+}
+
+fn reset_accessibility_clicks(
+    mut q_clicked: Query<(Entity, &mut FluxInteraction, &mut AccessibilityClick)>,
+    mut commands: Commands,
+) {
+    for (entity, mut interaction, mut click) in &mut q_clicked {
+        match *click {
+            AccessibilityClick::Pressed => {
+                if *interaction == FluxInteraction::Pressed {
+                    *interaction = FluxInteraction::Released;
+                }
+                *click = AccessibilityClick::Released;
+            }
+            AccessibilityClick::Released => {
+                if *interaction == FluxInteraction::Released {
+                    *interaction = FluxInteraction::None;
+                }
+                commands.entity(entity).remove::<AccessibilityClick>();
+            }
+        }
+    }
+}
+
+fn handle_accessibility_actions(
+    mut reader: EventReader<ActionRequest>,
+    mut q_interactions: Query<(
+        &mut FluxInteraction,
+        &AccessibleWidget,
+        Option<&PseudoStates>,
+    )>,
+    focus: Option<ResMut<Focus>>,
+    mut commands: Commands,
+) {
+    let mut focus = focus;
+    for request in reader.read() {
+        let Some(entity) = action_target(request) else {
+            continue;
+        };
+
+        match request.action {
+            Action::Focus => {
+                if let Some(focus) = focus.as_mut() {
+                    focus.0 = Some(entity);
+                }
+            }
+            Action::Blur => {
+                if let Some(focus) = focus.as_mut() {
+                    if focus.0 == Some(entity) {
+                        focus.0 = None;
+                    }
+                }
+            }
+            Action::Default | Action::Expand | Action::Collapse => {
+                let Ok((mut interaction, widget, pseudo_states)) = q_interactions.get_mut(entity)
+                else {
+                    continue;
+                };
+
+                if *interaction == FluxInteraction::Disabled {
+                    continue;
+                }
+
+                let expanded = widget.expanded(pseudo_states);
+                if (request.action == Action::Expand && expanded == Some(true))
+                    || (request.action == Action::Collapse && expanded != Some(true))
+                {
+                    continue;
+                }
+
+                *interaction = FluxInteraction::Pressed;
+                commands.entity(entity).insert(AccessibilityClick::Pressed);
+            }
+            // Widget specific actions (e.g. increment) are handled by the widgets
+            _ => (),
+        }
+    }
+}
+
+type AccessibleWidgetNode<'a> = (
+    Entity,
+    &'a AccessibleWidget,
+    Option<&'a PseudoStates>,
+    Option<&'a mut AccessibilityNode>,
+);
+
+type AccessibleWidgetChanged = Or<(
+    Changed<AccessibleWidget>,
+    Changed<PseudoStates>,
+    Changed<AccessibilityNode>,
+)>;
+
+/// Runs in `Last`, after bevy_ui's button accessibility system. That system inserts its own
+/// node on buttons and renames it when their children change, so changed nodes are
+/// re-applied to keep the widget's role and name.
+fn update_accessibility_nodes(
+    mut q_widgets: Query<AccessibleWidgetNode, AccessibleWidgetChanged>,
+    mut commands: Commands,
+) {
+    for (entity, widget, pseudo_states, node) in &mut q_widgets {
+        match node {
+            Some(mut node) => widget.apply(&mut node, pseudo_states),
+            None => {
+                let mut node = NodeBuilder::new(widget.role);
+                widget.apply(&mut node, pseudo_states);
+                commands
+                    .entity(entity)
+                    .try_insert(AccessibilityNode::from(node));
+            }
+        }
+    }
+}
+
+/// Tracks entities clicked by an accessibility action. The click is played back as a
+/// press, a release and a reset to no interaction over consecutive frames.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+enum AccessibilityClick {
+    Pressed,
+    Released,
+}
+
+/// The numeric value of an accessible widget, e.g. a slider.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct AccessibleValue {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: Option<f32>,
+}
+
+/// Describes a widget to assistive technology.
+///
+/// Synced to the entity's [`AccessibilityNode`], along with the disabled, checked, selected
+/// and expanded states derived from the entity's [`PseudoStates`]. Accessibility clicks,
+/// expand and collapse requests are played back as a press and release of the entity.
+#[derive(Component, Clone, Debug)]
+pub struct AccessibleWidget {
+    pub role: Role,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The current value as text, e.g. the selected option of a combo box
+    pub text_value: Option<String>,
+    pub value: Option<AccessibleValue>,
+    pub actions: Vec<Action>,
+    /// Reports `PseudoState::Checked` as toggled for roles that aren't toggleable on their
+    /// own, e.g. a `Button` used as a toggle button
+    pub toggleable: bool,
+}
+
+impl AccessibleWidget {
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            name: None,
+            description: None,
+            text_value: None,
+            value: None,
+            actions: Vec::new(),
+            toggleable: false,
+        }
+    }
+
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        let name: String = name.into();
+        self.name = match name.is_empty() {
+            true => None,
+            false => Some(name),
+        };
+        self
+    }
+
+    pub fn with_value(mut self, value: AccessibleValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn with_actions(mut self, actions: impl Into<Vec<Action>>) -> Self {
+        self.actions = actions.into();
+        self
+    }
+
+    pub fn toggleable(mut self) -> Self {
+        self.toggleable = true;
+        self
+    }
+
+    fn is_toggleable(&self) -> bool {
+        self.toggleable
+            || matches!(
+                self.role,
+                Role::CheckBox
+                    | Role::RadioButton
+                    | Role::MenuItemCheckBox
+                    | Role::MenuItemRadio
+                    | Role::Switch
+            )
+    }
+
+    fn is_selectable(&self) -> bool {
+        matches!(
+            self.role,
+            Role::Tab | Role::TreeItem | Role::ListBoxOption | Role::RadioButton
+        )
+    }
+
+    fn is_expandable(&self) -> bool {
+        matches!(
+            self.role,
+            Role::ComboBox | Role::DisclosureTriangle | Role::TreeItem | Role::MenuItem
+        )
+    }
+
+    /// Expanded state of expandable roles. `Open` marks expanded widgets, `Folded` and
+    /// `Closed` collapsed ones. Disclosure triangles are expanded unless folded.
+    fn expanded(&self, pseudo_states: Option<&PseudoStates>) -> Option<bool> {
+        if !self.is_expandable() {
+            return None;
+        }
+
+        let has = |state: PseudoState| pseudo_states.is_some_and(|states| states.has(&state));
+        if has(PseudoState::Open) {
+            Some(true)
+        } else if has(PseudoState::Folded) || has(PseudoState::Closed) {
+            Some(false)
+        } else if self.role == Role::DisclosureTriangle {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    fn apply(&self, node: &mut NodeBuilder, pseudo_states: Option<&PseudoStates>) {
+        let has = |state: PseudoState| pseudo_states.is_some_and(|states| states.has(&state));
+
+        node.set_role(self.role);
+        match &self.name {
+            Some(name) => node.set_name(name.clone()),
+            None => node.clear_name(),
+        }
+        match &self.description {
+            Some(description) => node.set_description(description.clone()),
+            None => node.clear_description(),
+        }
+
+        match &self.text_value {
+            Some(value) => node.set_value(value.clone()),
+            None => node.clear_value(),
+        }
+
+        if let Some(value) = self.value {
+            node.set_numeric_value(value.value.into());
+            node.set_min_numeric_value(value.min.into());
+            node.set_max_numeric_value(value.max.into());
+            match value.step {
+                Some(step) => node.set_numeric_value_step(step.into()),
+                None => node.clear_numeric_value_step(),
+            }
+        } else {
+            node.clear_numeric_value();
+            node.clear_min_numeric_value();
+            node.clear_max_numeric_value();
+            node.clear_numeric_value_step();
+        }
+
+        if has(PseudoState::Disabled) {
+            node.set_disabled();
+        } else {
+            node.clear_disabled();
+        }
+
+        if self.is_toggleable() {
+            node.set_toggled(match has(PseudoState::Checked) {
+                true => Toggled::True,
+                false => Toggled::False,
+            });
+        }
+
+        if self.is_selectable() {
+            node.set_selected(has(PseudoState::Selected) || has(PseudoState::Checked));
+        }
+
+        match self.expanded(pseudo_states) {
+            Some(expanded) => node.set_expanded(expanded),
+            None => node.clear_expanded(),
+        }
+
+        node.add_action(Action::Focus);
+        if self.role != Role::StaticText {
+            node.add_action(Action::Default);
+        }
+        if self.is_expandable() {
+            node.add_action(Action::Expand);
+            node.add_action(Action::Collapse);
+        }
+        for action in &self.actions {
+            node.add_action(*action);
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
-use bevy::window::{CursorGrabMode, PrimaryWindow};
+use bevy::window::CursorGrabMode;
 
-use crate::flux_interaction::{FluxInteraction, FluxInteractionUpdate};
+use crate::{
+    flux_interaction::{FluxInteraction, FluxInteractionUpdate},
+    ui_utils::UiWindows,
+};
 
 pub struct DragInteractionPlugin;
 
@@ -69,22 +72,30 @@ fn is_windows_os() -> bool {
 }
 
 fn update_cursor_confinement_from_drag(
-    q_draggable: Query<&Draggable, Changed<Draggable>>,
-    mut q_window: Query<&mut Window, With<PrimaryWindow>>,
+    q_draggable: Query<(Entity, &Draggable), Changed<Draggable>>,
+    ui_windows: UiWindows,
+    mut q_window: Query<&mut Window>,
 ) {
-    let Ok(mut window) = q_window.get_single_mut() else {
-        return;
-    };
-
-    if let Some(_) = q_draggable
-        .iter()
-        .find(|&draggable| draggable.state == DragState::DragStart)
-    {
-        window.cursor.grab_mode = CursorGrabMode::Confined;
-    } else if let Some(_) = q_draggable.iter().find(|&draggable| {
-        draggable.state == DragState::DragEnd || draggable.state == DragState::DragCanceled
-    }) {
-        window.cursor.grab_mode = CursorGrabMode::None;
+    // With multiple windows, the cursor must be free to drag nodes over to another window
+    let confine = q_window.iter().count() == 1;
+
+    for (entity, draggable) in &q_draggable {
+        let grab_mode = match draggable.state {
+            DragState::DragStart if confine => CursorGrabMode::Confined,
+            DragState::DragEnd | DragState::DragCanceled => CursorGrabMode::None,
+            _ => continue,
+        };
+
+        let Some(window) = ui_windows.window_of(entity) else {
+            continue;
+        };
+        let Ok(mut window) = q_window.get_mut(window) else {
+            continue;
+        };
+
+        if window.cursor.grab_mode != grab_mode {
+            window.cursor.grab_mode = grab_mode;
+        }
     }
 }
 
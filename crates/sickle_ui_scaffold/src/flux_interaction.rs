@@ -220,13 +220,34 @@ fn tick_flux_interaction_stopwatch(
     }
 }
 
+type FluxInteractionNode<'a> = (
+    &'a PrevInteraction,
+    &'a Interaction,
+    &'a mut FluxInteraction,
+    Option<&'a Node>,
+    Option<&'a GlobalTransform>,
+);
+
 fn update_flux_interaction(
-    mut q_interaction: Query<
-        (&PrevInteraction, &Interaction, &mut FluxInteraction),
-        Changed<Interaction>,
-    >,
+    r_touches: Option<Res<Touches>>,
+    r_ui_scale: Option<Res<UiScale>>,
+    mut q_interaction: Query<FluxInteractionNode, Changed<Interaction>>,
 ) {
-    for (prev, curr, mut flux) in &mut q_interaction {
+    let ui_scale = r_ui_scale.map_or(1., |ui_scale| ui_scale.0);
+
+    // Lifting a finger leaves no pointer behind, so taps are resolved from the released touches
+    let released_touch_within = |node: Option<&Node>, transform: Option<&GlobalTransform>| {
+        let (Some(touches), Some(node), Some(transform)) = (&r_touches, node, transform) else {
+            return false;
+        };
+
+        let rect = Rect::from_center_size(transform.translation().truncate(), node.size());
+        touches
+            .iter_just_released()
+            .any(|touch| rect.contains(touch.position() / ui_scale))
+    };
+
+    for (prev, curr, mut flux, node, transform) in &mut q_interaction {
         if *flux == FluxInteraction::Disabled {
             continue;
         }
@@ -240,7 +261,10 @@ fn update_flux_interaction(
         } else if *prev == PrevInteraction::Hovered && *curr == Interaction::None {
             *flux = FluxInteraction::PointerLeave;
         } else if *prev == PrevInteraction::Pressed && *curr == Interaction::None {
-            *flux = FluxInteraction::PressCanceled;
+            *flux = match released_touch_within(node, transform) {
+                true => FluxInteraction::Released,
+                false => FluxInteraction::PressCanceled,
+            };
         } else if *prev == PrevInteraction::Pressed && *curr == Interaction::Hovered {
             *flux = FluxInteraction::Released;
         }
@@ -1,3 +1,4 @@
+pub mod accessibility;
 pub mod drag_interaction;
 pub mod drop_interaction;
 pub mod flux_interaction;
@@ -13,6 +14,7 @@ use serde::{Deserialize, Serialize};
 
 pub mod prelude {
     pub use super::{
+        accessibility::{AccessibleValue, AccessibleWidget, AccessibleWidgetPreUpdate},
         drag_interaction::{DragState, Draggable, DraggableUpdate},
         drop_interaction::{DropPhase, DropZone, Droppable, DroppableUpdate},
         flux_interaction::{
@@ -24,7 +26,7 @@ pub mod prelude {
         ui_builder::{UiBuilder, UiBuilderExt, UiContextRoot, UiRoot},
         ui_commands::ManagePseudoStateExt,
         ui_style::prelude::*,
-        ui_utils::UiUtils,
+        ui_utils::{UiUtils, UiWindows},
         CardinalDirection,
     };
 }
@@ -30,14 +30,15 @@ fn should_update_resize_handle_cursor(
 }
 
 fn update_cursor_on_resize_handles(
-    q_flux: Query<(&ResizeHandle, &FluxInteraction)>,
+    q_flux: Query<(Entity, &ResizeHandle, &FluxInteraction)>,
     mut locked: Local<bool>,
     mut commands: Commands,
 ) {
     let mut new_cursor: Option<CursorIcon> = None;
+    let mut cursor_node: Option<Entity> = None;
     let multiple_active = q_flux
         .iter()
-        .filter(|(_, flux)| {
+        .filter(|(_, _, flux)| {
             (**flux == FluxInteraction::PointerEnter && !*locked)
                 || **flux == FluxInteraction::Pressed
         })
@@ -46,7 +47,8 @@ fn update_cursor_on_resize_handles(
 
     let omni_cursor = CursorIcon::Move;
 
-    for (handle, flux) in &q_flux {
+    for (entity, handle, flux) in &q_flux {
+        let previous_cursor = new_cursor;
         match *flux {
             FluxInteraction::PointerEnter => {
                 if !*locked {
@@ -82,11 +84,17 @@ fn update_cursor_on_resize_handles(
             }
             _ => (),
         }
-    }
 
-    if let Some(new_cursor) = new_cursor {
-        commands.set_cursor(new_cursor);
+        if new_cursor != previous_cursor {
+            cursor_node = entity.into();
+        }
     }
+
+    let (Some(new_cursor), Some(cursor_node)) = (new_cursor, cursor_node) else {
+        return;
+    };
+
+    commands.entity(cursor_node).set_cursor(new_cursor);
 }
 
 #[derive(Component, Debug, Default, Reflect)]
@@ -3,8 +3,10 @@ pub mod dynamic_style_attribute;
 pub mod icons;
 pub mod pseudo_state;
 pub mod style_animation;
+pub mod theme_bundle;
 pub mod theme_colors;
 pub mod theme_data;
+pub mod theme_inspection;
 pub mod theme_spacing;
 pub mod typography;
 
@@ -17,15 +19,20 @@ use std::{
 use bevy::{prelude::*, ui::UiSystem};
 use dynamic_style::{DynamicStyle, DynamicStylePlugin};
 use pseudo_state::{AutoPseudoStatePlugin, PseudoState, PseudoStates};
+use theme_bundle::{
+    apply_active_theme_bundle, loader::ThemeBundleLoader, ActiveThemeBundle, ThemeBundle,
+};
 use theme_colors::{loader::ThemeColorsLoader, ThemeColors};
-use theme_data::ThemeData;
+use theme_data::{detect_theme_data_changes, ThemeData, ThemeDataChanges, ThemeDataSections};
+use theme_inspection::ThemeInspection;
 
 use crate::{prelude::UiBuilder, ui_commands::RefreshThemeExt, ui_style::builder::StyleBuilder};
 
 pub mod prelude {
     pub use super::{
         dynamic_style::{
-            ContextStyleAttribute, DynamicStyle, DynamicStyleEnterState, DynamicStylePostUpdate,
+            AppliedDynamicStyle, ContextStyleAttribute, DynamicStyle, DynamicStyleEnterState,
+            DynamicStylePostUpdate,
         },
         dynamic_style_attribute::{DynamicStyleAttribute, DynamicStyleController},
         icons::IconData,
@@ -37,11 +44,15 @@ pub mod prelude {
             AnimationLoop, AnimationSettings, AnimationState, InteractionStyle,
             LoopedAnimationConfig,
         },
+        theme_bundle::{ActiveThemeBundle, ThemeBundle, ThemeBundleError},
         theme_colors::{Accent, Container, On, Surface},
-        theme_data::{Contrast, Scheme, ThemeData},
+        theme_data::{Contrast, Scheme, ThemeData, ThemeDataChanges, ThemeDataSections},
+        theme_inspection::{
+            AttributeInspection, PseudoThemeInspection, StyleSource, ThemeInspection,
+        },
         typography::{FontScale, FontStyle, FontType, SizedFont},
         ComponentThemePlugin, CustomThemeUpdate, DefaultTheme, DynamicStyleBuilder, PseudoTheme,
-        Theme, ThemeUpdate, UiContext,
+        Theme, ThemeRegistry, ThemeUpdate, UiContext,
     };
 }
 
@@ -54,10 +65,22 @@ impl Plugin for ThemePlugin {
             (ThemeUpdate, CustomThemeUpdate.after(ThemeUpdate)).before(UiSystem::Layout),
         )
         .init_resource::<ThemeData>()
+        .init_resource::<ThemeDataChanges>()
         .init_resource::<ThemeRegistry>()
         .init_asset::<ThemeColors>()
         .init_asset_loader::<ThemeColorsLoader>()
-        .add_plugins((AutoPseudoStatePlugin, DynamicStylePlugin));
+        .init_asset::<ThemeBundle>()
+        .init_asset_loader::<ThemeBundleLoader>()
+        .add_plugins((AutoPseudoStatePlugin, DynamicStylePlugin))
+        .add_systems(
+            PostUpdate,
+            (
+                apply_active_theme_bundle.run_if(resource_exists::<ActiveThemeBundle>),
+                detect_theme_data_changes.run_if(resource_changed::<ThemeData>),
+            )
+                .chain()
+                .before(ThemeUpdate),
+        );
     }
 }
 
@@ -90,10 +113,21 @@ impl<C> From<DynamicStyle> for DynamicStyleBuilder<C> {
     }
 }
 
+impl<C> DynamicStyleBuilder<C> {
+    /// The [`ThemeData`] sections the builder may read, unless declared otherwise.
+    pub fn default_sections(&self) -> ThemeDataSections {
+        match self {
+            DynamicStyleBuilder::Static(_) => ThemeDataSections::NONE,
+            _ => ThemeDataSections::ALL,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PseudoTheme<C> {
     state: Option<Vec<PseudoState>>,
     builder: DynamicStyleBuilder<C>,
+    sections: ThemeDataSections,
 }
 
 impl<C> PseudoTheme<C> {
@@ -101,9 +135,11 @@ impl<C> PseudoTheme<C> {
         state: impl Into<Option<Vec<PseudoState>>>,
         theme: impl Into<DynamicStyleBuilder<C>>,
     ) -> Self {
+        let builder: DynamicStyleBuilder<C> = theme.into();
         Self {
             state: state.into(),
-            builder: theme.into(),
+            sections: builder.default_sections(),
+            builder,
         }
     }
 
@@ -115,6 +151,24 @@ impl<C> PseudoTheme<C> {
         &self.builder
     }
 
+    pub fn sections(&self) -> ThemeDataSections {
+        self.sections
+    }
+
+    /// Declares the [`ThemeData`] sections the builder reads.
+    ///
+    /// Deferred builders are assumed to read all sections by default. Narrowing the
+    /// list allows skipping the refresh of the theme when unrelated sections change.
+    ///
+    /// ```rust,ignore
+    /// PseudoTheme::deferred(None, MyWidget::primary_style)
+    ///     .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
+    /// ```
+    pub fn depends_on(mut self, sections: ThemeDataSections) -> Self {
+        self.sections = sections;
+        self
+    }
+
     pub fn build(
         state: impl Into<Option<Vec<PseudoState>>>,
         builder: fn(&mut StyleBuilder),
@@ -125,6 +179,7 @@ impl<C> PseudoTheme<C> {
         Self {
             state: state.into(),
             builder: style_builder.into(),
+            sections: ThemeDataSections::NONE,
         }
     }
 
@@ -135,6 +190,7 @@ impl<C> PseudoTheme<C> {
         Self {
             state: state.into(),
             builder: DynamicStyleBuilder::StyleBuilder(builder),
+            sections: ThemeDataSections::ALL,
         }
     }
 
@@ -145,6 +201,7 @@ impl<C> PseudoTheme<C> {
         Self {
             state: state.into(),
             builder: DynamicStyleBuilder::ContextStyleBuilder(builder),
+            sections: ThemeDataSections::ALL,
         }
     }
 
@@ -155,6 +212,7 @@ impl<C> PseudoTheme<C> {
         Self {
             state: state.into(),
             builder: DynamicStyleBuilder::WorldStyleBuilder(builder),
+            sections: ThemeDataSections::ALL,
         }
     }
 
@@ -172,9 +230,44 @@ impl<C> PseudoTheme<C> {
         Self {
             state: state.into(),
             builder: DynamicStyleBuilder::InfoWorldStyleBuilder(builder),
+            sections: ThemeDataSections::ALL,
         }
     }
 
+    /// Runs the deferred builder of the pseudo theme into `style_builder`.
+    ///
+    /// Returns the prebuilt style instead if the pseudo theme is static.
+    pub fn build_into<'a>(
+        &'a self,
+        style_builder: &mut StyleBuilder,
+        source_entity: Option<Entity>,
+        entity: Entity,
+        context: &C,
+        theme_data: &ThemeData,
+        world: &World,
+    ) -> Option<&'a DynamicStyle> {
+        match &self.builder {
+            DynamicStyleBuilder::Static(style) => return Some(style),
+            DynamicStyleBuilder::StyleBuilder(builder) => builder(style_builder, theme_data),
+            DynamicStyleBuilder::ContextStyleBuilder(builder) => {
+                builder(style_builder, context, theme_data)
+            }
+            DynamicStyleBuilder::WorldStyleBuilder(builder) => {
+                builder(style_builder, entity, context, world)
+            }
+            DynamicStyleBuilder::InfoWorldStyleBuilder(builder) => builder(
+                style_builder,
+                source_entity,
+                &self.state,
+                entity,
+                context,
+                world,
+            ),
+        };
+
+        None
+    }
+
     pub fn is_base_theme(&self) -> bool {
         match &self.state {
             Some(list) => list.is_empty(),
@@ -256,6 +349,79 @@ where
         &self.pseudo_themes
     }
 
+    /// The combined [`ThemeData`] sections read by the pseudo themes.
+    pub fn sections(&self) -> ThemeDataSections {
+        self.pseudo_themes
+            .iter()
+            .fold(ThemeDataSections::NONE, |acc, pt| acc | pt.sections())
+    }
+
+    /// Collects the themes that apply to `entity` in order of application:
+    /// the default theme, then ancestor themes from the root down, then the entity's own theme.
+    pub fn collect_for<'a>(
+        entity: Entity,
+        world: &'a World,
+        default_theme: &'a Option<Theme<C>>,
+    ) -> Vec<(&'a Theme<C>, Option<Entity>)> {
+        // Default -> General (App-wide) -> Specialized (Screen) theming is a reasonable guess.
+        // Round to 4, which is the first growth step.
+        // TODO: Cache most common theme count in theme data.
+        let mut themes: Vec<(&Theme<C>, Option<Entity>)> = Vec::with_capacity(4);
+        // Add own theme
+        if let Some(own_theme) = world.get::<Theme<C>>(entity) {
+            themes.push((own_theme, Some(entity)));
+        }
+
+        // Add all ancestor themes
+        let mut current_ancestor = entity;
+        while let Some(parent) = world.get::<Parent>(current_ancestor) {
+            current_ancestor = parent.get();
+            if let Some(ancestor_theme) = world.get::<Theme<C>>(current_ancestor) {
+                themes.push((ancestor_theme, Some(current_ancestor)));
+            }
+        }
+
+        if let Some(default_theme) = default_theme {
+            themes.push((default_theme, None));
+        }
+
+        // The list contains themes in reverse order of application
+        themes.reverse();
+        themes
+    }
+
+    /// Selects the pseudo themes of `themes` that apply to an entity with `pseudo_states`,
+    /// in order of application.
+    pub fn matching_pseudo_themes<'a>(
+        themes: &[(&'a Theme<C>, Option<Entity>)],
+        pseudo_states: &Vec<PseudoState>,
+    ) -> Vec<(&'a PseudoTheme<C>, Option<Entity>)> {
+        // Assuming we have a base style and two-three pseudo state style is a reasonable guess.
+        // TODO: Cache most common pseudo theme count in theme data.
+        let mut pseudo_themes: Vec<(&PseudoTheme<C>, Option<Entity>)> =
+            Vec::with_capacity(themes.len() * 4);
+
+        for (theme, source_entity) in themes {
+            if let Some(base_theme) = theme.pseudo_themes().iter().find(|pt| pt.is_base_theme()) {
+                pseudo_themes.push((base_theme, *source_entity));
+            }
+        }
+
+        if pseudo_states.len() > 0 {
+            for i in 0..pseudo_states.len() {
+                for (theme, source_entity) in themes {
+                    theme
+                        .pseudo_themes()
+                        .iter()
+                        .filter(|pt| pt.count_match(pseudo_states) == i + 1)
+                        .for_each(|pt| pseudo_themes.push((pt, *source_entity)));
+                }
+            }
+        }
+
+        pseudo_themes
+    }
+
     pub fn post_update() -> impl IntoSystemConfigs<()> {
         Theme::<C>::post_update_in(ThemeUpdate)
     }
@@ -276,13 +442,26 @@ where
         q_targets: Query<Entity, With<C>>,
         q_added_targets: Query<Entity, Added<C>>,
         q_removed_themes: RemovedComponents<Theme<C>>,
-        q_changed_themes: Query<Entity, Changed<Theme<C>>>,
+        q_themes: Query<Ref<Theme<C>>>,
         theme_data: Res<ThemeData>,
+        theme_data_changes: Res<ThemeDataChanges>,
         mut commands: Commands,
     ) {
-        if theme_data.is_changed()
+        let theme_data_affects_themes = theme_data.is_changed() && {
+            let changed_sections = theme_data_changes.get();
+            let default_sections = C::default_theme()
+                .map(|theme| theme.sections())
+                .unwrap_or_default();
+
+            default_sections.intersects(changed_sections)
+                || q_themes
+                    .iter()
+                    .any(|theme| theme.sections().intersects(changed_sections))
+        };
+
+        if theme_data_affects_themes
             || q_removed_themes.len() > 0
-            || q_changed_themes.iter().count() > 0
+            || q_themes.iter().any(|theme| theme.is_changed())
         {
             for entity in &q_targets {
                 commands.entity(entity).refresh_theme::<C>();
@@ -332,21 +511,25 @@ impl InsertThemedComponentExt for UiBuilder<'_, Entity> {
 
 /// Tracks all the themes that have been registered with [`ComponentThemePlugin`].
 ///
-/// This can be used to check if a theme's plugin is missing.
+/// This can be used to check if a theme's plugin is missing, or to inspect how the
+/// registered themes resolve for an entity.
 #[derive(Resource, Default, Debug)]
 pub struct ThemeRegistry {
     themes: HashSet<TypeId>,
+    inspectors: Vec<fn(Entity, &World) -> Option<ThemeInspection>>,
 }
 
 impl ThemeRegistry {
-    fn new_with<C: 'static>() -> Self {
+    fn new_with<C: DefaultTheme>() -> Self {
         let mut registry = Self::default();
         registry.add::<C>();
         registry
     }
 
-    fn add<C: 'static>(&mut self) {
-        self.themes.insert(TypeId::of::<C>());
+    fn add<C: DefaultTheme>(&mut self) {
+        if self.themes.insert(TypeId::of::<C>()) {
+            self.inspectors.push(ThemeInspection::inspect::<C>);
+        }
     }
 
     /// Returns `true` if the theme `C` has been registered.
@@ -358,6 +541,14 @@ impl ThemeRegistry {
     pub fn contains_by_id(&self, id: TypeId) -> bool {
         self.themes.contains(&id)
     }
+
+    /// Inspects all registered themes that apply to `entity`.
+    pub fn inspect(&self, entity: Entity, world: &World) -> Vec<ThemeInspection> {
+        self.inspectors
+            .iter()
+            .filter_map(|inspector| inspector(entity, world))
+            .collect()
+    }
 }
 
 #[derive(Default)]
@@ -73,7 +73,7 @@ fn update_dynamic_style_on_flux_change(
         (
             Entity,
             Ref<DynamicStyle>,
-            &FluxInteraction,
+            Ref<FluxInteraction>,
             Option<&mut DynamicStyleStopwatch>,
         ),
         Or<(Changed<DynamicStyle>, Changed<FluxInteraction>)>,
@@ -81,12 +81,18 @@ fn update_dynamic_style_on_flux_change(
     mut commands: Commands,
 ) {
     for (entity, style, interaction, stopwatch) in &mut q_styles {
+        let interaction_changed = interaction.is_changed();
+        let interaction = interaction.into_inner();
         let mut lock_needed = StopwatchLock::None;
         let mut keep_stop_watch = false;
 
         for context_attribute in &style.attributes {
             match &context_attribute.attribute {
                 DynamicStyleAttribute::Interactive(style) => {
+                    if context_attribute.applied && !interaction_changed {
+                        continue;
+                    }
+
                     let target = match context_attribute.target {
                         Some(context) => context,
                         None => entity,
@@ -176,7 +182,7 @@ fn update_dynamic_style_on_stopwatch_change(
                     controller.update(interaction, stopwatch.0.elapsed_secs());
                 }
 
-                if style_changed || controller.dirty() {
+                if (style_changed && !context_attribute.applied) || controller.dirty() {
                     let target = match context_attribute.target {
                         Some(context) => context,
                         None => entity,
@@ -262,6 +268,8 @@ impl DynamicStyleEnterState {
 pub struct ContextStyleAttribute {
     target: Option<Entity>,
     attribute: DynamicStyleAttribute,
+    /// Set when the same attribute was already applied by a previous theme refresh.
+    applied: bool,
 }
 
 impl LogicalEq for ContextStyleAttribute {
@@ -270,11 +278,53 @@ impl LogicalEq for ContextStyleAttribute {
     }
 }
 
+impl PartialEq for ContextStyleAttribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target && self.attribute == other.attribute
+    }
+}
+
 impl ContextStyleAttribute {
     pub fn new(context: impl Into<Option<Entity>>, attribute: DynamicStyleAttribute) -> Self {
         Self {
             target: context.into(),
             attribute,
+            applied: false,
+        }
+    }
+
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    pub fn attribute(&self) -> &DynamicStyleAttribute {
+        &self.attribute
+    }
+}
+
+/// The complete list of attributes placed on an entity by the last theme refresh.
+///
+/// Static attributes are removed from the [`DynamicStyle`] once applied, so this copy is
+/// kept to diff subsequent refreshes against. Only attributes that differ are re-applied.
+///
+/// As a consequence, a static attribute changed in code after it was applied keeps its
+/// value until its themed value changes. Removing this component forces the next refresh
+/// to apply every attribute again.
+#[derive(Component, Clone, Debug, Default)]
+pub struct AppliedDynamicStyle {
+    attributes: Vec<ContextStyleAttribute>,
+}
+
+impl AppliedDynamicStyle {
+    pub fn attributes(&self) -> &Vec<ContextStyleAttribute> {
+        &self.attributes
+    }
+}
+
+impl From<&DynamicStyle> for AppliedDynamicStyle {
+    fn from(value: &DynamicStyle) -> Self {
+        Self {
+            attributes: value.attributes.clone(),
         }
     }
 }
@@ -294,10 +344,7 @@ impl DynamicStyle {
         Self {
             attributes: attributes
                 .iter()
-                .map(|attribute| ContextStyleAttribute {
-                    target: None,
-                    attribute: attribute.clone(),
-                })
+                .map(|attribute| ContextStyleAttribute::new(None, attribute.clone()))
                 .collect(),
             enter_completed: false,
         }
@@ -381,6 +428,41 @@ impl DynamicStyle {
         }
     }
 
+    /// Diffs the style against the attributes of a previous refresh.
+    ///
+    /// Returns `true` if the style is unchanged and does not need to be re-inserted.
+    /// Otherwise static attributes that are already in place are dropped and live
+    /// (interactive or animated) attributes that are unchanged are flagged, so only the
+    /// attributes that differ get applied.
+    pub fn diff_from(&mut self, applied: &AppliedDynamicStyle) -> bool {
+        let unchanged = self.attributes.len() == applied.attributes.len()
+            && self
+                .attributes
+                .iter()
+                .all(|csa| applied.attributes.contains(csa));
+
+        if unchanged {
+            return true;
+        }
+
+        self.attributes
+            .retain(|csa| !(csa.attribute.is_static() && applied.attributes.contains(csa)));
+
+        for context_attribute in self.attributes.iter_mut() {
+            context_attribute.applied = applied.attributes.contains(context_attribute);
+        }
+
+        false
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    pub fn attributes(&self) -> &Vec<ContextStyleAttribute> {
+        &self.attributes
+    }
+
     pub fn is_interactive(&self) -> bool {
         self.attributes
             .iter()
@@ -400,3 +482,79 @@ impl DynamicStyle {
         self.attributes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui_style::generated::{InteractiveStyleAttribute, StaticStyleAttribute};
+
+    use super::*;
+
+    fn width(px: f32) -> DynamicStyleAttribute {
+        DynamicStyleAttribute::Static(StaticStyleAttribute::Width(Val::Px(px)))
+    }
+
+    fn height(px: f32) -> DynamicStyleAttribute {
+        DynamicStyleAttribute::Static(StaticStyleAttribute::Height(Val::Px(px)))
+    }
+
+    fn background(color: Color) -> DynamicStyleAttribute {
+        DynamicStyleAttribute::Interactive(InteractiveStyleAttribute::BackgroundColor(color.into()))
+    }
+
+    #[test]
+    fn diff_from_unchanged_style_keeps_it_untouched() {
+        let applied = AppliedDynamicStyle::from(&DynamicStyle::new(vec![
+            width(10.),
+            background(Color::WHITE),
+        ]));
+        let mut style = DynamicStyle::new(vec![width(10.), background(Color::WHITE)]);
+
+        assert!(style.diff_from(&applied));
+        assert_eq!(style.attributes().len(), 2);
+    }
+
+    #[test]
+    fn diff_from_drops_static_attributes_already_in_place() {
+        let applied = AppliedDynamicStyle::from(&DynamicStyle::new(vec![width(10.), height(5.)]));
+        let mut style = DynamicStyle::new(vec![width(20.), height(5.)]);
+
+        assert!(!style.diff_from(&applied));
+        assert_eq!(
+            style.attributes(),
+            &vec![ContextStyleAttribute::new(None, width(20.))]
+        );
+    }
+
+    #[test]
+    fn diff_from_flags_unchanged_live_attributes_as_applied() {
+        let applied = AppliedDynamicStyle::from(&DynamicStyle::new(vec![
+            width(10.),
+            background(Color::WHITE),
+            background(Color::BLACK),
+        ]));
+        let mut style = DynamicStyle::new(vec![
+            width(20.),
+            background(Color::WHITE),
+            DynamicStyleAttribute::Interactive(InteractiveStyleAttribute::BorderColor(
+                Color::BLACK.into(),
+            )),
+        ]);
+
+        assert!(!style.diff_from(&applied));
+
+        let applied_flags: Vec<bool> = style.attributes().iter().map(|csa| csa.applied).collect();
+        assert_eq!(applied_flags, vec![false, true, false]);
+    }
+
+    #[test]
+    fn diff_from_compares_attribute_targets() {
+        let target = Entity::from_raw(42);
+        let applied = AppliedDynamicStyle {
+            attributes: vec![ContextStyleAttribute::new(target, width(10.))],
+        };
+        let mut style = DynamicStyle::copy_from(vec![ContextStyleAttribute::new(None, width(10.))]);
+
+        assert!(!style.diff_from(&applied));
+        assert_eq!(style.attributes().len(), 1);
+    }
+}
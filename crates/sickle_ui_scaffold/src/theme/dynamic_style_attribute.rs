@@ -76,6 +76,27 @@ impl LogicalEq for DynamicStyleAttribute {
     }
 }
 
+impl PartialEq for DynamicStyleAttribute {
+    /// Compares the styling values of the attributes. The state of animation controllers is ignored.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Static(l0), Self::Static(r0)) => l0 == r0,
+            (Self::Interactive(l0), Self::Interactive(r0)) => l0 == r0,
+            (
+                Self::Animated {
+                    attribute: l_attribute,
+                    controller: l_controller,
+                },
+                Self::Animated {
+                    attribute: r_attribute,
+                    controller: r_controller,
+                },
+            ) => l_attribute == r_attribute && l_controller.animation == r_controller.animation,
+            _ => false,
+        }
+    }
+}
+
 impl DynamicStyleAttribute {
     pub fn is_static(&self) -> bool {
         match self {
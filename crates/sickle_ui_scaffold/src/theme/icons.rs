@@ -1,17 +1,35 @@
 use std::char;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Default, Reflect)]
+use super::theme_colors::serialize_color;
+
+#[derive(Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
 pub enum IconData {
     #[default]
     None,
-    Image(String, Color),
-    FontCodepoint(String, char, Color, f32),
+    Image(String, #[serde(with = "serialize_color")] Color),
+    FontCodepoint(String, char, #[serde(with = "serialize_color")] Color, f32),
     // TODO: add texture atlas config
 }
 
 impl IconData {
+    pub const MATERIAL_ICONS_FONT: &'static str =
+        "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf";
+
+    /// An icon from the bundled MaterialIcons font.
+    ///
+    /// The available codepoints are listed in `MaterialIcons-Regular.codepoints`.
+    pub fn material(codepoint: char) -> Self {
+        Self::FontCodepoint(
+            IconData::MATERIAL_ICONS_FONT.into(),
+            codepoint,
+            Color::WHITE,
+            12.,
+        )
+    }
+
     pub fn is_none(&self) -> bool {
         matches!(self, Self::None)
     }
@@ -55,13 +73,14 @@ impl IconData {
     }
 }
 
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct CustomIconData {
     pub name: String,
     pub data: IconData,
 }
 
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Reflect, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Icons {
     pub arrow_right: IconData,
     pub checkmark: IconData,
@@ -71,10 +90,14 @@ pub struct Icons {
     pub exit_to_app: IconData,
     pub expand_less: IconData,
     pub expand_more: IconData,
+    pub maximize: IconData,
+    pub minimize: IconData,
+    pub new_window: IconData,
     pub open_in_new: IconData,
     pub radio_button_checked: IconData,
     pub radio_button_unchecked: IconData,
     pub redo: IconData,
+    pub restore: IconData,
     pub submenu: IconData,
     pub undo: IconData,
     pub custom: Vec<CustomIconData>,
@@ -132,6 +155,24 @@ impl Default for Icons {
                 Color::WHITE,
                 12.,
             ),
+            maximize: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{E3C6}',
+                Color::WHITE,
+                12.,
+            ),
+            minimize: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{E931}',
+                Color::WHITE,
+                12.,
+            ),
+            new_window: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{F088}',
+                Color::WHITE,
+                12.,
+            ),
             open_in_new: IconData::FontCodepoint(
                 "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
                 '\u{E89E}',
@@ -151,6 +192,12 @@ impl Default for Icons {
                 12.,
             ),
             redo: IconData::Image("".into(), Color::WHITE),
+            restore: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{E3E0}',
+                Color::WHITE,
+                12.,
+            ),
             submenu: IconData::Image("".into(), Color::WHITE),
             undo: IconData::Image("".into(), Color::WHITE),
             custom: Vec::new(),
@@ -236,6 +236,11 @@ pub enum PseudoState {
     Error,
     Resizable(CardinalDirection),
     Custom(String),
+    Minimized,
+    Maximized,
+    Pinned,
+    Modified,
+    Focused,
 }
 
 #[derive(Component, Clone, Debug, Default, Reflect)]
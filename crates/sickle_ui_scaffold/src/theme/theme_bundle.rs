@@ -0,0 +1,625 @@
+use std::fmt;
+
+use bevy::{
+    prelude::*,
+    reflect::{ReflectRef, Struct},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{
+    icons::{IconData, Icons},
+    style_animation::AnimationSettings,
+    theme_colors::ThemeColors,
+    theme_data::ThemeData,
+    theme_spacing::ThemeSpacing,
+    typography::ThemeTypography,
+};
+
+pub mod loader {
+    use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+
+    use super::{ThemeBundle, ThemeBundleError};
+
+    #[derive(Default)]
+    pub(crate) struct ThemeBundleLoader;
+
+    impl AssetLoader for ThemeBundleLoader {
+        type Asset = ThemeBundle;
+        type Settings = ();
+        type Error = ThemeBundleError;
+
+        async fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader<'_>,
+            _settings: &'a Self::Settings,
+            load_context: &'a mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+
+            let mut theme_bundle = ThemeBundle::from_slice(&bytes)?;
+            theme_bundle.validate()?;
+
+            theme_bundle.fonts = theme_bundle
+                .font_paths()
+                .iter()
+                .map(|path| load_context.load(path))
+                .collect();
+            theme_bundle.images = theme_bundle
+                .image_paths()
+                .iter()
+                .map(|path| load_context.load(path))
+                .collect();
+
+            Ok(theme_bundle)
+        }
+    }
+}
+
+/// Error of loading a [`ThemeBundle`].
+#[derive(Debug)]
+pub enum ThemeBundleError {
+    Io(std::io::Error),
+    /// The bundle is not a JSON object.
+    Parse(serde_json::Error),
+    /// A field of the bundle could not be deserialized or has an invalid value.
+    InvalidField {
+        field: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ThemeBundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeBundleError::Io(err) => write!(f, "Failed to read theme bundle: {}", err),
+            ThemeBundleError::Parse(err) => write!(f, "Failed to parse theme bundle: {}", err),
+            ThemeBundleError::InvalidField { field, reason } => {
+                write!(f, "Invalid theme bundle field `{}`: {}", field, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeBundleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeBundleError::Io(err) => Some(err),
+            ThemeBundleError::Parse(err) => Some(err),
+            ThemeBundleError::InvalidField { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ThemeBundleError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for ThemeBundleError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Parse(value)
+    }
+}
+
+impl ThemeBundleError {
+    fn invalid(field: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidField {
+            field: field.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// All [`ThemeData`] sections, except the active scheme, as a single asset.
+///
+/// Sections missing from the file fall back to their defaults, as do the top level fields of
+/// `spacing` and `text`. Colors follow the same
+/// Material3 format as [`ThemeColors`]. Font and icon paths are loaded as dependencies
+/// of the bundle, so the bundle is only loaded with dependencies once they are available.
+#[derive(Asset, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeBundle {
+    pub colors: ThemeColors,
+    pub spacing: ThemeSpacing,
+    pub text: ThemeTypography,
+    pub icons: Icons,
+    pub interaction_animation: AnimationSettings,
+    pub delayed_interaction_animation: AnimationSettings,
+    pub enter_animation: AnimationSettings,
+    #[dependency]
+    #[reflect(ignore)]
+    #[serde(skip)]
+    fonts: Vec<Handle<Font>>,
+    #[dependency]
+    #[reflect(ignore)]
+    #[serde(skip)]
+    images: Vec<Handle<Image>>,
+}
+
+impl Default for ThemeBundle {
+    fn default() -> Self {
+        ThemeData::default().into()
+    }
+}
+
+impl From<&ThemeData> for ThemeBundle {
+    fn from(value: &ThemeData) -> Self {
+        Self {
+            colors: value.colors.clone(),
+            spacing: value.spacing,
+            text: value.text.clone(),
+            icons: value.icons.clone(),
+            interaction_animation: value.interaction_animation,
+            delayed_interaction_animation: value.delayed_interaction_animation,
+            enter_animation: value.enter_animation,
+            fonts: Vec::new(),
+            images: Vec::new(),
+        }
+    }
+}
+
+impl From<ThemeData> for ThemeBundle {
+    fn from(value: ThemeData) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl ThemeBundle {
+    const SECTIONS: [&'static str; 7] = [
+        "colors",
+        "spacing",
+        "text",
+        "icons",
+        "interaction_animation",
+        "delayed_interaction_animation",
+        "enter_animation",
+    ];
+
+    /// Parses a bundle from JSON. Sections are deserialized one by one, so errors name
+    /// the path of the field they occur in (i.e. `spacing.gaps.small`).
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, ThemeBundleError> {
+        let mut sections: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_slice(bytes)?;
+
+        if let Some(unknown) = sections
+            .keys()
+            .find(|key| !ThemeBundle::SECTIONS.contains(&key.as_str()))
+        {
+            return Err(ThemeBundleError::invalid(unknown, "unknown section"));
+        }
+
+        fn section<T: DeserializeOwned>(
+            sections: &mut serde_json::Map<String, serde_json::Value>,
+            name: &str,
+            default: T,
+        ) -> Result<T, ThemeBundleError> {
+            match sections.remove(name) {
+                Some(value) => serde_path_to_error::deserialize(value).map_err(|err| {
+                    let field = match err.path().to_string().as_str() {
+                        "." => name.to_string(),
+                        path => format!("{}.{}", name, path),
+                    };
+                    ThemeBundleError::invalid(field, err.into_inner().to_string())
+                }),
+                None => Ok(default),
+            }
+        }
+
+        let default = ThemeBundle::default();
+        Ok(Self {
+            colors: section(&mut sections, "colors", default.colors)?,
+            spacing: section(&mut sections, "spacing", default.spacing)?,
+            text: section(&mut sections, "text", default.text)?,
+            icons: section(&mut sections, "icons", default.icons)?,
+            interaction_animation: section(
+                &mut sections,
+                "interaction_animation",
+                default.interaction_animation,
+            )?,
+            delayed_interaction_animation: section(
+                &mut sections,
+                "delayed_interaction_animation",
+                default.delayed_interaction_animation,
+            )?,
+            enter_animation: section(&mut sections, "enter_animation", default.enter_animation)?,
+            fonts: Vec::new(),
+            images: Vec::new(),
+        })
+    }
+
+    /// Checks the values serde can't: sizes, durations and paths.
+    /// The error names the first invalid field.
+    pub fn validate(&self) -> Result<(), ThemeBundleError> {
+        validate_numbers("spacing", &self.spacing, NumberConstraint::NonNegative)?;
+        validate_numbers(
+            "interaction_animation",
+            &self.interaction_animation,
+            NumberConstraint::NonNegative,
+        )?;
+        validate_numbers(
+            "delayed_interaction_animation",
+            &self.delayed_interaction_animation,
+            NumberConstraint::NonNegative,
+        )?;
+        validate_numbers(
+            "enter_animation",
+            &self.enter_animation,
+            NumberConstraint::NonNegative,
+        )?;
+        // Tracking may be negative, sizes are checked below
+        validate_numbers("text", &self.text, NumberConstraint::Finite)?;
+
+        for (style, scales) in [
+            ("display", &self.text.display),
+            ("headline", &self.text.headline),
+            ("title", &self.text.title),
+            ("body", &self.text.body),
+            ("label", &self.text.label),
+        ] {
+            for (scale, config) in [
+                ("small", &scales.small),
+                ("medium", &scales.medium),
+                ("large", &scales.large),
+            ] {
+                let field = format!("text.{}.{}", style, scale);
+                if config.size <= 0. {
+                    return Err(ThemeBundleError::invalid(
+                        format!("{}.size", field),
+                        "must be greater than zero",
+                    ));
+                }
+                if config.line_height < 0. {
+                    return Err(ThemeBundleError::invalid(
+                        format!("{}.line_height", field),
+                        "must not be negative",
+                    ));
+                }
+
+                for (font_type, path) in [
+                    ("regular", &config.font.regular),
+                    ("bold", &config.font.bold),
+                    ("italic", &config.font.italic),
+                    ("bold_italic", &config.font.bold_italic),
+                ] {
+                    if path.is_empty() {
+                        return Err(ThemeBundleError::invalid(
+                            format!("{}.font.{}", field, font_type),
+                            "font path must not be empty",
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (field, icon) in self.icon_fields() {
+            match icon {
+                IconData::FontCodepoint(path, _, _, size) => {
+                    if path.is_empty() {
+                        return Err(ThemeBundleError::invalid(
+                            field,
+                            "font path must not be empty",
+                        ));
+                    }
+                    if !size.is_finite() || *size <= 0. {
+                        return Err(ThemeBundleError::invalid(
+                            field,
+                            "size must be greater than zero",
+                        ));
+                    }
+                }
+                IconData::Image(_, _) | IconData::None => (),
+            }
+        }
+
+        for (i, custom) in self.icons.custom.iter().enumerate() {
+            if custom.name.is_empty() {
+                return Err(ThemeBundleError::invalid(
+                    format!("icons.custom[{}].name", i),
+                    "must not be empty",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copies the bundle into the theme data, keeping the active scheme.
+    pub fn apply(&self, theme_data: &mut ThemeData) {
+        theme_data.colors = self.colors.clone();
+        theme_data.spacing = self.spacing;
+        theme_data.text = self.text.clone();
+        theme_data.icons = self.icons.clone();
+        theme_data.interaction_animation = self.interaction_animation;
+        theme_data.delayed_interaction_animation = self.delayed_interaction_animation;
+        theme_data.enter_animation = self.enter_animation;
+    }
+
+    /// All distinct font paths used by the typography and font codepoint icons.
+    pub fn font_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = Vec::new();
+        for scales in [
+            &self.text.display,
+            &self.text.headline,
+            &self.text.title,
+            &self.text.body,
+            &self.text.label,
+        ] {
+            for config in [&scales.small, &scales.medium, &scales.large] {
+                paths.extend([
+                    config.font.regular.clone(),
+                    config.font.bold.clone(),
+                    config.font.italic.clone(),
+                    config.font.bold_italic.clone(),
+                ]);
+            }
+        }
+
+        for (_, icon) in self.icon_fields() {
+            if let IconData::FontCodepoint(path, _, _, _) = icon {
+                paths.push(path.clone());
+            }
+        }
+
+        paths.sort();
+        paths.dedup();
+        paths.retain(|path| !path.is_empty());
+        paths
+    }
+
+    /// All distinct, non-empty image icon paths.
+    pub fn image_paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self
+            .icon_fields()
+            .filter_map(|(_, icon)| match icon {
+                IconData::Image(path, _) if !path.is_empty() => Some(path.clone()),
+                _ => None,
+            })
+            .collect();
+
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    /// Every named icon of [`Icons`], found via reflection so new icons are never missed,
+    /// followed by the custom icons.
+    fn icon_fields(&self) -> impl Iterator<Item = (String, &IconData)> {
+        let icons: &dyn Struct = &self.icons;
+        let custom_icons = &self.icons.custom;
+        (0..icons.field_len())
+            .filter_map(move |i| {
+                let name = icons.name_at(i)?;
+                let icon = icons.field_at(i)?.downcast_ref::<IconData>()?;
+                Some((format!("icons.{}", name), icon))
+            })
+            .chain(
+                custom_icons
+                    .iter()
+                    .enumerate()
+                    .map(|(i, custom)| (format!("icons.custom[{}].data", i), &custom.data)),
+            )
+    }
+}
+
+/// The constraint numbers of a bundle section must satisfy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NumberConstraint {
+    Finite,
+    NonNegative,
+}
+
+impl NumberConstraint {
+    /// Returns the reason the number fails the constraint, if it does.
+    fn check(&self, number: f32) -> Result<(), &'static str> {
+        if number.is_nan() {
+            return Err("must be a number, not NaN");
+        }
+        if number.is_infinite() {
+            return Err("must be finite");
+        }
+
+        match self {
+            NumberConstraint::NonNegative if number < 0. => Err("must not be negative"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks that every `f32` reachable from `value` satisfies `constraint`.
+fn validate_numbers(
+    path: &str,
+    value: &dyn Reflect,
+    constraint: NumberConstraint,
+) -> Result<(), ThemeBundleError> {
+    if let Some(number) = value.downcast_ref::<f32>() {
+        return constraint
+            .check(*number)
+            .map_err(|reason| ThemeBundleError::invalid(path, reason));
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for i in 0..value.field_len() {
+                // Safe unwraps: i is in range
+                let name = value.name_at(i).unwrap();
+                let field = value.field_at(i).unwrap();
+                validate_numbers(&format!("{}.{}", path, name), field, constraint)?;
+            }
+        }
+        ReflectRef::Enum(value) => {
+            // Option fields are transparent
+            for field in value.iter_fields() {
+                validate_numbers(path, field.value(), constraint)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// The [`ThemeBundle`] applied to [`ThemeData`] whenever it (re)loads.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ActiveThemeBundle(pub Handle<ThemeBundle>);
+
+pub(crate) fn apply_active_theme_bundle(
+    active_bundle: Res<ActiveThemeBundle>,
+    mut reader: EventReader<AssetEvent<ThemeBundle>>,
+    theme_bundles: Res<Assets<ThemeBundle>>,
+    mut theme_data: ResMut<ThemeData>,
+) {
+    let id = active_bundle.0.id();
+    let reloaded = reader
+        .read()
+        .any(|event| event.is_loaded_with_dependencies(id) || event.is_modified(id));
+
+    if !reloaded && !active_bundle.is_changed() {
+        return;
+    }
+
+    let Some(theme_bundle) = theme_bundles.get(id) else {
+        return;
+    };
+
+    theme_bundle.apply(&mut theme_data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid_field(result: Result<impl fmt::Debug, ThemeBundleError>) -> (String, String) {
+        match result {
+            Err(ThemeBundleError::InvalidField { field, reason }) => (field, reason),
+            other => panic!("Expected an invalid field error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn default_bundle_round_trips() {
+        let json = serde_json::to_vec(&ThemeBundle::default()).unwrap();
+        let bundle = ThemeBundle::from_slice(&json).unwrap();
+
+        assert!(bundle.validate().is_ok());
+        assert!(bundle
+            .spacing
+            .reflect_partial_eq(&ThemeBundle::default().spacing)
+            .unwrap_or(false));
+    }
+
+    #[test]
+    fn missing_sections_fall_back_to_defaults() {
+        let mut spacing = serde_json::to_value(ThemeBundle::default().spacing).unwrap();
+        spacing["gaps"]["small"] = 6.into();
+        let json = serde_json::json!({ "spacing": spacing }).to_string();
+
+        let bundle = ThemeBundle::from_slice(json.as_bytes()).unwrap();
+
+        assert_eq!(bundle.spacing.gaps.small, 6.);
+        assert!(bundle
+            .text
+            .reflect_partial_eq(&ThemeBundle::default().text)
+            .unwrap_or(false));
+        assert!(bundle.validate().is_ok());
+    }
+
+    #[test]
+    fn partial_spacing_and_text_fall_back_to_defaults() {
+        let mut body = serde_json::to_value(&ThemeBundle::default().text.body).unwrap();
+        body["medium"]["size"] = 12.into();
+        let json = serde_json::json!({
+            "spacing": { "scroll_bar_size": 12 },
+            "text": { "body": body },
+        })
+        .to_string();
+
+        let bundle = ThemeBundle::from_slice(json.as_bytes()).unwrap();
+        let default = ThemeBundle::default();
+
+        assert_eq!(bundle.spacing.scroll_bar_size, 12.);
+        assert_eq!(bundle.spacing.gaps.small, default.spacing.gaps.small);
+        assert_eq!(bundle.text.body.medium.size, 12.);
+        assert_eq!(
+            bundle.text.label.medium.size,
+            default.text.label.medium.size
+        );
+        assert!(bundle.validate().is_ok());
+    }
+
+    #[test]
+    fn parse_errors_name_the_field_path() {
+        let (field, _) = invalid_field(ThemeBundle::from_slice(
+            br#"{ "spacing": { "gaps": { "small": "4" } } }"#,
+        ));
+        assert_eq!(field, "spacing.gaps.small");
+
+        let (field, reason) = invalid_field(ThemeBundle::from_slice(
+            br#"{ "spacing": { "gaps": { "small": 4 } } }"#,
+        ));
+        assert_eq!(field, "spacing.gaps");
+        assert!(reason.contains("missing field"), "{}", reason);
+    }
+
+    #[test]
+    fn parse_errors_name_the_section() {
+        assert!(matches!(
+            ThemeBundle::from_slice(b"[]"),
+            Err(ThemeBundleError::Parse(_))
+        ));
+
+        let (field, reason) = invalid_field(ThemeBundle::from_slice(br#"{ "colours": {} }"#));
+        assert_eq!(field, "colours");
+        assert_eq!(reason, "unknown section");
+
+        let (field, _) = invalid_field(ThemeBundle::from_slice(br#"{ "spacing": 4 }"#));
+        assert_eq!(field, "spacing");
+    }
+
+    #[test]
+    fn validation_reports_the_failed_constraint() {
+        let mut bundle = ThemeBundle::default();
+        bundle.spacing.gaps.small = -1.;
+        let (field, reason) = invalid_field(bundle.validate());
+        assert_eq!(field, "spacing.gaps.small");
+        assert_eq!(reason, "must not be negative");
+
+        let mut bundle = ThemeBundle::default();
+        bundle.spacing.gaps.small = f32::INFINITY;
+        let (field, reason) = invalid_field(bundle.validate());
+        assert_eq!(field, "spacing.gaps.small");
+        assert_eq!(reason, "must be finite");
+
+        let mut bundle = ThemeBundle::default();
+        bundle.text.body.medium.tracking = f32::NAN;
+        let (field, reason) = invalid_field(bundle.validate());
+        assert_eq!(field, "text.body.medium.tracking");
+        assert_eq!(reason, "must be a number, not NaN");
+
+        let mut bundle = ThemeBundle::default();
+        bundle.text.body.medium.tracking = -0.5;
+        assert!(bundle.validate().is_ok());
+
+        let mut bundle = ThemeBundle::default();
+        bundle.text.body.medium.size = 0.;
+        let (field, _) = invalid_field(bundle.validate());
+        assert_eq!(field, "text.body.medium.size");
+    }
+
+    #[test]
+    fn icon_validation_covers_every_icon() {
+        let mut bundle = ThemeBundle::default();
+        bundle.icons.restore = IconData::FontCodepoint("".into(), 'a', Color::WHITE, 12.);
+        let (field, reason) = invalid_field(bundle.validate());
+        assert_eq!(field, "icons.restore");
+        assert_eq!(reason, "font path must not be empty");
+
+        let mut bundle = ThemeBundle::default();
+        bundle.icons.maximize = IconData::Image("icons/maximize.png".into(), Color::WHITE);
+        assert!(bundle
+            .image_paths()
+            .contains(&"icons/maximize.png".to_string()));
+    }
+}
@@ -5,7 +5,7 @@ use super::theme_data::Contrast;
 
 /// Custom serialization and deserialization functions necessary for the loading and saving of
 /// [`Color`] structs to their hex string representation.
-mod serialize_color {
+pub(crate) mod serialize_color {
 
     use bevy::color::{Color, Srgba};
     use serde::{
@@ -13,7 +13,7 @@ mod serialize_color {
         Deserializer, Serializer,
     };
 
-    pub(super) fn serialize<S, T>(color: &T, serializer: S) -> Result<S::Ok, S::Error>
+    pub(crate) fn serialize<S, T>(color: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
         T: Into<Option<Color>> + Clone,
@@ -28,13 +28,20 @@ mod serialize_color {
         }
     }
 
-    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
     where
         D: Deserializer<'de>,
     {
         deserializer.deserialize_str(ColorVisitor)
     }
 
+    pub(crate) fn deserialize_option<'de, D>(deserializer: D) -> Result<Option<Color>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer).map(Some)
+    }
+
     struct ColorVisitor;
 
     impl<'de> Visitor<'de> for ColorVisitor {
@@ -161,31 +168,41 @@ pub struct CoreColors {
 
     #[serde(
         serialize_with = "serialize_color::serialize",
-        skip_serializing_if = "Option::is_none"
+        deserialize_with = "serialize_color::deserialize_option",
+        skip_serializing_if = "Option::is_none",
+        default
     )]
     pub secondary: Option<Color>,
 
     #[serde(
         serialize_with = "serialize_color::serialize",
-        skip_serializing_if = "Option::is_none"
+        deserialize_with = "serialize_color::deserialize_option",
+        skip_serializing_if = "Option::is_none",
+        default
     )]
     pub tertiary: Option<Color>,
 
     #[serde(
         serialize_with = "serialize_color::serialize",
-        skip_serializing_if = "Option::is_none"
+        deserialize_with = "serialize_color::deserialize_option",
+        skip_serializing_if = "Option::is_none",
+        default
     )]
     pub error: Option<Color>,
 
     #[serde(
         serialize_with = "serialize_color::serialize",
-        skip_serializing_if = "Option::is_none"
+        deserialize_with = "serialize_color::deserialize_option",
+        skip_serializing_if = "Option::is_none",
+        default
     )]
     pub neutral: Option<Color>,
 
     #[serde(
         serialize_with = "serialize_color::serialize",
-        skip_serializing_if = "Option::is_none"
+        deserialize_with = "serialize_color::deserialize_option",
+        skip_serializing_if = "Option::is_none",
+        default
     )]
     pub neutral_variant: Option<Color>,
 }
@@ -1,3 +1,5 @@
+use std::ops::{BitOr, BitOrAssign};
+
 use bevy::prelude::*;
 use sickle_math::ease::Ease;
 
@@ -41,6 +43,81 @@ impl Scheme {
     }
 }
 
+/// A set of [`ThemeData`] sections.
+///
+/// Pseudo themes declare the sections their builders read, so a change to a section
+/// only refreshes the themes that depend on it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub struct ThemeDataSections(u8);
+
+impl ThemeDataSections {
+    pub const NONE: Self = Self(0);
+    /// `active_scheme` and `colors`
+    pub const COLORS: Self = Self(1);
+    pub const SPACING: Self = Self(1 << 1);
+    pub const TEXT: Self = Self(1 << 2);
+    pub const ICONS: Self = Self(1 << 3);
+    /// `interaction_animation`, `delayed_interaction_animation` and `enter_animation`
+    pub const ANIMATIONS: Self = Self(1 << 4);
+    pub const ALL: Self = Self(0b11111);
+
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn intersects(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for ThemeDataSections {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ThemeDataSections {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// The sections of [`ThemeData`] that changed the last time it was modified.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+pub struct ThemeDataChanges(ThemeDataSections);
+
+impl Default for ThemeDataChanges {
+    fn default() -> Self {
+        Self(ThemeDataSections::ALL)
+    }
+}
+
+impl ThemeDataChanges {
+    pub fn get(&self) -> ThemeDataSections {
+        self.0
+    }
+}
+
+pub(crate) fn detect_theme_data_changes(
+    theme_data: Res<ThemeData>,
+    mut previous: Local<Option<ThemeData>>,
+    mut changes: ResMut<ThemeDataChanges>,
+) {
+    let sections = match previous.as_ref() {
+        Some(previous) => previous.changed_sections(&theme_data),
+        None => ThemeDataSections::ALL,
+    };
+
+    changes.0 = sections;
+    *previous = Some(theme_data.clone());
+}
+
 #[derive(Resource, Clone, Debug, Reflect)]
 pub struct ThemeData {
     pub active_scheme: Scheme,
@@ -94,6 +171,35 @@ impl ThemeData {
         style_builder
     }
 
+    /// Returns the sections that differ between the two theme data.
+    pub fn changed_sections(&self, other: &ThemeData) -> ThemeDataSections {
+        fn differs(a: &dyn Reflect, b: &dyn Reflect) -> bool {
+            !a.reflect_partial_eq(b).unwrap_or(false)
+        }
+
+        let mut sections = ThemeDataSections::NONE;
+        if self.active_scheme != other.active_scheme || differs(&self.colors, &other.colors) {
+            sections |= ThemeDataSections::COLORS;
+        }
+        if differs(&self.spacing, &other.spacing) {
+            sections |= ThemeDataSections::SPACING;
+        }
+        if differs(&self.text, &other.text) {
+            sections |= ThemeDataSections::TEXT;
+        }
+        if differs(&self.icons, &other.icons) {
+            sections |= ThemeDataSections::ICONS;
+        }
+        if self.interaction_animation != other.interaction_animation
+            || self.delayed_interaction_animation != other.delayed_interaction_animation
+            || self.enter_animation != other.enter_animation
+        {
+            sections |= ThemeDataSections::ANIMATIONS;
+        }
+
+        sections
+    }
+
     /// Returns the scheme colors of the current active scheme / contrast
     pub fn colors(&self) -> SchemeColors {
         match self.active_scheme {
@@ -102,3 +208,70 @@ impl ThemeData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::theme::icons::IconData;
+
+    use super::*;
+
+    #[test]
+    fn changed_sections_of_identical_theme_data_is_empty() {
+        let theme_data = ThemeData::default();
+
+        assert!(theme_data.changed_sections(&theme_data.clone()).is_empty());
+    }
+
+    #[test]
+    fn changed_sections_detects_each_section() {
+        let theme_data = ThemeData::default();
+
+        let mut changed = theme_data.clone();
+        changed.active_scheme = Scheme::Light(Contrast::High);
+        assert_eq!(
+            theme_data.changed_sections(&changed),
+            ThemeDataSections::COLORS
+        );
+
+        let mut changed = theme_data.clone();
+        changed.spacing.gaps.small += 1.;
+        assert_eq!(
+            theme_data.changed_sections(&changed),
+            ThemeDataSections::SPACING
+        );
+
+        let mut changed = theme_data.clone();
+        changed.text.body.medium.tracking += 0.25;
+        assert_eq!(
+            theme_data.changed_sections(&changed),
+            ThemeDataSections::TEXT
+        );
+
+        let mut changed = theme_data.clone();
+        changed.icons.checkmark = IconData::None;
+        assert_eq!(
+            theme_data.changed_sections(&changed),
+            ThemeDataSections::ICONS
+        );
+
+        let mut changed = theme_data.clone();
+        changed.enter_animation.delete_on_entered(false);
+        assert_eq!(
+            theme_data.changed_sections(&changed),
+            ThemeDataSections::ANIMATIONS
+        );
+    }
+
+    #[test]
+    fn changed_sections_combines_multiple_changes() {
+        let theme_data = ThemeData::default();
+        let mut changed = theme_data.clone();
+        changed.spacing.borders.extra_small += 1.;
+        changed.text.body.medium.tracking += 0.25;
+
+        let sections = theme_data.changed_sections(&changed);
+
+        assert!(sections.contains(ThemeDataSections::SPACING | ThemeDataSections::TEXT));
+        assert!(!sections.intersects(ThemeDataSections::COLORS));
+    }
+}
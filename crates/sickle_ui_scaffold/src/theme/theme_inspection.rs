@@ -0,0 +1,203 @@
+use bevy::prelude::*;
+
+use crate::ui_style::{
+    builder::StyleBuilder, generated::LockableStyleAttribute, LockedStyleAttributes, LogicalEq,
+};
+
+use super::{
+    dynamic_style::{ContextStyleAttribute, DynamicStyle},
+    dynamic_style_attribute::DynamicStyleAttribute,
+    pseudo_state::{PseudoState, PseudoStates},
+    style_animation::AnimationState,
+    theme_data::ThemeData,
+    DefaultTheme, Theme,
+};
+
+/// Where a style attribute or pseudo theme comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StyleSource {
+    /// The default theme of the component.
+    Base,
+    /// A [`Theme`] placed on the entity itself or on one of its ancestors.
+    Theme(Entity),
+    /// An attribute of the current [`DynamicStyle`] no theme produced.
+    Inline,
+}
+
+impl From<Option<Entity>> for StyleSource {
+    fn from(value: Option<Entity>) -> Self {
+        match value {
+            Some(entity) => StyleSource::Theme(entity),
+            None => StyleSource::Base,
+        }
+    }
+}
+
+/// A candidate [`PseudoTheme`](super::PseudoTheme) of a themed entity.
+#[derive(Clone, Debug)]
+pub struct PseudoThemeInspection {
+    pub source: StyleSource,
+    pub state: Option<Vec<PseudoState>>,
+    /// The number of the entity's pseudo states the pseudo theme matches.
+    pub score: usize,
+    /// Whether the pseudo theme is used when the entity is styled.
+    pub matched: bool,
+}
+
+/// An attribute of the merged style of a themed entity.
+#[derive(Clone, Debug)]
+pub struct AttributeInspection {
+    /// The entity the attribute is placed on, `None` for the themed entity itself.
+    pub placement: Option<Entity>,
+    pub attribute: ContextStyleAttribute,
+    pub source: StyleSource,
+    /// The current state of the animation controller, for animated attributes.
+    pub animation_state: Option<AnimationState>,
+}
+
+impl AttributeInspection {
+    /// A short description of the attribute value.
+    pub fn description(&self) -> String {
+        match self.attribute.attribute() {
+            DynamicStyleAttribute::Static(attribute) => format!("{:?}", attribute),
+            DynamicStyleAttribute::Interactive(attribute) => format!("{:?}", attribute),
+            DynamicStyleAttribute::Animated { attribute, .. } => format!("{:?}", attribute),
+        }
+    }
+}
+
+/// A snapshot of how the theme of component `component` resolves for an entity.
+#[derive(Clone, Debug)]
+pub struct ThemeInspection {
+    pub component: &'static str,
+    pub pseudo_states: Vec<PseudoState>,
+    /// All pseudo themes of the themes that apply to the entity, in order of application.
+    pub pseudo_themes: Vec<PseudoThemeInspection>,
+    /// The merged attributes, in order of application.
+    pub attributes: Vec<AttributeInspection>,
+    pub locked_attributes: Vec<LockableStyleAttribute>,
+}
+
+impl ThemeInspection {
+    /// Resolves the theme of `C` for `entity` the same way a theme refresh does,
+    /// without changing the world. Returns `None` if the entity has no `C`.
+    pub fn inspect<C>(entity: Entity, world: &World) -> Option<Self>
+    where
+        C: DefaultTheme,
+    {
+        let context = world.get::<C>(entity)?;
+        let theme_data = world.resource::<ThemeData>();
+        let pseudo_states = match world.get::<PseudoStates>(entity) {
+            Some(pseudo_states) => pseudo_states.get().clone(),
+            None => Vec::new(),
+        };
+
+        let default_theme = C::default_theme();
+        let themes = Theme::<C>::collect_for(entity, world, &default_theme);
+        let matching = Theme::<C>::matching_pseudo_themes(&themes, &pseudo_states);
+
+        let pseudo_themes = themes
+            .iter()
+            .flat_map(|(theme, source_entity)| {
+                theme
+                    .pseudo_themes()
+                    .iter()
+                    .map(|pseudo_theme| PseudoThemeInspection {
+                        source: (*source_entity).into(),
+                        state: pseudo_theme.state().clone(),
+                        score: pseudo_theme.count_match(&pseudo_states),
+                        matched: matching
+                            .iter()
+                            .any(|(matched, _)| std::ptr::eq(*matched, pseudo_theme)),
+                    })
+            })
+            .collect();
+
+        let mut attributes: Vec<AttributeInspection> = Vec::new();
+        let mut style_builder = StyleBuilder::new();
+        for (pseudo_theme, source_entity) in matching.iter() {
+            style_builder.clear();
+            let styles: Vec<(Option<Entity>, DynamicStyle)> = match pseudo_theme.build_into(
+                &mut style_builder,
+                *source_entity,
+                entity,
+                context,
+                theme_data,
+                world,
+            ) {
+                Some(style) => vec![(None, style.clone())],
+                None => style_builder.convert_to_iter(context).collect(),
+            };
+
+            for (placement, style) in styles {
+                for attribute in style.attributes() {
+                    let inspection = AttributeInspection {
+                        placement,
+                        attribute: attribute.clone(),
+                        source: (*source_entity).into(),
+                        animation_state: None,
+                    };
+
+                    match attributes.iter().position(|entry| {
+                        entry.placement == placement && entry.attribute.logical_eq(attribute)
+                    }) {
+                        Some(index) => attributes[index] = inspection,
+                        None => attributes.push(inspection),
+                    }
+                }
+            }
+        }
+
+        let mut placements: Vec<Option<Entity>> = vec![None];
+        for attribute in attributes.iter() {
+            if !placements.contains(&attribute.placement) {
+                placements.push(attribute.placement);
+            }
+        }
+
+        for placement in placements {
+            let Some(current_style) = world.get::<DynamicStyle>(placement.unwrap_or(entity))
+            else {
+                continue;
+            };
+
+            for current in current_style.attributes() {
+                match attributes.iter_mut().find(|entry| {
+                    entry.placement == placement && entry.attribute.logical_eq(current)
+                }) {
+                    Some(entry) => {
+                        if let DynamicStyleAttribute::Animated { controller, .. } =
+                            current.attribute()
+                        {
+                            entry.animation_state = controller.current_state().clone().into();
+                        }
+                    }
+                    None => attributes.push(AttributeInspection {
+                        placement,
+                        attribute: current.clone(),
+                        source: StyleSource::Inline,
+                        animation_state: match current.attribute() {
+                            DynamicStyleAttribute::Animated { controller, .. } => {
+                                controller.current_state().clone().into()
+                            }
+                            _ => None,
+                        },
+                    }),
+                }
+            }
+        }
+
+        let locked_attributes = match world.get::<LockedStyleAttributes>(entity) {
+            Some(locked) => locked.iter().copied().collect(),
+            None => Vec::new(),
+        };
+
+        Some(Self {
+            component: std::any::type_name::<C>(),
+            pseudo_states,
+            pseudo_themes,
+            attributes,
+            locked_attributes,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct DividerSpacing {
     pub extra_small: f32,
     pub small: f32,
@@ -10,7 +11,7 @@ pub struct DividerSpacing {
     pub custom_2: f32,
 }
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct Spacing {
     pub tiny: f32,
     pub extra_small: f32,
@@ -24,7 +25,7 @@ pub struct Spacing {
     pub custom_4: f32,
 }
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct IconSizes {
     pub extra_small: f32,
     pub small: f32,
@@ -34,7 +35,7 @@ pub struct IconSizes {
     pub custom_2: f32,
 }
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct CheckboxSizes {
     pub line_height: f32,
     pub border_size: f32,
@@ -48,7 +49,7 @@ impl CheckboxSizes {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct RedioButtonSizes {
     pub border_size: f32,
     pub radiomark_outer_size: f32,
@@ -61,20 +62,21 @@ impl RedioButtonSizes {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct InputSizes {
     pub checkbox: CheckboxSizes,
     pub radio_button: RedioButtonSizes,
 }
 
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct ResizeZone {
     pub width: f32,
     pub pullback: f32,
     pub handle_gap: f32,
 }
 
-#[derive(Clone, Copy, Debug, Reflect)]
+#[derive(Clone, Copy, Debug, Reflect, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ThemeSpacing {
     pub borders: DividerSpacing,
     pub corners: Spacing,
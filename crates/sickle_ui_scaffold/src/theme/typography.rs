@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum FontStyle {
     Display,
     Headline,
@@ -9,14 +10,14 @@ pub enum FontStyle {
     Label,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum FontScale {
     Small,
     Medium,
     Large,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum FontType {
     Regular,
     Bold,
@@ -24,13 +25,13 @@ pub enum FontType {
     BoldItalic,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct SizedFont {
     pub font: String,
     pub size: f32,
 }
 
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct FontSet {
     pub regular: String,
     pub bold: String,
@@ -38,7 +39,7 @@ pub struct FontSet {
     pub bold_italic: String,
 }
 
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct FontConfig {
     pub font: FontSet,
     // Unusued until proper text handling exists
@@ -75,7 +76,7 @@ impl FontConfig {
     }
 }
 
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Reflect, Serialize, Deserialize)]
 pub struct StyleScales {
     pub small: FontConfig,
     pub medium: FontConfig,
@@ -92,7 +93,8 @@ impl StyleScales {
     }
 }
 
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Reflect, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ThemeTypography {
     pub display: StyleScales,
     pub headline: StyleScales,
@@ -9,7 +9,7 @@ use bevy::{
         system::{Commands, EntityCommand, EntityCommands},
         world::{Command, World},
     },
-    hierarchy::{Children, Parent},
+    hierarchy::Children,
     log::{info, warn},
     state::state::{FreelyMutableState, NextState, States},
     text::{Text, TextSection, TextStyle},
@@ -124,7 +124,7 @@ impl UpdateTextExt for EntityCommands<'_> {
     }
 }
 
-// TODO: Move to style and apply to Node's window
+// TODO: Move to style
 struct SetCursor {
     cursor: CursorIcon,
 }
@@ -142,16 +142,45 @@ impl Command for SetCursor {
     }
 }
 
+/// Sets the cursor of the window the node is rendered to
+struct SetNodeCursor {
+    cursor: CursorIcon,
+}
+
+impl EntityCommand for SetNodeCursor {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(window) = UiUtils::find_window(entity, world) else {
+            return;
+        };
+
+        let Some(mut window) = world.get_mut::<Window>(window) else {
+            return;
+        };
+
+        if window.cursor.icon != self.cursor {
+            window.cursor.icon = self.cursor;
+        }
+    }
+}
+
 pub trait SetCursorExt<'w, 's, 'a> {
     fn set_cursor(&mut self, cursor: CursorIcon);
 }
 
 impl<'w, 's, 'a> SetCursorExt<'w, 's, 'a> for Commands<'w, 's> {
+    /// Sets the cursor of the primary window
     fn set_cursor(&mut self, cursor: CursorIcon) {
         self.add(SetCursor { cursor });
     }
 }
 
+impl<'w, 's, 'a> SetCursorExt<'w, 's, 'a> for EntityCommands<'a> {
+    /// Sets the cursor of the window the node is rendered to
+    fn set_cursor(&mut self, cursor: CursorIcon) {
+        self.add(SetNodeCursor { cursor });
+    }
+}
+
 struct LogHierarchy {
     level: usize,
     is_last: bool,
@@ -299,6 +328,13 @@ impl EntityCommandsNamedExt for EntityCommands<'_> {
 }
 
 pub trait RefreshThemeExt {
+    /// Rebuilds the themed styles of the entity and applies them as a diff.
+    ///
+    /// Attributes are compared against the [`AppliedDynamicStyle`] of the previous refresh:
+    /// static attributes are only written again when their themed value changed, and static
+    /// attributes the new style no longer sets are left at their current value. Changes made
+    /// to such attributes in code are therefore kept. Remove [`AppliedDynamicStyle`] before
+    /// refreshing to re-apply every attribute.
     fn refresh_theme<C>(&mut self) -> &mut Self
     where
         C: DefaultTheme;
@@ -338,29 +374,8 @@ where
             None => &empty_pseudo_state,
         };
 
-        // Default -> General (App-wide) -> Specialized (Screen) theming is a reasonable guess.
-        // Round to 4, which is the first growth step.
-        // TODO: Cache most common theme count in theme data.
-        let mut themes: Vec<(&Theme<C>, Option<Entity>)> = Vec::with_capacity(4);
-        // Add own theme
-        if let Some(own_theme) = world.get::<Theme<C>>(entity) {
-            themes.push((own_theme, Some(entity)));
-        }
-
-        // Add all ancestor themes
-        let mut current_ancestor = entity;
-        while let Some(parent) = world.get::<Parent>(current_ancestor) {
-            current_ancestor = parent.get();
-            if let Some(ancestor_theme) = world.get::<Theme<C>>(current_ancestor) {
-                themes.push((ancestor_theme, Some(current_ancestor)));
-            }
-        }
-
         let default_theme = C::default_theme();
-        if let Some(ref default_theme) = default_theme {
-            themes.push((default_theme, None));
-        }
-
+        let themes = Theme::<C>::collect_for(entity, world, &default_theme);
         if themes.len() == 0 {
             warn!(
                 "Theme missing for component {} on entity: {}",
@@ -370,74 +385,28 @@ where
             return;
         }
 
-        // The list contains themes in reverse order of application
-        themes.reverse();
-
-        // Assuming we have a base style and two-three pseudo state style is a reasonable guess.
-        // TODO: Cache most common pseudo theme count in theme data.
-        let mut pseudo_themes: Vec<(&PseudoTheme<C>, Option<Entity>)> =
-            Vec::with_capacity(themes.len() * 4);
-
-        for (theme, source_entity) in &themes {
-            if let Some(base_theme) = theme.pseudo_themes().iter().find(|pt| pt.is_base_theme()) {
-                pseudo_themes.push((base_theme, *source_entity));
-            }
-        }
-
-        if pseudo_states.len() > 0 {
-            for i in 0..pseudo_states.len() {
-                for (theme, source_entity) in &themes {
-                    theme
-                        .pseudo_themes()
-                        .iter()
-                        .filter(|pt| pt.count_match(pseudo_states) == i + 1)
-                        .for_each(|pt| pseudo_themes.push((pt, *source_entity)));
-                }
-            }
-        }
+        let pseudo_themes = Theme::<C>::matching_pseudo_themes(&themes, pseudo_states);
 
         // Merge base attributes on top of the default and down the chain, overwriting per-attribute at each level
         let mut styles = Vec::<(Option<Entity>, DynamicStyle)>::default();
         let mut style_builder = StyleBuilder::new();
         for (pseudo_theme, source_entity) in pseudo_themes.iter() {
-            let builder = pseudo_theme.builder();
-            if let DynamicStyleBuilder::Static(style) = builder {
+            style_builder.clear();
+            if let Some(style) = pseudo_theme.build_into(
+                &mut style_builder,
+                *source_entity,
+                entity,
+                context,
+                theme_data,
+                world,
+            ) {
                 styles = [(None, style.clone())]
                     .into_iter()
                     .fold(std::mem::take(&mut styles), fold_dynamic_styles);
             } else {
-                style_builder.clear();
-                let styles_iter = match builder {
-                    DynamicStyleBuilder::Static(_) => unreachable!(),
-                    DynamicStyleBuilder::StyleBuilder(builder) => {
-                        builder(&mut style_builder, &theme_data);
-
-                        style_builder.convert_to_iter(context)
-                    }
-                    DynamicStyleBuilder::ContextStyleBuilder(builder) => {
-                        builder(&mut style_builder, &context, &theme_data);
-
-                        style_builder.convert_to_iter(context)
-                    }
-                    DynamicStyleBuilder::WorldStyleBuilder(builder) => {
-                        builder(&mut style_builder, entity, &context, world);
-
-                        style_builder.convert_to_iter(context)
-                    }
-                    DynamicStyleBuilder::InfoWorldStyleBuilder(builder) => {
-                        builder(
-                            &mut style_builder,
-                            *source_entity,
-                            pseudo_theme.state(),
-                            entity,
-                            &context,
-                            world,
-                        );
-
-                        style_builder.convert_to_iter(context)
-                    }
-                };
-                styles = styles_iter.fold(std::mem::take(&mut styles), fold_dynamic_styles);
+                styles = style_builder
+                    .convert_to_iter(context)
+                    .fold(std::mem::take(&mut styles), fold_dynamic_styles);
             }
         }
 
@@ -462,6 +431,19 @@ where
 
             unstyled_entities.retain(|e| *e != placement_entity);
 
+            let applied_style = AppliedDynamicStyle::from(&style);
+            if let Some(previous_style) = world.get::<AppliedDynamicStyle>(placement_entity) {
+                if style.diff_from(previous_style) {
+                    continue;
+                }
+            }
+            world.entity_mut(placement_entity).insert(applied_style);
+
+            if style.is_empty() {
+                world.entity_mut(placement_entity).remove::<DynamicStyle>();
+                continue;
+            }
+
             if let Some(current_style) = world.get::<DynamicStyle>(placement_entity) {
                 style.copy_controllers(current_style);
             }
@@ -484,11 +466,15 @@ where
         }
 
         for unstyled_context in unstyled_entities {
-            world.entity_mut(unstyled_context).remove::<DynamicStyle>();
+            world
+                .entity_mut(unstyled_context)
+                .remove::<(DynamicStyle, AppliedDynamicStyle)>();
         }
 
         if cleanup_main_style {
-            world.entity_mut(entity).remove::<DynamicStyle>();
+            world
+                .entity_mut(entity)
+                .remove::<(DynamicStyle, AppliedDynamicStyle)>();
         }
     }
 }
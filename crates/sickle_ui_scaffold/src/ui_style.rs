@@ -127,6 +127,10 @@ impl LockedStyleAttributes {
     pub fn contains(&self, attr: LockableStyleAttribute) -> bool {
         self.0.contains(&attr)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LockableStyleAttribute> {
+        self.0.iter()
+    }
 }
 
 impl From<LockableStyleAttribute> for HashSet<LockableStyleAttribute> {
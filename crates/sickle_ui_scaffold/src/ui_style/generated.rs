@@ -187,6 +187,7 @@ enum _StyleAttributes {
         image_flip: BVec2,
     },
     #[skip_enity_command]
+    #[reflect_eq]
     ImageScaleMode {
         image_scale_mode: Option<ImageScaleMode>,
     },
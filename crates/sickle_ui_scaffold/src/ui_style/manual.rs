@@ -58,6 +58,22 @@ pub enum ImageSource {
     Atlas(String, TextureAtlasLayout),
 }
 
+impl PartialEq for ImageSource {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Path(l0), Self::Path(r0)) => l0 == r0,
+            (Self::Lookup(l0, l1), Self::Lookup(r0, r1)) => {
+                l0 == r0 && *l1 as usize == *r1 as usize
+            }
+            (Self::Handle(l0), Self::Handle(r0)) => l0 == r0,
+            (Self::Atlas(l0, l1), Self::Atlas(r0, r1)) => {
+                l0 == r0 && l1.size == r1.size && l1.textures == r1.textures
+            }
+            _ => false,
+        }
+    }
+}
+
 impl Default for ImageSource {
     fn default() -> Self {
         Self::Handle(Handle::default())
@@ -1,5 +1,5 @@
 use bevy::{
-    ecs::component::ComponentInfo,
+    ecs::{component::ComponentInfo, system::SystemParam},
     prelude::*,
     render::camera::{ManualTextureViews, RenderTarget},
     window::{PrimaryWindow, WindowRef, WindowResolution},
@@ -161,19 +161,31 @@ impl UiUtils {
         }
     }
 
+    /// Finds the render target of the node's `TargetCamera`, set on the node or its ancestors
     pub fn find_render_target(entity: Entity, world: &World) -> Option<RenderTarget> {
         let mut current_ancestor = entity;
-        while let Some(parent) = world.get::<Parent>(current_ancestor) {
-            current_ancestor = parent.get();
+        loop {
             if let Some(target_camera) = world.get::<TargetCamera>(current_ancestor) {
                 let camera_entity = target_camera.0;
                 if let Some(camera) = world.get::<Camera>(camera_entity) {
                     return camera.target.clone().into();
                 };
             }
+
+            current_ancestor = world.get::<Parent>(current_ancestor)?.get();
         }
+    }
 
-        None
+    /// Finds the window the node is rendered to. Nodes without a `TargetCamera` are assumed
+    /// to be in the primary window, nodes rendered to images or texture views have no window.
+    pub fn find_window(entity: Entity, world: &World) -> Option<Entity> {
+        match UiUtils::find_render_target(entity, world) {
+            Some(RenderTarget::Window(WindowRef::Entity(window))) => Some(window),
+            Some(RenderTarget::Window(WindowRef::Primary)) | None => {
+                UiUtils::get_primary_window_entity(world)
+            }
+            Some(_) => None,
+        }
     }
 
     pub fn render_target_size(render_target: RenderTarget, world: &World) -> Vec2 {
@@ -214,6 +226,15 @@ impl UiUtils {
         }
     }
 
+    pub fn get_primary_window_entity(world: &World) -> Option<Entity> {
+        let primary_window = world.component_id::<PrimaryWindow>()?;
+        world
+            .archetypes()
+            .iter()
+            .find(|a| !a.is_empty() && a.contains(primary_window))
+            .map(|a| a.entities()[0].id())
+    }
+
     pub fn get_primary_window(world: &World) -> &Window {
         // Unsafe single: don't ask for a primary window if it doesn't exists pls.
         // TODO: use resource to store primary window entity
@@ -232,3 +253,40 @@ impl UiUtils {
         Vec2::new(resolution.width(), resolution.height())
     }
 }
+
+/// Resolves the window UI nodes are rendered to, for systems without `World` access.
+///
+/// Works the same as `UiUtils::find_window`.
+#[derive(SystemParam)]
+pub struct UiWindows<'w, 's> {
+    q_parent: Query<'w, 's, &'static Parent>,
+    q_target_camera: Query<'w, 's, &'static TargetCamera>,
+    q_camera: Query<'w, 's, &'static Camera>,
+    q_primary_window: Query<'w, 's, Entity, With<PrimaryWindow>>,
+}
+
+impl UiWindows<'_, '_> {
+    pub fn primary(&self) -> Option<Entity> {
+        self.q_primary_window.get_single().ok()
+    }
+
+    pub fn window_of(&self, node: Entity) -> Option<Entity> {
+        let target_camera = std::iter::once(node)
+            .chain(self.q_parent.iter_ancestors(node))
+            .find_map(|entity| self.q_target_camera.get(entity).ok());
+
+        let Some(target_camera) = target_camera else {
+            return self.primary();
+        };
+
+        match self
+            .q_camera
+            .get(target_camera.entity())
+            .map(|camera| &camera.target)
+        {
+            Ok(RenderTarget::Window(WindowRef::Entity(window))) => Some(*window),
+            Ok(RenderTarget::Window(WindowRef::Primary)) | Err(_) => self.primary(),
+            Ok(_) => None,
+        }
+    }
+}
@@ -6,6 +6,8 @@ use sickle_ui::{
     dev_panels::{
         hierarchy::{HierarchyTreeViewPlugin, UiHierarchyExt},
         scene_view::{SceneView, SceneViewPlugin, SpawnSceneViewPreUpdate, UiSceneViewExt},
+        theme_debugger::{ThemeDebuggerPlugin, UiThemeDebuggerExt},
+        theme_editor::{ThemeEditorConfig, ThemeEditorPlugin, UiThemeEditorExt},
     },
     prelude::*,
     ui_commands::{SetCursorExt, UpdateStatesExt},
@@ -30,6 +32,8 @@ fn main() {
         .init_state::<Page>()
         .add_plugins(HierarchyTreeViewPlugin)
         .add_plugins(SceneViewPlugin)
+        .add_plugins(ThemeDebuggerPlugin)
+        .add_plugins(ThemeEditorPlugin)
         .add_systems(Startup, setup.in_set(UiStartupSet))
         .add_systems(OnEnter(Page::Layout), layout_showcase)
         .add_systems(OnExit(Page::Layout), clear_content_on_menu_change)
@@ -44,6 +48,7 @@ fn main() {
             Update,
             (
                 update_current_page,
+                update_page_status,
                 handle_theme_data_update,
                 handle_theme_switch,
                 handle_theme_contrast_select,
@@ -333,6 +338,10 @@ struct ShowcaseContainer;
 #[reflect(Component)]
 struct HierarchyPanel;
 
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+struct PageStatus;
+
 #[derive(Resource, Debug, Default, Reflect)]
 #[reflect(Resource)]
 struct CurrentPage(Page);
@@ -698,6 +707,12 @@ fn setup(mut commands: Commands) {
             .style()
             .height(Val::Percent(100.))
             .background_color(Color::NONE);
+
+        column.status_bar(|status_bar| {
+            status_bar
+                .status_item(StatusBarSection::Left, StatusItemConfig::default())
+                .insert(PageStatus);
+        });
     });
 
     commands.next_state(Page::Layout);
@@ -730,6 +745,19 @@ fn update_current_page(
     }
 }
 
+fn update_page_status(
+    r_page: Res<State<Page>>,
+    mut q_status_items: Query<&mut StatusItem, With<PageStatus>>,
+) {
+    if !r_page.is_changed() {
+        return;
+    }
+
+    for mut item in &mut q_status_items {
+        item.text = format!("Page: {:?}", r_page.get());
+    }
+}
+
 fn clear_content_on_menu_change(
     root_node: Query<Entity, With<ShowcaseContainer>>,
     mut commands: Commands,
@@ -848,6 +876,12 @@ fn layout_showcase(root_node: Query<Entity, With<ShowcaseContainer>>, mut comman
                                     ..default()
                                 });
                             });
+                            tab_container.add_tab("Theme Debugger".into(), |panel| {
+                                panel.theme_debugger(None);
+                            });
+                            tab_container.add_tab("Theme Editor".into(), |panel| {
+                                panel.theme_editor(ThemeEditorConfig::default());
+                            });
                             tab_container.add_tab("Tab 6".into(), |panel| {
                                 panel.label(LabelConfig {
                                     label: "Panel 6".into(),
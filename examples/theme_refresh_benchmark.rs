@@ -0,0 +1,281 @@
+//! An example measuring the cost of theme refreshes when `ThemeData` changes.
+//!
+//! Cycles through changes of a section the theme does not read (text), a section
+//! that changes a single attribute (spacing) and the active scheme (colors).
+//! The default theme of the boxes declares the `ThemeData` sections it reads, so text
+//! changes skip the refresh. Press `Space` to toggle a theme that doesn't declare its
+//! sections on the root. Sections are combined with the default theme, so the override
+//! forces a refresh on every change.
+use std::time::{Duration, Instant};
+
+use bevy::{prelude::*, time::Stopwatch};
+
+use sickle_macros::UiContext;
+use sickle_ui::{
+    prelude::*,
+    theme::{
+        theme_colors::{Container, On},
+        theme_data::{Contrast, Scheme, ThemeData, ThemeDataChanges, ThemeDataSections},
+        ComponentThemePlugin, DefaultTheme, PseudoTheme, Theme, ThemeUpdate, UiContext,
+    },
+    ui_builder::{UiBuilderExt, UiRoot},
+    SickleUiPlugin,
+};
+
+const BOX_COUNT: usize = 2000;
+const CHANGE_INTERVAL: f32 = 0.25;
+const REPORT_INTERVAL: f32 = 3.;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Sickle UI - Theme Refresh Benchmark".into(),
+                present_mode: bevy::window::PresentMode::Immediate,
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(SickleUiPlugin)
+        .add_plugins(ComponentThemePlugin::<BenchmarkBox>::new())
+        .init_resource::<Benchmark>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (toggle_declared_sections, change_theme_data))
+        .add_systems(PostUpdate, start_measurement.before(ThemeUpdate))
+        .add_systems(
+            PostUpdate,
+            stop_measurement
+                .after(DynamicStylePostUpdate)
+                .before(bevy::ui::UiSystem::Layout),
+        )
+        .run();
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ThemeDataChange {
+    #[default]
+    Text,
+    Spacing,
+    Scheme,
+}
+
+impl ThemeDataChange {
+    fn next(&self) -> Self {
+        match self {
+            ThemeDataChange::Text => ThemeDataChange::Spacing,
+            ThemeDataChange::Spacing => ThemeDataChange::Scheme,
+            ThemeDataChange::Scheme => ThemeDataChange::Text,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct Benchmark {
+    refresh_all: bool,
+    last_change: ThemeDataChange,
+    change_timer: Stopwatch,
+    report_timer: Stopwatch,
+    frame_start: Option<Instant>,
+    samples: Vec<Sample>,
+}
+
+struct Sample {
+    change: ThemeDataChange,
+    duration: Duration,
+    refreshed: bool,
+}
+
+#[derive(Component)]
+struct BenchmarkRoot;
+
+#[derive(Component, UiContext)]
+struct BenchmarkBox;
+
+impl DefaultTheme for BenchmarkBox {
+    fn default_theme() -> Option<Theme<Self>> {
+        BenchmarkBox::declared_theme().into()
+    }
+}
+
+impl BenchmarkBox {
+    fn undeclared_theme() -> Theme<BenchmarkBox> {
+        Theme::new(vec![PseudoTheme::deferred(
+            None,
+            BenchmarkBox::primary_style,
+        )])
+    }
+
+    fn declared_theme() -> Theme<BenchmarkBox> {
+        Theme::new(vec![PseudoTheme::deferred(
+            None,
+            BenchmarkBox::primary_style,
+        )
+        .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING)])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .width(Val::Px(theme_spacing.areas.small))
+            .height(Val::Px(theme_spacing.areas.small))
+            .margin(UiRect::all(Val::Px(theme_spacing.gaps.tiny)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_color(colors.on(On::Surface))
+            .background_color(colors.container(Container::SurfaceMid));
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands
+        .ui_builder(UiRoot)
+        .row(|row| {
+            for _ in 0..BOX_COUNT {
+                row.spawn((NodeBundle::default(), BenchmarkBox));
+            }
+        })
+        .insert(BenchmarkRoot)
+        .style()
+        .width(Val::Percent(100.))
+        .height(Val::Percent(100.))
+        .flex_wrap(FlexWrap::Wrap)
+        .align_content(AlignContent::FlexStart);
+}
+
+fn toggle_declared_sections(
+    keys: Res<ButtonInput<KeyCode>>,
+    q_root: Query<Entity, With<BenchmarkRoot>>,
+    mut benchmark: ResMut<Benchmark>,
+    mut commands: Commands,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let Ok(root) = q_root.get_single() else {
+        return;
+    };
+
+    benchmark.refresh_all = !benchmark.refresh_all;
+    benchmark.samples.clear();
+    benchmark.report_timer.reset();
+
+    match benchmark.refresh_all {
+        true => commands
+            .entity(root)
+            .insert(BenchmarkBox::undeclared_theme()),
+        false => commands.entity(root).remove::<Theme<BenchmarkBox>>(),
+    };
+
+    info!(
+        "Undeclared theme on root: {}. Collecting samples...",
+        benchmark.refresh_all
+    );
+}
+
+fn change_theme_data(
+    time: Res<Time<Real>>,
+    mut theme_data: ResMut<ThemeData>,
+    mut benchmark: ResMut<Benchmark>,
+) {
+    benchmark.change_timer.tick(time.delta());
+    if benchmark.change_timer.elapsed_secs() < CHANGE_INTERVAL {
+        return;
+    }
+
+    benchmark.change_timer.reset();
+    let change = benchmark.last_change.next();
+    benchmark.last_change = change;
+
+    match change {
+        ThemeDataChange::Text => {
+            theme_data.text.body.medium.tracking = match theme_data.text.body.medium.tracking > 0. {
+                true => 0.,
+                false => 0.25,
+            };
+        }
+        ThemeDataChange::Spacing => {
+            theme_data.spacing.borders.extra_small =
+                match theme_data.spacing.borders.extra_small > 1. {
+                    true => 1.,
+                    false => 2.,
+                };
+        }
+        ThemeDataChange::Scheme => {
+            theme_data.active_scheme = match theme_data.active_scheme.is_dark() {
+                true => Scheme::Light(Contrast::Standard),
+                false => Scheme::Dark(Contrast::Standard),
+            };
+        }
+    }
+}
+
+fn start_measurement(theme_data: Res<ThemeData>, mut benchmark: ResMut<Benchmark>) {
+    benchmark.frame_start = match theme_data.is_changed() {
+        true => Some(Instant::now()),
+        false => None,
+    };
+}
+
+fn stop_measurement(
+    time: Res<Time<Real>>,
+    theme_data_changes: Res<ThemeDataChanges>,
+    q_root_theme: Query<&Theme<BenchmarkBox>, With<BenchmarkRoot>>,
+    mut benchmark: ResMut<Benchmark>,
+) {
+    if let Some(start) = benchmark.frame_start.take() {
+        // Mirrors the check of the theme plugin: the sections of the default theme are
+        // combined with the sections of every `Theme<BenchmarkBox>` (only the root has one)
+        let sections = q_root_theme.iter().fold(
+            BenchmarkBox::declared_theme().sections(),
+            |sections, theme| sections | theme.sections(),
+        );
+
+        let sample = Sample {
+            change: benchmark.last_change,
+            duration: start.elapsed(),
+            refreshed: sections.intersects(theme_data_changes.get()),
+        };
+        benchmark.samples.push(sample);
+    }
+
+    benchmark.report_timer.tick(time.delta());
+    if benchmark.report_timer.elapsed_secs() < REPORT_INTERVAL {
+        return;
+    }
+
+    benchmark.report_timer.reset();
+    for change in [
+        ThemeDataChange::Text,
+        ThemeDataChange::Spacing,
+        ThemeDataChange::Scheme,
+    ] {
+        let samples: Vec<&Sample> = benchmark
+            .samples
+            .iter()
+            .filter(|sample| sample.change == change)
+            .collect();
+
+        if samples.is_empty() {
+            continue;
+        }
+
+        let refreshed = samples.iter().filter(|sample| sample.refreshed).count();
+        let average = samples
+            .iter()
+            .map(|sample| sample.duration)
+            .sum::<Duration>()
+            / samples.len() as u32;
+        info!(
+            "[undeclared theme on root: {}] {:?} change: refreshed {}, skipped {}, {:.3} ms average",
+            benchmark.refresh_all,
+            change,
+            refreshed,
+            samples.len() - refreshed,
+            average.as_secs_f64() * 1000.,
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod entity_component_list;
 pub mod hierarchy;
 pub mod scene_view;
+pub mod theme_debugger;
+pub mod theme_editor;
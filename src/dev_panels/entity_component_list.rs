@@ -132,7 +132,12 @@ impl EntityComponentTag {
     pub const LABEL: &'static str = "Label";
 
     pub fn theme() -> Theme<EntityComponentTag> {
-        let base_theme = PseudoTheme::deferred(None, EntityComponentTag::primary_style);
+        let base_theme = PseudoTheme::deferred(None, EntityComponentTag::primary_style).depends_on(
+            ThemeDataSections::COLORS
+                | ThemeDataSections::SPACING
+                | ThemeDataSections::TEXT
+                | ThemeDataSections::ANIMATIONS,
+        );
         Theme::new(vec![base_theme])
     }
 
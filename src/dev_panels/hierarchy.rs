@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{a11y::accesskit::Role, prelude::*};
 
 use sickle_ui_scaffold::prelude::*;
 
@@ -8,7 +8,7 @@ use crate::widgets::{
         foldable::{Foldable, UiFoldableExt},
         panel::UiPanelExt,
         row::UiRowExt,
-        scroll_view::UiScrollViewExt,
+        scroll_view::{ScrollAlignment, ScrollIntoViewExt, UiScrollViewExt},
         sized_zone::{SizedZoneConfig, UiSizedZoneExt},
     },
     menus::menu_item::{MenuItem, MenuItemConfig, UiMenuItemExt},
@@ -137,29 +137,41 @@ fn update_entity_component_list(
 }
 
 fn update_hierarchy_on_foldable_change(
-    mut q_foldables: Query<(&HierarchyNode, &mut Foldable), Changed<Foldable>>,
+    mut q_foldables: Query<(Entity, &HierarchyNode, &mut Foldable), Changed<Foldable>>,
     q_children: Query<&Children>,
     q_name: Query<&Name>,
     mut commands: Commands,
 ) {
-    for (hierarchy_node, mut foldable) in &mut q_foldables {
-        if foldable.empty {
-            continue;
-        }
-
-        commands.entity(foldable.container()).despawn_descendants();
-
-        if foldable.open {
-            if let Ok(children) = q_children.get(hierarchy_node.entity) {
-                let mut builder = commands.ui_builder(foldable.container());
-                for child in children.iter() {
-                    spawn_hierarchy_level(hierarchy_node.hierarchy, *child, &mut builder, &q_name);
+    for (entity, hierarchy_node, mut foldable) in &mut q_foldables {
+        if !foldable.empty {
+            commands.entity(foldable.container()).despawn_descendants();
+
+            if foldable.open {
+                if let Ok(children) = q_children.get(hierarchy_node.entity) {
+                    let mut builder = commands.ui_builder(foldable.container());
+                    for child in children.iter() {
+                        spawn_hierarchy_level(
+                            hierarchy_node.hierarchy,
+                            *child,
+                            &mut builder,
+                            &q_name,
+                        );
+                    }
+                } else {
+                    foldable.empty = true;
                 }
-            } else {
+            } else if q_children.get(hierarchy_node.entity).is_err() {
                 foldable.empty = true;
             }
-        } else if q_children.get(hierarchy_node.entity).is_err() {
-            foldable.empty = true;
+        }
+
+        // Reported as the expanded state of the tree item, leaves have none
+        if foldable.open && !foldable.empty {
+            commands.entity(entity).add_pseudo_state(PseudoState::Open);
+        } else {
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Open);
         }
     }
 }
@@ -169,16 +181,22 @@ fn update_hierarchy_on_foldable_change(
 // anchestor access, theme, separate world for layout (or filter itself) etc. Tag open entities per hierarchy
 fn update_hierarchy_node_style(
     q_hierarchies: Query<(Entity, &HierarchyContainer), Changed<HierarchyContainer>>,
-    q_hierarchy_nodes: Query<(Entity, &HierarchyNode)>,
+    q_hierarchy_nodes: Query<(Entity, &HierarchyNode, Option<&PseudoStates>)>,
     mut commands: Commands,
 ) {
     for (entity, hierarchy) in &q_hierarchies {
-        for (foldable, hierarchy_node) in q_hierarchy_nodes
+        for (foldable, hierarchy_node, pseudo_states) in q_hierarchy_nodes
             .iter()
-            .filter(|(_, node)| node.hierarchy == entity)
+            .filter(|(_, node, _)| node.hierarchy == entity)
         {
             if let Some(selected) = hierarchy.selected {
                 if hierarchy_node.entity == selected {
+                    if !pseudo_states.is_some_and(|states| states.has(&PseudoState::Selected)) {
+                        commands
+                            .entity(hierarchy.scroll_view)
+                            .scroll_into_view(foldable, ScrollAlignment::Nearest);
+                    }
+
                     commands
                         .entity(foldable)
                         .add_pseudo_state(PseudoState::Selected);
@@ -207,9 +225,10 @@ fn spawn_hierarchy_level(
         Err(_) => format!("[{}]", entity),
     };
 
+    let accessible = AccessibleWidget::new(Role::TreeItem).named(name.clone());
     container
         .foldable(name, false, false, |_| {})
-        .insert(HierarchyNode { hierarchy, entity });
+        .insert((HierarchyNode { hierarchy, entity }, accessible));
 }
 
 #[derive(Component, Debug, Reflect)]
@@ -244,21 +263,25 @@ pub struct HierarchyContainer {
     root: Entity,
     selected: Option<Entity>,
     component_list: Entity,
+    scroll_view: Entity,
 }
 
 impl HierarchyContainer {
     pub fn foldable_theme() -> Theme<Foldable> {
-        let base_theme = PseudoTheme::deferred(None, HierarchyContainer::foldable_style);
+        let base_theme = PseudoTheme::deferred(None, HierarchyContainer::foldable_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
         let selected_theme = PseudoTheme::deferred(
             vec![PseudoState::Selected],
             HierarchyContainer::selected_style,
-        );
+        )
+        .depends_on(ThemeDataSections::COLORS);
 
         Theme::new(vec![base_theme, selected_theme])
     }
 
     pub fn refresh_button_theme() -> Theme<MenuItem> {
-        let base_theme = PseudoTheme::deferred(None, HierarchyContainer::refresh_button_style);
+        let base_theme = PseudoTheme::deferred(None, HierarchyContainer::refresh_button_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
 
         Theme::new(vec![base_theme])
     }
@@ -302,11 +325,13 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
             column.insert(HierarchyContainer::foldable_theme());
             column.style().width(Val::Percent(100.));
 
+            let mut scroll_view_id = Entity::PLACEHOLDER;
             let main_zone = column
                 .sized_zone(
                     SizedZoneConfig {
                         size: 70.,
                         min_size: 200.,
+                        ..default()
                     },
                     |zone| {
                         let hierarchy_id = zone.id();
@@ -327,21 +352,23 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
                                 })
                                 .insert(HierarchyContainer::refresh_button_theme());
 
-                            panel.scroll_view(None, |scroll_view| {
-                                let node_container = scroll_view
-                                    .column(|_| {})
-                                    .insert(HierarchyNodeContainer {
-                                        hierarchy: hierarchy_id,
-                                    })
-                                    .id();
-
-                                scroll_view.commands().entity(refresh_button).insert(
-                                    RefreshHierarchyButton {
-                                        hierarchy: hierarchy_id,
-                                        container: node_container,
-                                    },
-                                );
-                            });
+                            scroll_view_id = panel
+                                .scroll_view(None, |scroll_view| {
+                                    let node_container = scroll_view
+                                        .column(|_| {})
+                                        .insert(HierarchyNodeContainer {
+                                            hierarchy: hierarchy_id,
+                                        })
+                                        .id();
+
+                                    scroll_view.commands().entity(refresh_button).insert(
+                                        RefreshHierarchyButton {
+                                            hierarchy: hierarchy_id,
+                                            container: node_container,
+                                        },
+                                    );
+                                })
+                                .id();
                         });
                     },
                 )
@@ -364,6 +391,7 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
                     root: root_entity,
                     selected: None,
                     component_list,
+                    scroll_view: scroll_view_id,
                 },
             ));
         })
@@ -443,7 +443,8 @@ impl DefaultTheme for SceneControls {
 
 impl SceneControls {
     pub fn theme() -> Theme<SceneControls> {
-        let base_theme = PseudoTheme::deferred(None, SceneControls::primary_style);
+        let base_theme = PseudoTheme::deferred(None, SceneControls::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
         Theme::new(vec![base_theme])
     }
 
@@ -0,0 +1,388 @@
+use bevy::prelude::*;
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::{
+    inputs::checkbox::{Checkbox, UiCheckboxExt},
+    layout::{
+        column::UiColumnExt,
+        label::{LabelConfig, UiLabelExt},
+        row::UiRowExt,
+        scroll_view::UiScrollViewExt,
+    },
+    menus::menu_item::{MenuItem, MenuItemConfig, UiMenuItemExt},
+    WidgetLibraryUpdate,
+};
+
+pub struct ThemeDebuggerPlugin;
+
+impl Plugin for ThemeDebuggerPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, ThemeDebuggerUpdate.after(WidgetLibraryUpdate))
+            .add_systems(
+                Update,
+                (
+                    update_theme_debugger_on_button_press,
+                    pick_theme_debugger_target,
+                    toggle_pseudo_state_on_checkbox_change,
+                    update_theme_debugger_on_target_change,
+                    update_theme_debuggers,
+                )
+                    .chain()
+                    .in_set(ThemeDebuggerUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct ThemeDebuggerUpdate;
+
+fn update_theme_debugger_on_button_press(
+    q_menu_items: Query<(&MenuItem, &ThemeDebuggerButton), Changed<MenuItem>>,
+    mut q_debuggers: Query<&mut ThemeDebugger>,
+) {
+    for (menu_item, button) in &q_menu_items {
+        if !menu_item.interacted() {
+            continue;
+        }
+
+        let Ok(mut debugger) = q_debuggers.get_mut(button.debugger) else {
+            continue;
+        };
+
+        match button.action {
+            ThemeDebuggerAction::Pick => debugger.picking = !debugger.picking,
+            ThemeDebuggerAction::Refresh => debugger.set_changed(),
+        }
+    }
+}
+
+fn pick_theme_debugger_target(
+    q_interactions: Query<(Entity, &Interaction), Changed<Interaction>>,
+    q_parents: Query<&Parent>,
+    mut q_debuggers: Query<(Entity, &mut ThemeDebugger)>,
+) {
+    for (debugger_id, mut debugger) in &mut q_debuggers {
+        if !debugger.picking {
+            continue;
+        }
+
+        // Widgets of the debugger itself cannot be picked
+        let Some((picked, _)) = q_interactions.iter().find(|(entity, interaction)| {
+            **interaction == Interaction::Pressed
+                && *entity != debugger_id
+                && !q_parents
+                    .iter_ancestors(*entity)
+                    .any(|ancestor| ancestor == debugger_id)
+        }) else {
+            continue;
+        };
+
+        debugger.entity = picked.into();
+        debugger.picking = false;
+    }
+}
+
+fn toggle_pseudo_state_on_checkbox_change(
+    q_checkboxes: Query<(&Checkbox, &ThemeDebuggerPseudoStateToggle), Changed<Checkbox>>,
+    mut commands: Commands,
+) {
+    for (checkbox, toggle) in &q_checkboxes {
+        let Some(mut target) = commands.get_entity(toggle.target) else {
+            continue;
+        };
+
+        match checkbox.checked {
+            true => target.add_pseudo_state(toggle.state.clone()),
+            false => target.remove_pseudo_state(toggle.state.clone()),
+        };
+    }
+}
+
+type ChangedThemeTarget = Or<(Changed<PseudoStates>, Changed<AppliedDynamicStyle>)>;
+
+fn update_theme_debugger_on_target_change(
+    q_changed_targets: Query<Entity, ChangedThemeTarget>,
+    mut q_debuggers: Query<&mut ThemeDebugger>,
+) {
+    for mut debugger in &mut q_debuggers {
+        let Some(entity) = debugger.entity else {
+            continue;
+        };
+
+        if q_changed_targets.contains(entity) {
+            debugger.set_changed();
+        }
+    }
+}
+
+fn update_theme_debuggers(world: &mut World) {
+    let changed: Vec<(Entity, Option<Entity>, bool)> = world
+        .query::<Ref<ThemeDebugger>>()
+        .iter(world)
+        .filter(|debugger| debugger.is_changed())
+        .map(|debugger| (debugger.content, debugger.entity, debugger.picking))
+        .collect();
+
+    for (content, selected_entity, picking) in changed.iter().copied() {
+        update_theme_debugger(content, selected_entity, picking, world);
+    }
+}
+
+fn update_theme_debugger(
+    content: Entity,
+    selected_entity: Option<Entity>,
+    picking: bool,
+    world: &mut World,
+) {
+    let selected_entity = selected_entity.filter(|entity| world.get_entity(*entity).is_some());
+    let title = match selected_entity {
+        Some(entity) => match world.get::<Name>(entity) {
+            Some(name) => format!("[{}] {}", entity, name),
+            None => format!("[{}]", entity),
+        },
+        None => "No entity selected".into(),
+    };
+    let inspections = match selected_entity {
+        Some(entity) => world.resource::<ThemeRegistry>().inspect(entity, world),
+        None => Vec::new(),
+    };
+
+    world.commands().entity(content).despawn_descendants();
+    let mut commands = world.commands();
+    let mut builder = commands.ui_builder(content);
+
+    if picking {
+        builder.label(LabelConfig {
+            label: "Press a widget to inspect it".into(),
+            color: Color::srgb(1., 0.8, 0.2),
+            ..default()
+        });
+    }
+
+    builder.label(LabelConfig::from(title));
+
+    let Some(entity) = selected_entity else {
+        world.flush();
+        return;
+    };
+
+    if inspections.is_empty() {
+        builder.label(LabelConfig {
+            label: "The entity has no registered themed component".into(),
+            color: ThemeDebugger::INACTIVE_COLOR,
+            ..default()
+        });
+    }
+
+    for inspection in inspections.iter() {
+        section_header(
+            &mut builder,
+            UiUtils::simplify_type_name(inspection.component),
+        );
+
+        section_header(&mut builder, "Pseudo states");
+        let mut toggled_states: Vec<PseudoState> = inspection.pseudo_states.clone();
+        for pseudo_theme in inspection.pseudo_themes.iter() {
+            let Some(states) = &pseudo_theme.state else {
+                continue;
+            };
+
+            for state in states {
+                if !toggled_states.contains(state) {
+                    toggled_states.push(state.clone());
+                }
+            }
+        }
+
+        builder
+            .row(|row| {
+                for state in toggled_states {
+                    let checked = inspection.pseudo_states.contains(&state);
+                    row.checkbox(format!("{:?}", state), checked)
+                        .insert(ThemeDebuggerPseudoStateToggle {
+                            target: entity,
+                            state,
+                        });
+                }
+            })
+            .style()
+            .flex_wrap(FlexWrap::Wrap);
+
+        section_header(&mut builder, "Pseudo themes");
+        for pseudo_theme in inspection.pseudo_themes.iter() {
+            let state = match &pseudo_theme.state {
+                Some(states) if !states.is_empty() => format!("{:?}", states),
+                _ => "Base".into(),
+            };
+
+            builder.label(LabelConfig {
+                label: format!(
+                    "{} from {} - score: {}{}",
+                    state,
+                    source_name(pseudo_theme.source),
+                    pseudo_theme.score,
+                    match pseudo_theme.matched {
+                        true => " (applied)",
+                        false => "",
+                    }
+                ),
+                color: match pseudo_theme.matched {
+                    true => ThemeDebugger::ACTIVE_COLOR,
+                    false => ThemeDebugger::INACTIVE_COLOR,
+                },
+                wrap: FlexWrap::Wrap,
+                ..default()
+            });
+        }
+
+        section_header(&mut builder, "Attributes");
+        for attribute in inspection.attributes.iter() {
+            let placement = match attribute.placement {
+                Some(placement) => format!("[{}] ", placement),
+                None => "".into(),
+            };
+            let target = match attribute.attribute.target() {
+                Some(target) => format!("-> [{}] ", target),
+                None => "".into(),
+            };
+
+            builder.label(LabelConfig {
+                label: format!(
+                    "{}{}{} from {}",
+                    placement,
+                    target,
+                    attribute.description(),
+                    source_name(attribute.source)
+                ),
+                wrap: FlexWrap::Wrap,
+                ..default()
+            });
+
+            if let Some(animation_state) = &attribute.animation_state {
+                builder.label(LabelConfig {
+                    label: format!("    {:?}", animation_state),
+                    color: ThemeDebugger::INACTIVE_COLOR,
+                    wrap: FlexWrap::Wrap,
+                    ..default()
+                });
+            }
+        }
+
+        section_header(&mut builder, "Locked attributes");
+        if inspection.locked_attributes.is_empty() {
+            builder.label(LabelConfig {
+                label: "None".into(),
+                color: ThemeDebugger::INACTIVE_COLOR,
+                ..default()
+            });
+        }
+
+        for locked in inspection.locked_attributes.iter() {
+            builder.label(LabelConfig::from(format!("{:?}", locked)));
+        }
+    }
+
+    world.flush();
+}
+
+fn section_header(builder: &mut UiBuilder<Entity>, label: impl Into<String>) {
+    builder.label(LabelConfig {
+        label: label.into(),
+        color: ThemeDebugger::HEADER_COLOR,
+        margin: UiRect::top(Val::Px(5.)),
+        ..default()
+    });
+}
+
+fn source_name(source: StyleSource) -> String {
+    match source {
+        StyleSource::Base => "default theme".into(),
+        StyleSource::Theme(entity) => format!("theme on [{}]", entity),
+        StyleSource::Inline => "inline style".into(),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+enum ThemeDebuggerAction {
+    Pick,
+    Refresh,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ThemeDebuggerButton {
+    debugger: Entity,
+    action: ThemeDebuggerAction,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ThemeDebuggerPseudoStateToggle {
+    target: Entity,
+    state: PseudoState,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ThemeDebugger {
+    pub entity: Option<Entity>,
+    pub picking: bool,
+    content: Entity,
+}
+
+impl ThemeDebugger {
+    const HEADER_COLOR: Color = Color::srgb(0.6, 0.8, 1.);
+    const ACTIVE_COLOR: Color = Color::srgb(0.6, 1., 0.6);
+    const INACTIVE_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+}
+
+pub trait UiThemeDebuggerExt {
+    fn theme_debugger(&mut self, entity: Option<Entity>) -> UiBuilder<Entity>;
+}
+
+impl UiThemeDebuggerExt for UiBuilder<'_, Entity> {
+    /// A dev panel showing how the registered themes resolve for `entity`.
+    ///
+    /// Lists the pseudo states, the candidate pseudo themes with their match score,
+    /// the merged attributes with their source and animation state, and the locked attributes.
+    /// Pseudo states can be toggled to preview their styles, and the inspected entity can be
+    /// picked by pressing a widget after pressing `Pick`.
+    fn theme_debugger(&mut self, entity: Option<Entity>) -> UiBuilder<Entity> {
+        self.column(|column| {
+            let debugger = column.id();
+            let mut content = Entity::PLACEHOLDER;
+
+            column.style().width(Val::Percent(100.)).height(Val::Percent(100.));
+            column.row(|row| {
+                for (name, action) in [
+                    ("Pick", ThemeDebuggerAction::Pick),
+                    ("Refresh", ThemeDebuggerAction::Refresh),
+                ] {
+                    row.menu_item(MenuItemConfig {
+                        name: name.into(),
+                        ..default()
+                    })
+                    .insert(ThemeDebuggerButton { debugger, action });
+                }
+            });
+
+            column.scroll_view(None, |scroll_view| {
+                content = scroll_view
+                    .column(|_| {})
+                    .style()
+                    .width(Val::Percent(100.))
+                    .id();
+            });
+
+            column.insert((
+                Name::new("Theme Debugger"),
+                ThemeDebugger {
+                    entity,
+                    picking: false,
+                    content,
+                },
+            ));
+        })
+    }
+}
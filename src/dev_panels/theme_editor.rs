@@ -0,0 +1,697 @@
+use bevy::{
+    prelude::*,
+    reflect::{DynamicEnum, DynamicVariant, GetPath, ReflectRef, TypeInfo, VariantInfo},
+};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::{
+    inputs::{
+        checkbox::{Checkbox, UiCheckboxExt},
+        dropdown::{Dropdown, UiDropdownExt},
+        radio_group::{RadioGroup, UiRadioGroupExt},
+        slider::{Slider, SliderConfig, UiSliderExt},
+    },
+    layout::{
+        column::UiColumnExt,
+        foldable::{Foldable, UiFoldableExt},
+        label::{LabelConfig, UiLabelExt},
+        row::UiRowExt,
+        scroll_view::UiScrollViewExt,
+    },
+    menus::menu_item::{MenuItem, MenuItemConfig, UiMenuItemExt},
+    WidgetLibraryUpdate,
+};
+
+const BUILT_IN_FONTS: [&str; 10] = [
+    "embedded://sickle_ui/fonts/FiraSans-Regular.ttf",
+    "embedded://sickle_ui/fonts/FiraSans-Bold.ttf",
+    "embedded://sickle_ui/fonts/FiraSans-Italic.ttf",
+    "embedded://sickle_ui/fonts/FiraSans-BoldItalic.ttf",
+    "embedded://sickle_ui/fonts/FiraSans-Medium.ttf",
+    "embedded://sickle_ui/fonts/FiraSans-MediumItalic.ttf",
+    "embedded://sickle_ui/fonts/FiraSansCondensed-Regular.ttf",
+    "embedded://sickle_ui/fonts/FiraSansCondensed-Bold.ttf",
+    "embedded://sickle_ui/fonts/FiraSansCondensed-Italic.ttf",
+    "embedded://sickle_ui/fonts/FiraSansCondensed-BoldItalic.ttf",
+];
+
+pub struct ThemeEditorPlugin;
+
+impl Plugin for ThemeEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, ThemeEditorUpdate.after(WidgetLibraryUpdate))
+            .add_systems(
+                Update,
+                (
+                    init_theme_editor_scheme_select,
+                    update_theme_editor_node_on_foldable_change,
+                    update_theme_data_on_slider_change,
+                    update_theme_data_on_checkbox_change,
+                    update_theme_data_on_dropdown_change,
+                    update_theme_data_on_scheme_change,
+                    update_theme_editor_swatches.run_if(resource_changed::<ThemeData>),
+                    export_theme_on_press,
+                )
+                    .chain()
+                    .in_set(ThemeEditorUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct ThemeEditorUpdate;
+
+fn init_theme_editor_scheme_select(
+    q_editors: Query<&ThemeEditor, Added<ThemeEditor>>,
+    mut q_radio_groups: Query<&mut RadioGroup>,
+    theme_data: Res<ThemeData>,
+) {
+    for editor in &q_editors {
+        let (scheme, contrast) = ThemeEditor::scheme_index(theme_data.active_scheme);
+        if let Ok(mut radio_group) = q_radio_groups.get_mut(editor.scheme_select) {
+            radio_group.select(scheme);
+        }
+        if let Ok(mut radio_group) = q_radio_groups.get_mut(editor.contrast_select) {
+            radio_group.select(contrast);
+        }
+    }
+}
+
+fn update_theme_editor_node_on_foldable_change(
+    mut q_foldables: Query<(&ThemeEditorNode, &mut Foldable), Changed<Foldable>>,
+    theme_data: Res<ThemeData>,
+    mut commands: Commands,
+) {
+    for (node, mut foldable) in &mut q_foldables {
+        if foldable.empty {
+            continue;
+        }
+
+        commands.entity(foldable.container()).despawn_descendants();
+
+        if !foldable.open {
+            continue;
+        }
+
+        let Ok(value) = theme_data.reflect_path(node.path.as_str()) else {
+            warn!("Theme data has no field at {}", node.path);
+            foldable.empty = true;
+            continue;
+        };
+
+        let mut builder = commands.ui_builder(foldable.container());
+        spawn_theme_editor_fields(&node.path, value, &mut builder);
+    }
+}
+
+fn update_theme_data_on_slider_change(
+    q_sliders: Query<(&Slider, &ThemeEditorField), Changed<Slider>>,
+    mut theme_data: ResMut<ThemeData>,
+) {
+    for (slider, field) in &q_sliders {
+        let value = slider.value();
+        let Ok(current) = theme_data.reflect_path(field.path.as_str()) else {
+            continue;
+        };
+
+        match field.kind {
+            ThemeEditorFieldKind::Number => {
+                let Some(current) = current.downcast_ref::<f32>() else {
+                    continue;
+                };
+
+                if *current == value {
+                    continue;
+                }
+
+                if let Ok(target) = theme_data.reflect_path_mut(field.path.as_str()) {
+                    target.apply(&value);
+                }
+            }
+            ThemeEditorFieldKind::ColorChannel(channel) => {
+                let Some(current) = current.downcast_ref::<Color>() else {
+                    continue;
+                };
+
+                let mut srgba = current.to_srgba();
+                let component = match channel {
+                    0 => &mut srgba.red,
+                    1 => &mut srgba.green,
+                    2 => &mut srgba.blue,
+                    _ => &mut srgba.alpha,
+                };
+
+                if *component == value {
+                    continue;
+                }
+
+                *component = value;
+                if let Ok(target) = theme_data.reflect_path_mut(field.path.as_str()) {
+                    target.apply(&Color::Srgba(srgba));
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn update_theme_data_on_checkbox_change(
+    q_checkboxes: Query<(&Checkbox, &ThemeEditorField), Changed<Checkbox>>,
+    mut theme_data: ResMut<ThemeData>,
+) {
+    for (checkbox, field) in &q_checkboxes {
+        let Ok(current) = theme_data.reflect_path(field.path.as_str()) else {
+            continue;
+        };
+
+        if current.downcast_ref::<bool>() == Some(&checkbox.checked) {
+            continue;
+        }
+
+        if let Ok(target) = theme_data.reflect_path_mut(field.path.as_str()) {
+            target.apply(&checkbox.checked);
+        }
+    }
+}
+
+fn update_theme_data_on_dropdown_change(
+    q_dropdowns: Query<(&Dropdown, &ThemeEditorField), Changed<Dropdown>>,
+    mut theme_data: ResMut<ThemeData>,
+) {
+    for (dropdown, field) in &q_dropdowns {
+        let Some(value) = dropdown.value() else {
+            continue;
+        };
+        let Ok(current) = theme_data.reflect_path(field.path.as_str()) else {
+            continue;
+        };
+
+        match &field.kind {
+            ThemeEditorFieldKind::Font(options) => {
+                let Some(font) = options.get(value) else {
+                    continue;
+                };
+
+                if current.downcast_ref::<String>() == Some(font) {
+                    continue;
+                }
+
+                if let Ok(target) = theme_data.reflect_path_mut(field.path.as_str()) {
+                    target.apply(font);
+                }
+            }
+            ThemeEditorFieldKind::Variant(options) => {
+                let Some(variant) = options.get(value) else {
+                    continue;
+                };
+                let ReflectRef::Enum(current) = current.reflect_ref() else {
+                    continue;
+                };
+
+                if current.variant_name() == variant {
+                    continue;
+                }
+
+                if let Ok(target) = theme_data.reflect_path_mut(field.path.as_str()) {
+                    target.apply(&DynamicEnum::new(variant, DynamicVariant::Unit));
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn update_theme_data_on_scheme_change(
+    q_radio_groups: Query<&ThemeEditorSchemeSelect, Changed<RadioGroup>>,
+    q_editors: Query<&ThemeEditor>,
+    q_radio_group: Query<&RadioGroup>,
+    mut theme_data: ResMut<ThemeData>,
+) {
+    for select in &q_radio_groups {
+        let Ok(editor) = q_editors.get(select.editor) else {
+            continue;
+        };
+        let Ok(scheme) = q_radio_group.get(editor.scheme_select) else {
+            continue;
+        };
+        let Ok(contrast) = q_radio_group.get(editor.contrast_select) else {
+            continue;
+        };
+
+        // Selections are initialized from the active scheme once the editor is spawned
+        let (Some(scheme), Some(contrast)) = (scheme.selected(), contrast.selected()) else {
+            continue;
+        };
+
+        let contrast = match contrast {
+            1 => Contrast::Medium,
+            2 => Contrast::High,
+            _ => Contrast::Standard,
+        };
+        let scheme = match scheme {
+            0 => Scheme::Light(contrast),
+            _ => Scheme::Dark(contrast),
+        };
+
+        if theme_data.active_scheme != scheme {
+            theme_data.active_scheme = scheme;
+        }
+    }
+}
+
+fn update_theme_editor_swatches(
+    mut q_swatches: Query<(&ThemeEditorSwatch, &mut BackgroundColor)>,
+    theme_data: Res<ThemeData>,
+) {
+    for (swatch, mut background_color) in &mut q_swatches {
+        let Ok(color) = theme_data.path::<Color>(swatch.path.as_str()) else {
+            continue;
+        };
+
+        if background_color.0 != *color {
+            background_color.0 = *color;
+        }
+    }
+}
+
+fn export_theme_on_press(
+    q_menu_items: Query<(&MenuItem, &ThemeEditorExportButton), Changed<MenuItem>>,
+    q_editors: Query<&ThemeEditor>,
+    theme_data: Res<ThemeData>,
+) {
+    for (menu_item, button) in &q_menu_items {
+        if !menu_item.interacted() {
+            continue;
+        }
+
+        let Ok(editor) = q_editors.get(button.editor) else {
+            continue;
+        };
+
+        let (path, json) = match button.export {
+            ThemeEditorExport::Colors => (
+                &editor.config.colors_path,
+                serde_json::to_string_pretty(&theme_data.colors),
+            ),
+            ThemeEditorExport::Bundle => (
+                &editor.config.bundle_path,
+                serde_json::to_string_pretty(&ThemeBundle::from(theme_data.as_ref())),
+            ),
+        };
+
+        let result = match json {
+            Ok(json) => std::fs::write(path, json).map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+
+        match result {
+            Ok(_) => info!("Theme exported to {}", path),
+            Err(err) => warn!("Failed to export theme to {}: {}", path, err),
+        }
+    }
+}
+
+/// Spawns the editors of all fields of `value`. Nested structures get a foldable
+/// that builds its content when opened.
+fn spawn_theme_editor_fields(path: &str, value: &dyn Reflect, builder: &mut UiBuilder<Entity>) {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for i in 0..value.field_len() {
+                // Safe unwraps: i is in range
+                let name = value.name_at(i).unwrap();
+                let field = value.field_at(i).unwrap();
+                spawn_theme_editor_entry(name, &join_path(path, name), field, builder);
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            for (i, field) in value.iter_fields().enumerate() {
+                let name = i.to_string();
+                spawn_theme_editor_entry(&name, &join_path(path, &name), field, builder);
+            }
+        }
+        ReflectRef::List(value) => {
+            for (i, item) in value.iter().enumerate() {
+                let name = format!("[{}]", i);
+                spawn_theme_editor_entry(&name, &format!("{}{}", path, name), item, builder);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            for i in 0..value.field_len() {
+                let name = match value.name_at(i) {
+                    Some(name) => name.to_string(),
+                    None => i.to_string(),
+                };
+                // Safe unwrap: i is in range
+                let field = value.field_at(i).unwrap();
+                spawn_theme_editor_entry(&name, &join_path(path, &name), field, builder);
+            }
+        }
+        _ => {
+            builder.label(LabelConfig::from(format!("{:?}", value)));
+        }
+    }
+}
+
+fn spawn_theme_editor_entry(
+    name: &str,
+    path: &str,
+    value: &dyn Reflect,
+    builder: &mut UiBuilder<Entity>,
+) {
+    let field = |kind| ThemeEditorField {
+        path: path.into(),
+        kind,
+    };
+
+    if let Some(value) = value.downcast_ref::<f32>() {
+        let span = (value.abs() * 4.).max(1.);
+        let min = match *value < 0. {
+            true => -span,
+            false => 0.,
+        };
+
+        builder
+            .slider(SliderConfig::horizontal(
+                name.to_string(),
+                min,
+                span,
+                *value,
+                true,
+            ))
+            .insert(field(ThemeEditorFieldKind::Number));
+    } else if let Some(color) = value.downcast_ref::<Color>() {
+        let srgba = color.to_srgba();
+        builder.row(|row| {
+            row.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(16.),
+                        height: Val::Px(16.),
+                        margin: UiRect::right(Val::Px(5.)),
+                        ..default()
+                    },
+                    background_color: (*color).into(),
+                    ..default()
+                },
+                ThemeEditorSwatch { path: path.into() },
+            ));
+            row.label(LabelConfig::from(name));
+        });
+
+        for (channel, (label, value)) in [
+            ("R", srgba.red),
+            ("G", srgba.green),
+            ("B", srgba.blue),
+            ("A", srgba.alpha),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            builder
+                .slider(SliderConfig::horizontal(
+                    label.to_string(),
+                    0.,
+                    1.,
+                    value.clamp(0., 1.),
+                    true,
+                ))
+                .insert(field(ThemeEditorFieldKind::ColorChannel(channel as u8)));
+        }
+    } else if let Some(value) = value.downcast_ref::<bool>() {
+        builder
+            .checkbox(name.to_string(), *value)
+            .insert(field(ThemeEditorFieldKind::Toggle));
+    } else if let Some(value) = value.downcast_ref::<String>() {
+        if path.contains("font") {
+            let mut options: Vec<String> = BUILT_IN_FONTS.iter().map(|f| f.to_string()).collect();
+            if !options.contains(value) {
+                options.push(value.clone());
+            }
+            // Safe unwrap: value is in the list
+            let selected = options.iter().position(|o| o == value).unwrap();
+            let labels: Vec<String> = options
+                .iter()
+                .map(|font| font.rsplit('/').next().unwrap_or(font).to_string())
+                .collect();
+
+            builder.label(LabelConfig::from(name));
+            builder
+                .dropdown(labels, selected)
+                .insert(field(ThemeEditorFieldKind::Font(options)));
+        } else {
+            builder.label(LabelConfig::from(format!("{}: {}", name, value)));
+        }
+    } else if let Some(variants) = unit_variants(value) {
+        let ReflectRef::Enum(current) = value.reflect_ref() else {
+            return;
+        };
+        let selected = variants
+            .iter()
+            .position(|variant| variant == current.variant_name());
+
+        builder.label(LabelConfig::from(name));
+        builder
+            .dropdown(variants.clone(), selected)
+            .insert(field(ThemeEditorFieldKind::Variant(variants)));
+    } else {
+        match value.reflect_ref() {
+            ReflectRef::Struct(_) | ReflectRef::TupleStruct(_) | ReflectRef::List(_) => {
+                builder
+                    .foldable(name, false, false, |_| {})
+                    .insert(ThemeEditorNode { path: path.into() });
+            }
+            ReflectRef::Enum(current) if current.field_len() > 0 => {
+                builder
+                    .foldable(
+                        format!("{} ({})", name, current.variant_name()),
+                        false,
+                        false,
+                        |_| {},
+                    )
+                    .insert(ThemeEditorNode { path: path.into() });
+            }
+            _ => {
+                builder.label(LabelConfig::from(format!("{}: {:?}", name, value)));
+            }
+        }
+    }
+}
+
+/// Returns the variant names of enums that only have unit variants.
+fn unit_variants(value: &dyn Reflect) -> Option<Vec<String>> {
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        return None;
+    };
+
+    if !info
+        .iter()
+        .all(|variant| matches!(variant, VariantInfo::Unit(_)))
+    {
+        return None;
+    }
+
+    Some(
+        info.iter()
+            .map(|variant| variant.name().to_string())
+            .collect(),
+    )
+}
+
+fn join_path(path: &str, name: &str) -> String {
+    match path.is_empty() {
+        true => name.into(),
+        false => format!("{}.{}", path, name),
+    }
+}
+
+#[derive(Clone, Debug, Reflect)]
+enum ThemeEditorFieldKind {
+    Number,
+    ColorChannel(u8),
+    Toggle,
+    Font(Vec<String>),
+    Variant(Vec<String>),
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ThemeEditorField {
+    path: String,
+    kind: ThemeEditorFieldKind,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ThemeEditorNode {
+    path: String,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ThemeEditorSwatch {
+    path: String,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ThemeEditorSchemeSelect {
+    editor: Entity,
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+enum ThemeEditorExport {
+    Colors,
+    Bundle,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ThemeEditorExportButton {
+    editor: Entity,
+    export: ThemeEditorExport,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct ThemeEditorConfig {
+    /// Where `ThemeColors` are exported, in the format `ThemeColorsLoader` reads.
+    pub colors_path: String,
+    /// Where the whole theme is exported as a `ThemeBundle`.
+    pub bundle_path: String,
+}
+
+impl Default for ThemeEditorConfig {
+    fn default() -> Self {
+        Self {
+            colors_path: "assets/themes/exported-theme.json".into(),
+            bundle_path: "assets/themes/exported-theme-bundle.json".into(),
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ThemeEditor {
+    pub config: ThemeEditorConfig,
+    scheme_select: Entity,
+    contrast_select: Entity,
+}
+
+impl ThemeEditor {
+    const SECTIONS: [&'static str; 7] = [
+        "colors",
+        "spacing",
+        "text",
+        "icons",
+        "interaction_animation",
+        "delayed_interaction_animation",
+        "enter_animation",
+    ];
+
+    fn scheme_index(scheme: Scheme) -> (usize, usize) {
+        let (scheme, contrast) = match scheme {
+            Scheme::Light(contrast) => (0, contrast),
+            Scheme::Dark(contrast) => (1, contrast),
+        };
+        let contrast = match contrast {
+            Contrast::Standard => 0,
+            Contrast::Medium => 1,
+            Contrast::High => 2,
+        };
+
+        (scheme, contrast)
+    }
+
+    fn spawn_gallery(builder: &mut UiBuilder<Entity>) {
+        builder.label(LabelConfig::from("Preview"));
+        builder.menu_item(MenuItemConfig {
+            name: "Menu item".into(),
+            ..default()
+        });
+        builder.checkbox("Checkbox".to_string(), true);
+        builder.radio_group(vec!["First", "Second", "Third"], 0, false);
+        builder.slider(SliderConfig::horizontal(
+            "Slider".to_string(),
+            0.,
+            100.,
+            50.,
+            true,
+        ));
+        builder.dropdown(vec!["Option A", "Option B", "Option C"], 0);
+        builder.foldable("Foldable", true, false, |foldable| {
+            foldable.label(LabelConfig::from("Foldable content"));
+        });
+    }
+}
+
+pub trait UiThemeEditorExt {
+    fn theme_editor(&mut self, config: ThemeEditorConfig) -> UiBuilder<Entity>;
+}
+
+impl UiThemeEditorExt for UiBuilder<'_, Entity> {
+    /// A dev panel to edit the [`ThemeData`] resource live.
+    ///
+    /// Sections are built when their foldable is opened and a gallery of widgets
+    /// previews the changes. Colors are exported in the Material format the theme
+    /// colors loader reads, or the whole theme as a loadable [`ThemeBundle`].
+    fn theme_editor(&mut self, config: ThemeEditorConfig) -> UiBuilder<Entity> {
+        self.column(|column| {
+            let editor = column.id();
+            let mut scheme_select = Entity::PLACEHOLDER;
+            let mut contrast_select = Entity::PLACEHOLDER;
+
+            column
+                .style()
+                .width(Val::Percent(100.))
+                .height(Val::Percent(100.));
+            column.row(|row| {
+                for (name, export) in [
+                    ("Export Colors", ThemeEditorExport::Colors),
+                    ("Export Bundle", ThemeEditorExport::Bundle),
+                ] {
+                    row.menu_item(MenuItemConfig {
+                        name: name.into(),
+                        ..default()
+                    })
+                    .insert(ThemeEditorExportButton { editor, export });
+                }
+            });
+
+            column.scroll_view(None, |scroll_view| {
+                scroll_view
+                    .column(|column| {
+                        column.label(LabelConfig::from("Scheme"));
+                        scheme_select = column
+                            .radio_group(vec!["Light", "Dark"], None, false)
+                            .insert(ThemeEditorSchemeSelect { editor })
+                            .id();
+                        contrast_select = column
+                            .radio_group(vec!["Standard", "Medium", "High"], None, false)
+                            .insert(ThemeEditorSchemeSelect { editor })
+                            .id();
+
+                        for section in ThemeEditor::SECTIONS {
+                            column.foldable(section, false, false, |_| {}).insert(
+                                ThemeEditorNode {
+                                    path: section.into(),
+                                },
+                            );
+                        }
+
+                        ThemeEditor::spawn_gallery(column);
+                    })
+                    .style()
+                    .width(Val::Percent(100.));
+            });
+
+            column.insert((
+                Name::new("Theme Editor"),
+                ThemeEditor {
+                    config,
+                    scheme_select,
+                    contrast_select,
+                },
+            ));
+        })
+    }
+}
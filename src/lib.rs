@@ -5,6 +5,7 @@ pub mod widgets;
 
 use bevy::prelude::*;
 
+use accessibility::AccessibleWidgetPlugin;
 use assets::BuiltInAssetsPlugin;
 use drag_interaction::DragInteractionPlugin;
 use drop_interaction::DropInteractionPlugin;
@@ -32,6 +33,7 @@ pub struct SickleUiPlugin;
 impl Plugin for SickleUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            AccessibleWidgetPlugin,
             BuiltInAssetsPlugin,
             DragInteractionPlugin,
             DropInteractionPlugin,
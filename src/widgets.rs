@@ -12,15 +12,24 @@ use self::{
     layout::docking_zone::DockingZonePlugin,
     layout::floating_panel::{FloatingPanelPlugin, FloatingPanelUpdate},
     layout::foldable::FoldablePlugin,
+    layout::notification::NotificationPlugin,
+    layout::progress_bar::ProgressBarPlugin,
     layout::resize_handles::ResizeHandlePlugin,
     layout::scroll_view::ScrollViewPlugin,
     layout::sized_zone::SizedZonePlugin,
+    layout::split_pane::SplitPanePlugin,
+    layout::status_bar::StatusBarPlugin,
     layout::tab_container::TabContainerPlugin,
+    layout::toolbar::ToolbarPlugin,
     menus::context_menu::ContextMenuPlugin,
     menus::menu::MenuPlugin,
     menus::menu_bar::MenuBarPlugin,
     menus::menu_item::MenuItemPlugin,
+    menus::menu_model::MenuModelPlugin,
+    menus::menu_navigation::MenuNavigationPlugin,
+    menus::menu_scroll::MenuScrollPlugin,
     menus::menu_separators::MenuSeparatorPlugin,
+    menus::radio_menu_item::RadioMenuItemPlugin,
     menus::shortcut::ShortcutPlugin,
     menus::submenu::SubmenuPlugin,
     menus::toggle_menu_item::ToggleMenuItemPlugin,
@@ -34,29 +43,71 @@ pub mod prelude {
         inputs::slider::{Slider, SliderConfig, UiSliderExt},
         layout::column::UiColumnExt,
         layout::container::UiContainerExt,
-        layout::docking_zone::UiDockingZoneExt,
+        layout::docking_zone::{DockingCommandsExt, UiDockingZoneExt},
         layout::floating_panel::{
-            FloatingPanelConfig, FloatingPanelLayout, FloatingPanelUpdate, UiFloatingPanelExt,
+            FloatingPanelBehavior, FloatingPanelCommandsExt, FloatingPanelConfig,
+            FloatingPanelLayout, FloatingPanelPlacement, FloatingPanelState, FloatingPanelTray,
+            FloatingPanelUpdate, FloatingPanelZOrder, UiFloatingPanelExt,
+        },
+        layout::foldable::{
+            Foldable, FoldableConfig, FoldableLazyContent, FoldableUpdate, UiFoldableExt,
         },
-        layout::foldable::{Foldable, FoldableUpdate, UiFoldableExt},
         layout::icon::UiIconExt,
         layout::label::{LabelConfig, UiLabelExt},
-        layout::panel::UiPanelExt,
+        layout::notification::{
+            NotificationUpdate, Notifications, Toast, ToastActionPressed, ToastConfig, ToastCorner,
+            ToastDismissed, ToastId, ToastSeverity,
+        },
+        layout::panel::{PanelKey, UiPanelExt},
+        layout::progress_bar::{
+            ProgressBar, ProgressBarConfig, ProgressBarUpdate, UiProgressBarExt,
+        },
         layout::resize_handles::{ResizeHandle, ResizeHandles, UiResizeHandlesExt},
         layout::row::UiRowExt,
-        layout::scroll_view::{ScrollViewLayoutUpdate, UiScrollViewExt},
-        layout::sized_zone::{SizedZoneConfig, SizedZonePreUpdate, UiSizedZoneExt},
-        layout::tab_container::{TabContainerUpdate, UiTabContainerExt, UiTabContainerSubExt},
+        layout::scroll_view::{
+            ScrollAlignment, ScrollChaining, ScrollChainingExt, ScrollIntoViewExt,
+            ScrollViewConfig, ScrollViewLayoutUpdate, UiScrollViewExt,
+        },
+        layout::sized_zone::{SizedZoneConfig, SizedZoneMode, SizedZonePreUpdate, UiSizedZoneExt},
+        layout::split_pane::{
+            SplitPaneConfig, SplitPaneDirection, SplitPaneItemConfig, SplitPaneRatios,
+            SplitPaneUpdate, UiSplitPaneExt, UiSplitPaneSubExt,
+        },
+        layout::status_bar::{
+            StatusBar, StatusBarSection, StatusBarUpdate, StatusItem, StatusItemClicked,
+            StatusItemConfig, UiStatusBarExt, UiStatusBarSubExt,
+        },
+        layout::tab_container::{
+            TabCommandsExt, TabContainerUpdate, UiTabContainerExt, UiTabContainerSubExt,
+        },
+        layout::toolbar::{
+            Toolbar, ToolbarButton, ToolbarButtonConfig, ToolbarButtonPressed, ToolbarConfig,
+            ToolbarGroupChanged, ToolbarItem, ToolbarOrientation, ToolbarToggleConfig,
+            ToolbarUpdate, UiToolbarExt, UiToolbarSubExt,
+        },
         menus::context_menu::{
-            ContextMenuGenerator, ContextMenuUpdate, ReflectContextMenuGenerator, UiContextMenuExt,
+            ContextMenuEntries, ContextMenuGenerator, ContextMenuUpdate, LongPressConfig,
+            ReflectContextMenuGenerator, UiContextMenuExt,
         },
         menus::extra_menu::{ExtraMenu, UiExtraMenuExt},
-        menus::menu::{MenuConfig, MenuUpdate, UiMenuExt, UiMenuSubExt},
+        menus::menu::{MenuConfig, MenuOpenChanged, MenuUpdate, UiMenuExt, UiMenuSubExt},
         menus::menu_bar::{UiMenuBarExt, UiMenuBarSubExt},
-        menus::menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
+        menus::menu_item::{KeepMenuOpen, MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
+        menus::menu_model::{
+            ActionTriggered, MenuAction, MenuActions, MenuModel, MenuModelAction, MenuModelEntry,
+            MenuModelMenu, MenuModelSubmenu, MenuModelUpdate, UiMenuModelExt,
+        },
+        menus::menu_navigation::{MenuMnemonic, MenuNavigation, MenuNavigationUpdate},
+        menus::menu_scroll::{MenuScroll, MenuScrollUpdate},
         menus::menu_separators::{UiMenuItemSeparatorExt, UiMenuSeparatorExt},
+        menus::radio_menu_item::{
+            RadioMenuGroupChanged, RadioMenuItem, RadioMenuItemConfig, RadioMenuItemUpdate,
+            UiRadioMenuItemExt,
+        },
         menus::shortcut::{Shortcut, ShortcutPreUpdate},
-        menus::submenu::{SubmenuConfig, SubmenuUpdate, UiSubmenuExt, UiSubmenuSubExt},
+        menus::submenu::{
+            SubmenuBehavior, SubmenuConfig, SubmenuUpdate, UiSubmenuExt, UiSubmenuSubExt,
+        },
         menus::toggle_menu_item::{
             ToggleMenuItemConfig, ToggleMenuItemUpdate, UiToggleMenuItemExt,
         },
@@ -81,16 +132,25 @@ impl Plugin for WidgetsPlugin {
                 FloatingPanelPlugin,
                 FoldablePlugin,
                 MenuPlugin,
+                MenuNavigationPlugin,
+                MenuScrollPlugin,
+                NotificationPlugin,
+                ProgressBarPlugin,
+                StatusBarPlugin,
+                ToolbarPlugin,
             ))
             .add_plugins((
                 MenuBarPlugin,
                 MenuItemPlugin,
+                MenuModelPlugin,
                 MenuSeparatorPlugin,
+                RadioMenuItemPlugin,
                 RadioGroupPlugin,
                 ResizeHandlePlugin,
                 ShortcutPlugin,
                 SliderPlugin,
                 ScrollViewPlugin,
+                SplitPanePlugin,
                 SubmenuPlugin,
                 TabContainerPlugin,
                 ToggleMenuItemPlugin,
@@ -1,4 +1,4 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{a11y::accesskit::Role, prelude::*, ui::FocusPolicy};
 
 use sickle_ui_scaffold::prelude::*;
 use crate::widgets::layout::{
@@ -126,7 +126,11 @@ impl Checkbox {
     pub fn theme() -> Theme<Checkbox> {
         let base_theme = PseudoTheme::deferred(None, Checkbox::primary_style);
         let checked_theme =
-            PseudoTheme::deferred(vec![PseudoState::Checked], Checkbox::checked_style);
+            PseudoTheme::deferred(vec![PseudoState::Checked], Checkbox::checked_style).depends_on(
+                ThemeDataSections::COLORS
+                    | ThemeDataSections::SPACING
+                    | ThemeDataSections::ANIMATIONS,
+            );
         Theme::new(vec![base_theme, checked_theme])
     }
 
@@ -308,6 +312,7 @@ impl UiCheckboxExt for UiBuilder<'_, Entity> {
             true => format!("Checkbox [{}]", label.clone()),
             false => "Checkbox".into(),
         };
+        let accessible = AccessibleWidget::new(Role::CheckBox).named(label.clone());
 
         let mut input = self.container(Checkbox::checkbox_container(name), |container| {
             checkbox.checkmark_background = container
@@ -323,7 +328,7 @@ impl UiCheckboxExt for UiBuilder<'_, Entity> {
                 .id();
         });
 
-        input.insert(checkbox);
+        input.insert((checkbox, accessible));
 
         input
     }
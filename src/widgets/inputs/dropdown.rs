@@ -1,14 +1,21 @@
 use std::collections::VecDeque;
 
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{
+    a11y::{
+        accesskit::{Action, Role},
+        ActionRequest,
+    },
+    prelude::*,
+    ui::FocusPolicy,
+};
 
-use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
+use sickle_ui_scaffold::{accessibility::action_target, prelude::*, ui_commands::UpdateTextExt};
 
 use crate::widgets::layout::{
     container::UiContainerExt,
     label::{LabelConfig, UiLabelExt},
     panel::UiPanelExt,
-    scroll_view::{ScrollView, ScrollViewLayoutUpdate, UiScrollViewExt},
+    scroll_view::{ScrollAlignment, ScrollView, ScrollViewLayoutUpdate, UiScrollViewExt},
 };
 
 const DROPDOWN_PANEL_Z_INDEX: usize = 11000;
@@ -33,6 +40,7 @@ impl Plugin for DropdownPlugin {
                 handle_option_press,
                 update_dropdown_label,
                 handle_click_or_touch,
+                handle_accessibility_action.run_if(resource_exists::<Events<ActionRequest>>),
                 update_drowdown_pseudo_state,
                 update_dropdown_panel_visibility,
             )
@@ -47,10 +55,13 @@ impl Plugin for DropdownPlugin {
 }
 
 fn update_dropdown_label(
-    mut q_dropdowns: Query<(&mut Dropdown, &DropdownOptions), Changed<Dropdown>>,
+    mut q_dropdowns: Query<
+        (&mut Dropdown, &DropdownOptions, &mut AccessibleWidget),
+        Changed<Dropdown>,
+    >,
     mut commands: Commands,
 ) {
-    for (mut dropdown, options) in &mut q_dropdowns {
+    for (mut dropdown, options, mut accessible) in &mut q_dropdowns {
         if let Some(value) = dropdown.value {
             if value >= options.0.len() {
                 dropdown.value = None;
@@ -63,6 +74,11 @@ fn update_dropdown_label(
             String::from("---")
         };
 
+        let text_value = dropdown.value.map(|value| options.0[value].clone());
+        if accessible.text_value != text_value {
+            accessible.text_value = text_value;
+        }
+
         commands.entity(dropdown.label).update_text(text);
     }
 }
@@ -97,10 +113,47 @@ fn handle_click_or_touch(
     }
 }
 
+fn handle_accessibility_action(
+    mut reader: EventReader<ActionRequest>,
+    q_options: Query<&DropdownOption>,
+    mut q_dropdowns: Query<&mut Dropdown>,
+) {
+    for request in reader.read() {
+        let Some(entity) = action_target(request) else {
+            continue;
+        };
+
+        if let Ok(option) = q_options.get(entity) {
+            // The option itself is pressed by the accessibility click
+            if request.action == Action::Default {
+                if let Ok(mut dropdown) = q_dropdowns.get_mut(option.dropdown) {
+                    dropdown.is_open = false;
+                }
+            }
+            continue;
+        }
+
+        let Ok(mut dropdown) = q_dropdowns.get_mut(entity) else {
+            continue;
+        };
+
+        let is_open = match request.action {
+            Action::Default => !dropdown.is_open,
+            Action::Expand => true,
+            Action::Collapse => false,
+            _ => continue,
+        };
+
+        if dropdown.is_open != is_open {
+            dropdown.is_open = is_open;
+        }
+    }
+}
+
 fn handle_option_press(
     q_options: Query<(&DropdownOption, &FluxInteraction), Changed<FluxInteraction>>,
     mut q_dropdown: Query<&mut Dropdown>,
-    mut commands: Commands
+    mut commands: Commands,
 ) {
     for (option, interaction) in &q_options {
         if *interaction == FluxInteraction::Released {
@@ -111,9 +164,12 @@ fn handle_option_press(
             dropdown.value = option.option.into();
 
             #[cfg(feature = "observable")]
-            commands.trigger_targets(DropdownChanged {
-                value: dropdown.value
-            }, option.dropdown);
+            commands.trigger_targets(
+                DropdownChanged {
+                    value: dropdown.value,
+                },
+                option.dropdown,
+            );
         }
     }
 }
@@ -137,6 +193,8 @@ fn update_drowdown_pseudo_state(
 
 fn update_dropdown_panel_visibility(
     q_dropdowns: Query<&Dropdown, Changed<Dropdown>>,
+    q_children: Query<&Children>,
+    q_option: Query<&DropdownOption>,
     mut q_scroll_view: Query<&mut ScrollView>,
     mut commands: Commands,
 ) {
@@ -153,6 +211,24 @@ fn update_dropdown_panel_visibility(
             };
 
             scroll_view.disabled = true;
+
+            // Show the selected option once the panel is open
+            let selected = dropdown.value.and_then(|value| {
+                q_children
+                    .get(dropdown.scroll_view_content)
+                    .ok()?
+                    .iter()
+                    .find(|child| {
+                        q_option
+                            .get(**child)
+                            .is_ok_and(|option| option.option == value)
+                    })
+                    .copied()
+            });
+
+            if let Some(option) = selected {
+                scroll_view.scroll_into_view(option, ScrollAlignment::Center);
+            }
         } else {
             commands
                 .style_unchecked(dropdown.panel)
@@ -248,7 +324,12 @@ impl DropdownOption {
     }
 
     pub fn theme() -> Theme<DropdownOption> {
-        let base_theme = PseudoTheme::deferred(None, DropdownOption::primary_style);
+        let base_theme = PseudoTheme::deferred(None, DropdownOption::primary_style).depends_on(
+            ThemeDataSections::COLORS
+                | ThemeDataSections::SPACING
+                | ThemeDataSections::TEXT
+                | ThemeDataSections::ANIMATIONS,
+        );
 
         Theme::new(vec![base_theme])
     }
@@ -387,7 +468,12 @@ impl Dropdown {
 
     pub fn theme() -> Theme<Dropdown> {
         let base_theme = PseudoTheme::deferred(None, Dropdown::primary_style);
-        let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Dropdown::open_style);
+        let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Dropdown::open_style)
+            .depends_on(
+                ThemeDataSections::COLORS
+                    | ThemeDataSections::SPACING
+                    | ThemeDataSections::ANIMATIONS,
+            );
 
         Theme::new(vec![base_theme, open_theme])
     }
@@ -844,11 +930,15 @@ impl UiDropdownExt for UiBuilder<'_, Entity> {
                                         })
                                         .id();
 
-                                    option.insert(DropdownOption {
-                                        dropdown: dropdown_id,
-                                        option: index,
-                                        label: label_id,
-                                    });
+                                    option.insert((
+                                        DropdownOption {
+                                            dropdown: dropdown_id,
+                                            option: index,
+                                            label: label_id,
+                                        },
+                                        AccessibleWidget::new(Role::ListBoxOption)
+                                            .named(label.clone()),
+                                    ));
                                 });
                             }
                         })
@@ -866,21 +956,25 @@ impl UiDropdownExt for UiBuilder<'_, Entity> {
                     ]),
                     PseudoStates::default(),
                     VisibilityToPseudoState,
+                    AccessibleWidget::new(Role::ListBox),
                 ))
                 .style_unchecked()
                 .focus_policy(bevy::ui::FocusPolicy::Block)
                 .id();
         });
 
-        dropdown.insert(Dropdown {
-            value: value.into(),
-            label: label_id,
-            icon: icon_id,
-            panel: panel_id,
-            scroll_view: scroll_view_id,
-            scroll_view_content: scroll_view_content_id,
-            ..default()
-        });
+        dropdown.insert((
+            Dropdown {
+                value: value.into(),
+                label: label_id,
+                icon: icon_id,
+                panel: panel_id,
+                scroll_view: scroll_view_id,
+                scroll_view_content: scroll_view_content_id,
+                ..default()
+            },
+            AccessibleWidget::new(Role::ComboBox),
+        ));
 
         dropdown
     }
@@ -1,4 +1,4 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{a11y::accesskit::Role, prelude::*, ui::FocusPolicy};
 
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
@@ -227,9 +227,19 @@ impl RadioButton {
     pub const LABEL: &'static str = "Label";
 
     pub fn theme() -> Theme<RadioButton> {
-        let base_theme = PseudoTheme::deferred(None, RadioButton::primary_style);
+        let base_theme = PseudoTheme::deferred(None, RadioButton::primary_style).depends_on(
+            ThemeDataSections::COLORS
+                | ThemeDataSections::SPACING
+                | ThemeDataSections::TEXT
+                | ThemeDataSections::ANIMATIONS,
+        );
         let checked_theme =
-            PseudoTheme::deferred(vec![PseudoState::Checked], RadioButton::checked_style);
+            PseudoTheme::deferred(vec![PseudoState::Checked], RadioButton::checked_style)
+                .depends_on(
+                    ThemeDataSections::COLORS
+                        | ThemeDataSections::SPACING
+                        | ThemeDataSections::ANIMATIONS,
+                );
         Theme::new(vec![base_theme, checked_theme])
     }
 
@@ -400,13 +410,15 @@ impl UiRadioGroupExt for UiBuilder<'_, Entity> {
             RadioGroup {
                 selected: selected.into(),
             },
+            AccessibleWidget::new(Role::RadioGroup),
         ));
 
         let mut index = 0;
         let group = radio_group.id();
         for option in options {
-            let label = option.into();
+            let label: String = option.into();
             let name = format!("Radio Button [{}]", label);
+            let accessible_name = label.clone();
             let mut radio_button = RadioButton {
                 checked: false,
                 unselectable,
@@ -425,7 +437,10 @@ impl UiRadioGroupExt for UiBuilder<'_, Entity> {
                         .id();
                     radio_button.label = button.label(LabelConfig { label, ..default() }).id();
                 })
-                .insert(radio_button);
+                .insert((
+                    radio_button,
+                    AccessibleWidget::new(Role::RadioButton).named(accessible_name),
+                ));
 
             index += 1;
         }
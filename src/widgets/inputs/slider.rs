@@ -1,8 +1,18 @@
 use std::ops::DerefMut;
 
-use bevy::{input::mouse::MouseScrollUnit, prelude::*, ui::RelativeCursorPosition};
+use bevy::{
+    a11y::{
+        accesskit::{Action, ActionData, Role},
+        ActionRequest,
+    },
+    input::mouse::MouseScrollUnit,
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
 
-use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
+use sickle_ui_scaffold::{
+    accessibility::action_target, prelude::*, ui_commands::UpdateTextExt,
+};
 use crate::widgets::layout::{
     container::UiContainerExt,
     label::{LabelConfig, UiLabelExt},
@@ -24,9 +34,12 @@ impl Plugin for SliderPlugin {
                 (
                     update_slider_on_scroll.after(ScrollableUpdate),
                     update_slider_on_drag.after(DraggableUpdate),
+                    update_slider_on_accessibility_action
+                        .run_if(resource_exists::<Events<ActionRequest>>),
                     update_slider_on_bar_change,
                     update_slider_handle,
                     update_slider_readout,
+                    update_slider_accessibility,
                 )
                     .chain(),
             );
@@ -141,6 +154,43 @@ fn update_slider_on_drag(
     }
 }
 
+fn update_slider_on_accessibility_action(
+    mut reader: EventReader<ActionRequest>,
+    mut q_slider: Query<&mut Slider>,
+    mut commands: Commands,
+) {
+    for request in reader.read() {
+        let Some(entity) = action_target(request) else {
+            continue;
+        };
+        let Ok(mut slider) = q_slider.get_mut(entity) else {
+            continue;
+        };
+
+        let ratio = match (request.action, &request.data) {
+            (Action::Increment, _) => slider.ratio + Slider::ACCESSIBILITY_STEP,
+            (Action::Decrement, _) => slider.ratio - Slider::ACCESSIBILITY_STEP,
+            (Action::SetValue, Some(ActionData::NumericValue(value))) => {
+                let config = &slider.config;
+                let range = config.max - config.min;
+                if range == 0. {
+                    continue;
+                }
+
+                (*value as f32 - config.min) / range
+            }
+            _ => continue,
+        };
+
+        slider.ratio = ratio.clamp(0., 1.);
+
+        #[cfg(feature = "observable")]
+        commands.trigger_targets(SliderChanged {
+            ratio: slider.ratio
+        }, entity);
+    }
+}
+
 fn update_slider_on_bar_change(
     q_slider_bars: Query<&SliderBar, Changed<Node>>,
     mut q_slider: Query<&mut Slider>,
@@ -199,6 +249,23 @@ fn update_slider_readout(q_slider: Query<&Slider, Changed<Slider>>, mut commands
     }
 }
 
+fn update_slider_accessibility(
+    mut q_slider: Query<(&Slider, &mut AccessibleWidget), Changed<Slider>>,
+) {
+    for (slider, mut accessible) in &mut q_slider {
+        let value = Some(AccessibleValue {
+            value: slider.value(),
+            min: slider.config.min,
+            max: slider.config.max,
+            step: Some((slider.config.max - slider.config.min) * Slider::ACCESSIBILITY_STEP),
+        });
+
+        if accessible.value != value {
+            accessible.value = value;
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Reflect)]
 pub enum SliderAxis {
     #[default]
@@ -402,6 +469,8 @@ impl Slider {
     pub const HANDLE: &'static str = "Handle";
     pub const READOUT_CONTAINER: &'static str = "ReadoutContainer";
     pub const READOUT: &'static str = "Readout";
+    /// Ratio change of an accessibility increment / decrement action
+    const ACCESSIBILITY_STEP: f32 = 0.05;
 
     pub fn value(&self) -> f32 {
         self.config.min.lerp(self.config.max, self.ratio)
@@ -631,6 +700,9 @@ impl UiSliderExt for UiBuilder<'_, Entity> {
             true => format!("Slider [{}]", label.clone()),
             false => "Slider".into(),
         };
+        let accessible = AccessibleWidget::new(Role::Slider)
+            .named(label.clone())
+            .with_actions([Action::Increment, Action::Decrement, Action::SetValue]);
 
         let mut input = self.container(Slider::container(name), |container| {
             let input_id = container.id();
@@ -656,7 +728,7 @@ impl UiSliderExt for UiBuilder<'_, Entity> {
                 .id();
         });
 
-        input.insert(slider);
+        input.insert((slider, accessible));
 
         input
     }
@@ -5,9 +5,14 @@ pub mod floating_panel;
 pub mod foldable;
 pub mod icon;
 pub mod label;
+pub mod notification;
 pub mod panel;
+pub mod progress_bar;
 pub mod resize_handles;
 pub mod row;
 pub mod scroll_view;
 pub mod sized_zone;
+pub mod split_pane;
+pub mod status_bar;
 pub mod tab_container;
+pub mod toolbar;
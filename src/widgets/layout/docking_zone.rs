@@ -1,19 +1,29 @@
 use bevy::{
     ecs::world::Command,
     prelude::*,
+    render::camera::RenderTarget,
     ui::{FocusPolicy, RelativeCursorPosition},
+    window::{WindowPosition, WindowRef, WindowResolution},
 };
 
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
 use super::{
-    floating_panel::FloatingPanelTitle,
+    container::UiContainerExt,
+    floating_panel::{
+        BringFloatingPanelToFront, FloatingPanel, FloatingPanelState, FloatingPanelTitle,
+        SetFloatingPanelState,
+    },
+    panel::{Panel, PanelKey},
     sized_zone::{
         SizedZone, SizedZoneConfig, SizedZonePreUpdate, SizedZoneResizeHandleContainer,
         UiSizedZoneExt,
     },
-    tab_container::{TabBar, TabContainer, UiTabContainerExt, UiTabContainerSubExt},
+    tab_container::{
+        CloseTab, FocusTab, MoveTab, PopoutTab, Tab, TabBar, TabContainer, UiTabContainerExt,
+        UiTabContainerSubExt,
+    },
 };
 
 pub struct DockingZonePlugin;
@@ -44,6 +54,8 @@ impl Plugin for DockingZonePlugin {
                 (
                     update_docking_zone_resize_handles,
                     handle_docking_zone_drop_zone_change,
+                    dock_panel_dropped_in_other_window,
+                    close_panel_windows,
                 )
                     .in_set(DockingZoneUpdate),
             );
@@ -473,7 +485,10 @@ fn handle_docking_zone_drop_zone_change(
                 commands.add(DockingZoneSplit {
                     direction: split_direction,
                     docking_zone: entity,
+                    size: 50.,
                     panel_to_dock: droppable_title.panel().into(),
+                    spawn_children: None,
+                    remove_empty: true,
                 });
             }
 
@@ -484,6 +499,138 @@ fn handle_docking_zone_drop_zone_change(
     }
 }
 
+type ChangedDroppableTitle = (With<Droppable>, Changed<Draggable>);
+
+/// Docks floating panels released outside of their own window into the docking zone under the
+/// cursor in another window. Window positions are in screen space, so the drop point is converted
+/// from the source window to the target window.
+fn dock_panel_dropped_in_other_window(
+    q_titles: Query<(Entity, &Draggable, &FloatingPanelTitle), ChangedDroppableTitle>,
+    q_docking_zones: Query<(Entity, &DockingZone, &Node, &GlobalTransform)>,
+    q_tab_container: Query<&TabContainer>,
+    q_window: Query<(Entity, &Window)>,
+    ui_windows: UiWindows,
+    mut commands: Commands,
+) {
+    for (entity, draggable, title) in &q_titles {
+        if draggable.state != DragState::DragEnd {
+            continue;
+        }
+
+        let Some(position) = draggable.position else {
+            continue;
+        };
+
+        let Some(source_id) = ui_windows.window_of(entity) else {
+            continue;
+        };
+        let Ok((_, source)) = q_window.get(source_id) else {
+            continue;
+        };
+
+        if Rect::new(0., 0., source.width(), source.height()).contains(position) {
+            // Dropped in its own window, regular drop zones handle it
+            continue;
+        }
+
+        // Windows only have a known position once the platform reports one,
+        // which some (i.e. Wayland) never do
+        let WindowPosition::At(source_origin) = source.position else {
+            warn!(
+                "Cannot dock {} in another window: the position of window {} is unknown",
+                title.panel(),
+                source_id
+            );
+            continue;
+        };
+
+        let screen_position = source_origin.as_vec2() + (position * source.scale_factor());
+        let mut unknown_windows = 0;
+        let target = q_window
+            .iter()
+            .filter(|(window_id, _)| *window_id != source_id)
+            .find_map(|(window_id, window)| {
+                let WindowPosition::At(origin) = window.position else {
+                    unknown_windows += 1;
+                    return None;
+                };
+
+                let local_position = (screen_position - origin.as_vec2()) / window.scale_factor();
+                Rect::new(0., 0., window.width(), window.height())
+                    .contains(local_position)
+                    .then_some((window_id, local_position))
+            });
+        let Some((target_id, target_position)) = target else {
+            if unknown_windows > 0 {
+                warn!(
+                    "Cannot tell where {} was dropped: {} windows have no known position",
+                    title.panel(),
+                    unknown_windows
+                );
+            }
+            continue;
+        };
+
+        let Some((_, docking_zone, _, _)) = q_docking_zones
+            .iter()
+            .filter(|(zone, ..)| ui_windows.window_of(*zone) == Some(target_id))
+            .filter(|(_, _, node, transform)| {
+                node.logical_rect(transform).contains(target_position)
+            })
+            .max_by_key(|(_, _, node, _)| node.stack_index())
+        else {
+            continue;
+        };
+
+        let Ok(tab_container) = q_tab_container.get(docking_zone.tab_container) else {
+            continue;
+        };
+
+        commands
+            .ui_builder((docking_zone.tab_container, *tab_container))
+            .dock_panel(title.panel());
+    }
+}
+
+/// Closes panel windows left without panels, and closes the panels of windows closed by the user
+fn close_panel_windows(
+    q_window_roots: Query<(Entity, &PanelWindowRoot)>,
+    q_windows: Query<(), With<Window>>,
+    q_panels: Query<Entity, With<Panel>>,
+    q_tabs: Query<(Entity, &Tab)>,
+    q_parent: Query<&Parent>,
+    mut commands: Commands,
+) {
+    for (entity, window_root) in &q_window_roots {
+        let window_open = q_windows.contains(window_root.window);
+        let has_panels = q_panels.iter().any(|panel| {
+            q_parent
+                .iter_ancestors(panel)
+                .any(|ancestor| ancestor == entity)
+        });
+
+        if window_open && has_panels {
+            continue;
+        }
+
+        for (tab, _) in q_tabs.iter().filter(|(_, tab)| {
+            q_parent
+                .iter_ancestors(tab.panel_id())
+                .any(|ancestor| ancestor == entity)
+        }) {
+            commands.add(CloseTab { tab });
+        }
+
+        commands.entity(entity).despawn_recursive();
+        if let Some(camera) = commands.get_entity(window_root.camera) {
+            camera.despawn_recursive();
+        }
+        if window_open {
+            commands.entity(window_root.window).despawn();
+        }
+    }
+}
+
 fn calculate_drop_area(position: Vec2, center: Vec2, size: Vec2) -> DropArea {
     let sixth_width = size.x / 6.;
     let sixth_height = size.y / 6.;
@@ -509,14 +656,37 @@ enum DockingZoneSplitDirection {
     HorizontallyAfter,
 }
 
+impl TryFrom<CardinalDirection> for DockingZoneSplitDirection {
+    type Error = String;
+
+    fn try_from(value: CardinalDirection) -> Result<Self, Self::Error> {
+        match value {
+            CardinalDirection::North => Ok(Self::VerticallyBefore),
+            CardinalDirection::East => Ok(Self::HorizontallyAfter),
+            CardinalDirection::South => Ok(Self::VerticallyAfter),
+            CardinalDirection::West => Ok(Self::HorizontallyBefore),
+            _ => Err(format!("Docking zones cannot be split {:?}", value)),
+        }
+    }
+}
+
+type SpawnDockingZoneChildren = Box<dyn FnOnce(&mut UiBuilder<(Entity, TabContainer)>) + Send>;
+
 struct DockingZoneSplit {
     docking_zone: Entity,
     direction: DockingZoneSplitDirection,
+    /// Size of the new zone, in percentage of the split zone
+    size: f32,
     panel_to_dock: Option<Entity>,
+    spawn_children: Option<SpawnDockingZoneChildren>,
+    /// Whether the new zone is despawned once its last tab is removed
+    remove_empty: bool,
 }
 
 impl Command for DockingZoneSplit {
-    fn apply(self, world: &mut World) {
+    fn apply(mut self, world: &mut World) {
+        self.docking_zone = resolve_docking_zone(world, self.docking_zone);
+
         let Ok((docking_zone, parent, sized_zone)) = world
             .query::<(&DockingZone, &Parent, &SizedZone)>()
             .get(world, self.docking_zone)
@@ -580,12 +750,16 @@ impl Command for DockingZoneSplit {
         // Missing SizedZone on a DockingZone must panic
         let mut sized_zone = world.get_mut::<SizedZone>(self.docking_zone).unwrap();
 
-        let new_container_size = if inject_container {
-            50.
+        let split_ratio = self.size.clamp(0., 100.) / 100.;
+        let (current_container_size, new_container_size) = if inject_container {
+            (100. * (1. - split_ratio), 100. * split_ratio)
         } else {
-            current_size / 2.
+            (
+                current_size * (1. - split_ratio),
+                current_size * split_ratio,
+            )
         };
-        sized_zone.set_size(new_container_size);
+        sized_zone.set_size(current_container_size);
 
         let mut commands = world.commands();
         if inject_container {
@@ -616,11 +790,14 @@ impl Command for DockingZoneSplit {
                     min_size: current_min_size,
                     ..default()
                 },
-                self.panel_to_dock.is_some(),
+                self.remove_empty,
                 |container| {
                     if let Some(floating_panel_id) = self.panel_to_dock {
                         container.dock_panel(floating_panel_id);
                     }
+                    if let Some(spawn_children) = self.spawn_children {
+                        spawn_children(container);
+                    }
                 },
             )
             .id();
@@ -678,6 +855,12 @@ impl Default for DockingZone {
     }
 }
 
+impl DockingZone {
+    pub fn tab_container_id(&self) -> Entity {
+        self.tab_container
+    }
+}
+
 #[derive(Component, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct DockingZoneHighlight {
@@ -700,11 +883,13 @@ impl DefaultTheme for DockingZoneHighlight {
 
 impl DockingZoneHighlight {
     pub fn theme() -> Theme<DockingZoneHighlight> {
-        let base_theme = PseudoTheme::deferred(None, DockingZoneHighlight::primary_style);
+        let base_theme = PseudoTheme::deferred(None, DockingZoneHighlight::primary_style)
+            .depends_on(ThemeDataSections::NONE);
         let visible_theme = PseudoTheme::deferred(
             vec![PseudoState::Visible],
             DockingZoneHighlight::visible_style,
-        );
+        )
+        .depends_on(ThemeDataSections::COLORS);
         Theme::new(vec![base_theme, visible_theme])
     }
 
@@ -760,6 +945,449 @@ impl Default for RemoveEmptyDockingZone {
     }
 }
 
+/// The UI root of a window spawned to hold panels popped out of the primary window.
+/// The window, its camera and this root are despawned together.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct PanelWindowRoot {
+    window: Entity,
+    camera: Entity,
+}
+
+impl Default for PanelWindowRoot {
+    fn default() -> Self {
+        Self {
+            window: Entity::PLACEHOLDER,
+            camera: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl PanelWindowRoot {
+    pub fn window_id(&self) -> Entity {
+        self.window
+    }
+
+    pub fn camera_id(&self) -> Entity {
+        self.camera
+    }
+}
+
+/// Returns the docking zone itself, or the docking zone of a tab container
+fn resolve_docking_zone(world: &World, entity: Entity) -> Entity {
+    if world.get::<DockingZone>(entity).is_some() {
+        return entity;
+    }
+
+    world
+        .get::<Parent>(entity)
+        .map(|parent| parent.get())
+        .filter(|parent| world.get::<DockingZone>(*parent).is_some())
+        .unwrap_or(entity)
+}
+
+/// Returns the tab container of a docking zone, or the tab container itself
+fn resolve_tab_container(world: &World, zone: Entity) -> Option<(Entity, TabContainer)> {
+    let container_id = match world.get::<DockingZone>(zone) {
+        Some(docking_zone) => docking_zone.tab_container,
+        None => zone,
+    };
+
+    let Some(tab_container) = world.get::<TabContainer>(container_id) else {
+        warn!("{} is not a DockingZone or a TabContainer", zone);
+        return None;
+    };
+
+    Some((container_id, *tab_container))
+}
+
+enum PanelLocation {
+    Tab(Entity),
+    FloatingPanel(Entity),
+}
+
+/// Finds the panel by its `PanelKey`, falling back to its title
+fn find_panel(world: &mut World, key: &str) -> Option<Entity> {
+    let mut q_panels = world.query::<(Entity, &Panel, Option<&PanelKey>)>();
+    let keyed = q_panels
+        .iter(world)
+        .find(|(_, _, panel_key)| panel_key.is_some_and(|panel_key| panel_key.0 == key))
+        .map(|(entity, _, _)| entity);
+
+    if keyed.is_some() {
+        return keyed;
+    }
+
+    q_panels
+        .iter(world)
+        .find(|(_, panel, _)| panel.title == key)
+        .map(|(entity, _, _)| entity)
+}
+
+fn locate_panel(world: &mut World, key: &str) -> Option<PanelLocation> {
+    let Some(panel) = find_panel(world, key) else {
+        warn!("No panel found with key or title {}", key);
+        return None;
+    };
+
+    let mut q_tabs = world.query::<(Entity, &Tab)>();
+    if let Some((tab, _)) = q_tabs.iter(world).find(|(_, tab)| tab.panel_id() == panel) {
+        return PanelLocation::Tab(tab).into();
+    }
+
+    let mut q_floating_panels = world.query::<(Entity, &FloatingPanel)>();
+    if let Some((floating_panel, _)) = q_floating_panels
+        .iter(world)
+        .find(|(_, floating_panel)| floating_panel.content_panel_id() == panel)
+    {
+        return PanelLocation::FloatingPanel(floating_panel).into();
+    }
+
+    warn!("Panel {} ({}) is neither docked nor floating", key, panel);
+    None
+}
+
+struct AddDockedTab<F> {
+    zone: Entity,
+    title: String,
+    spawn_children: F,
+}
+
+impl<F> Command for AddDockedTab<F>
+where
+    F: FnOnce(&mut UiBuilder<Entity>) + Send + 'static,
+{
+    fn apply(self, world: &mut World) {
+        let Some(context) = resolve_tab_container(world, self.zone) else {
+            return;
+        };
+
+        world
+            .commands()
+            .ui_builder(context)
+            .add_tab(self.title, self.spawn_children);
+    }
+}
+
+struct MovePanel {
+    key: String,
+    zone: Entity,
+}
+
+impl Command for MovePanel {
+    fn apply(self, world: &mut World) {
+        let Some(location) = locate_panel(world, &self.key) else {
+            return;
+        };
+
+        let Some((container_id, tab_container)) = resolve_tab_container(world, self.zone) else {
+            return;
+        };
+
+        match location {
+            PanelLocation::Tab(tab) => MoveTab {
+                tab,
+                container: container_id,
+            }
+            .apply(world),
+            PanelLocation::FloatingPanel(floating_panel) => {
+                world
+                    .commands()
+                    .ui_builder((container_id, tab_container))
+                    .dock_panel(floating_panel);
+            }
+        }
+    }
+}
+
+struct FloatPanel {
+    key: String,
+}
+
+impl Command for FloatPanel {
+    fn apply(self, world: &mut World) {
+        if let Some(PanelLocation::Tab(tab)) = locate_panel(world, &self.key) {
+            PopoutTab { tab }.apply(world);
+        }
+    }
+}
+
+/// Moves the tab into a docking zone of a new window, sized to its current tab container
+pub(crate) struct PopoutTabToWindow {
+    pub tab: Entity,
+}
+
+impl Command for PopoutTabToWindow {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab) else {
+            warn!("Cannot pop out {} to a window: not a tab", self.tab);
+            return;
+        };
+
+        let container_id = tab.container_id();
+        let title = world
+            .get::<Panel>(tab.panel_id())
+            .map(|panel| panel.title())
+            .unwrap_or_default();
+        let size = world
+            .get::<Node>(container_id)
+            .map(|node| node.size())
+            .filter(|size| size.x > 0. && size.y > 0.)
+            .unwrap_or(Vec2::new(800., 600.));
+
+        // An explicit position lets panels be dropped across windows
+        // without waiting for the platform to report where the window is
+        let position = popout_window_position(world, container_id);
+        let window = world
+            .spawn(Window {
+                title: title.clone(),
+                resolution: WindowResolution::new(size.x, size.y),
+                position: WindowPosition::At(position),
+                ..default()
+            })
+            .id();
+        let camera = world
+            .spawn(Camera2dBundle {
+                camera: Camera {
+                    target: RenderTarget::Window(WindowRef::Entity(window)),
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+
+        let mut tab_container = Entity::PLACEHOLDER;
+        let mut commands = world.commands();
+        commands.ui_builder(UiRoot).container(
+            (
+                Name::new(format!("Panel Window [{}]", title)),
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        flex_direction: FlexDirection::Row,
+                        ..default()
+                    },
+                    ..default()
+                },
+                TargetCamera(camera),
+                UiContextRoot,
+                PanelWindowRoot { window, camera },
+            ),
+            |root| {
+                root.docking_zone(
+                    SizedZoneConfig {
+                        size: 100.,
+                        ..default()
+                    },
+                    false,
+                    |zone| {
+                        tab_container = zone.id();
+                    },
+                );
+            },
+        );
+
+        commands.add(MoveTab {
+            tab: self.tab,
+            container: tab_container,
+        });
+    }
+}
+
+/// Screen position of the container's top left corner, in physical pixels
+fn popout_window_position(world: &World, container: Entity) -> IVec2 {
+    let Some(window) =
+        UiUtils::find_window(container, world).and_then(|window| world.get::<Window>(window))
+    else {
+        return IVec2::ZERO;
+    };
+
+    let origin = match window.position {
+        WindowPosition::At(origin) => origin,
+        _ => {
+            warn!(
+                "Window of {} has no known position, opening the pop out at the screen origin",
+                container
+            );
+            IVec2::ZERO
+        }
+    };
+
+    let offset = world
+        .get::<Node>(container)
+        .zip(world.get::<GlobalTransform>(container))
+        .map(|(node, transform)| node.logical_rect(transform).min * window.scale_factor())
+        .unwrap_or_default();
+
+    origin + offset.as_ivec2()
+}
+
+struct FloatPanelInWindow {
+    key: String,
+}
+
+impl Command for FloatPanelInWindow {
+    fn apply(self, world: &mut World) {
+        if let Some(PanelLocation::Tab(tab)) = locate_panel(world, &self.key) {
+            PopoutTabToWindow { tab }.apply(world);
+        }
+    }
+}
+
+struct FocusPanel {
+    key: String,
+}
+
+impl Command for FocusPanel {
+    fn apply(self, world: &mut World) {
+        match locate_panel(world, &self.key) {
+            Some(PanelLocation::Tab(tab)) => FocusTab { tab }.apply(world),
+            Some(PanelLocation::FloatingPanel(panel)) => {
+                let minimized = world
+                    .get::<FloatingPanel>(panel)
+                    .is_some_and(|floating_panel| {
+                        floating_panel.state() == FloatingPanelState::Minimized
+                    });
+
+                if minimized {
+                    SetFloatingPanelState {
+                        panel,
+                        state: FloatingPanelState::Normal,
+                    }
+                    .apply(world)
+                } else {
+                    BringFloatingPanelToFront { panel }.apply(world)
+                }
+            }
+            None => (),
+        }
+    }
+}
+
+struct ClosePanel {
+    key: String,
+}
+
+impl Command for ClosePanel {
+    fn apply(self, world: &mut World) {
+        match locate_panel(world, &self.key) {
+            Some(PanelLocation::Tab(tab)) => CloseTab { tab }.apply(world),
+            Some(PanelLocation::FloatingPanel(panel)) => {
+                world.entity_mut(panel).despawn_recursive();
+            }
+            None => (),
+        }
+    }
+}
+
+/// Arrange docking zones and their panels from code.
+///
+/// Zones can be referred to by the `DockingZone` or by its `TabContainer`. Panels are looked up
+/// by their `PanelKey`, or by their title if no panel has a matching key. Zones emptied by these
+/// commands are cleaned up the same way as when panels are dragged out of them.
+pub trait DockingCommandsExt {
+    /// Splits the zone in the given direction, giving `size` percent of it to the new zone.
+    /// Only `North`, `East`, `South` and `West` are valid directions.
+    fn split_docking_zone(
+        &mut self,
+        zone: Entity,
+        direction: CardinalDirection,
+        size: f32,
+        spawn_children: impl FnOnce(&mut UiBuilder<(Entity, TabContainer)>) + Send + 'static,
+    ) -> &mut Self;
+
+    /// Adds a tab to the zone's tab container
+    fn add_docked_tab(
+        &mut self,
+        zone: Entity,
+        title: impl Into<String>,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>) + Send + 'static,
+    ) -> &mut Self;
+
+    /// Moves a docked or floating panel into the zone, as its last tab
+    fn move_panel(&mut self, key: impl Into<String>, zone: Entity) -> &mut Self;
+
+    /// Pops a docked panel out into a floating panel
+    fn float_panel(&mut self, key: impl Into<String>) -> &mut Self;
+
+    /// Moves a docked panel into a docking zone of a new window.
+    /// The window is closed once its last panel is moved out or closed.
+    fn float_panel_in_window(&mut self, key: impl Into<String>) -> &mut Self;
+
+    /// Selects the tab of a docked panel, or brings a floating panel to the front
+    fn focus_panel(&mut self, key: impl Into<String>) -> &mut Self;
+
+    /// Closes a docked or floating panel
+    fn close_panel(&mut self, key: impl Into<String>) -> &mut Self;
+}
+
+impl DockingCommandsExt for Commands<'_, '_> {
+    fn split_docking_zone(
+        &mut self,
+        zone: Entity,
+        direction: CardinalDirection,
+        size: f32,
+        spawn_children: impl FnOnce(&mut UiBuilder<(Entity, TabContainer)>) + Send + 'static,
+    ) -> &mut Self {
+        match DockingZoneSplitDirection::try_from(direction) {
+            Ok(direction) => self.add(DockingZoneSplit {
+                docking_zone: zone,
+                direction,
+                size,
+                panel_to_dock: None,
+                spawn_children: Some(Box::new(spawn_children)),
+                remove_empty: true,
+            }),
+            Err(err) => error!("Failed to split docking zone {}: {}", zone, err),
+        }
+
+        self
+    }
+
+    fn add_docked_tab(
+        &mut self,
+        zone: Entity,
+        title: impl Into<String>,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>) + Send + 'static,
+    ) -> &mut Self {
+        self.add(AddDockedTab {
+            zone,
+            title: title.into(),
+            spawn_children,
+        });
+        self
+    }
+
+    fn move_panel(&mut self, key: impl Into<String>, zone: Entity) -> &mut Self {
+        self.add(MovePanel {
+            key: key.into(),
+            zone,
+        });
+        self
+    }
+
+    fn float_panel(&mut self, key: impl Into<String>) -> &mut Self {
+        self.add(FloatPanel { key: key.into() });
+        self
+    }
+
+    fn float_panel_in_window(&mut self, key: impl Into<String>) -> &mut Self {
+        self.add(FloatPanelInWindow { key: key.into() });
+        self
+    }
+
+    fn focus_panel(&mut self, key: impl Into<String>) -> &mut Self {
+        self.add(FocusPanel { key: key.into() });
+        self
+    }
+
+    fn close_panel(&mut self, key: impl Into<String>) -> &mut Self {
+        self.add(ClosePanel { key: key.into() });
+        self
+    }
+}
+
 pub trait UiDockingZoneExt {
     fn docking_zone(
         &mut self,
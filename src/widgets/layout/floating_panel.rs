@@ -1,9 +1,11 @@
 use std::ops::DerefMut;
 
 use bevy::{
+    a11y::accesskit::Role,
+    ecs::{system::SystemParam, world::Command},
     prelude::*,
     ui::{ContentSize, FocusPolicy, RelativeCursorPosition},
-    window::{PrimaryWindow, WindowResized},
+    window::WindowResized,
 };
 
 use sickle_ui_scaffold::{prelude::*, ui_commands::RefreshThemeExt};
@@ -33,6 +35,9 @@ impl Plugin for FloatingPanelPlugin {
                 .after(DroppableUpdate)
                 .after(FluxInteractionUpdate),
         )
+        .init_resource::<FloatingPanelBehavior>()
+        .init_resource::<FloatingPanelZOrder>()
+        .init_resource::<FloatingPanelTray>()
         .add_plugins(ComponentThemePlugin::<FloatingPanel>::default())
         .add_systems(PreUpdate, update_floating_panel_panel_id)
         .add_systems(
@@ -41,9 +46,12 @@ impl Plugin for FloatingPanelPlugin {
                 index_floating_panels.run_if(panel_added),
                 process_panel_close_pressed,
                 process_panel_fold_pressed,
+                process_panel_window_buttons_pressed,
+                process_panel_title_pressed,
                 update_panel_size_on_resize,
                 update_panel_on_title_drag,
                 handle_window_resize.run_if(window_resized),
+                update_minimized_panel_tray,
                 update_panel_layout,
                 touch_new_floating_panels.run_if(panel_added),
             )
@@ -96,18 +104,68 @@ fn panel_added(q_panels: Query<Entity, Added<FloatingPanel>>) -> bool {
     q_panels.iter().count() > 0
 }
 
-fn index_floating_panels(mut q_panels: Query<&mut FloatingPanel>) {
-    let max = if let Some(Some(m)) = q_panels.iter().map(|p| p.z_index).max() {
-        m
-    } else {
-        0
-    };
+/// The windows panels are rendered to, each panel is laid out within its own window
+#[derive(SystemParam)]
+struct PanelWindows<'w, 's> {
+    ui_windows: UiWindows<'w, 's>,
+    q_window: Query<'w, 's, &'static Window>,
+}
+
+impl PanelWindows<'_, '_> {
+    fn window_of(&self, node: Entity) -> Option<Entity> {
+        self.ui_windows.window_of(node)
+    }
+
+    fn size_of(&self, node: Entity) -> Option<Vec2> {
+        let window = self.q_window.get(self.window_of(node)?).ok()?;
+        Some(Vec2::new(window.width(), window.height()))
+    }
+}
 
-    let mut offset = 1;
-    for mut panel in &mut q_panels.iter_mut() {
-        if panel.z_index.is_none() {
-            panel.z_index = (MIN_FLOATING_PANEL_Z_INDEX + max + offset).into();
-            offset += 1;
+fn index_floating_panels(
+    r_behavior: Res<FloatingPanelBehavior>,
+    mut r_z_order: ResMut<FloatingPanelZOrder>,
+    panel_windows: PanelWindows,
+    mut q_panels: Query<(Entity, &mut FloatingPanel)>,
+) {
+    r_z_order.order.retain(|panel| q_panels.contains(*panel));
+    let new_panels: Vec<Entity> = q_panels
+        .iter()
+        .filter(|(entity, _)| !r_z_order.order.contains(entity))
+        .map(|(entity, _)| entity)
+        .collect();
+
+    for entity in new_panels {
+        let window = panel_windows.window_of(entity);
+        let area = panel_windows.size_of(entity).unwrap_or(Vec2::INFINITY);
+        let placed: Vec<Rect> = r_z_order
+            .order
+            .iter()
+            .filter(|panel| panel_windows.window_of(**panel) == window)
+            .filter_map(|panel| q_panels.get(*panel).ok())
+            .map(|(_, panel)| panel.rect())
+            .collect();
+
+        let Ok((_, mut panel)) = q_panels.get_mut(entity) else {
+            continue;
+        };
+
+        if panel.auto_place {
+            panel.position = r_behavior.place(panel.size, &placed, area);
+            panel.auto_place = false;
+        }
+
+        r_z_order.order.push(entity);
+    }
+
+    for (i, entity) in r_z_order.order.iter().enumerate() {
+        let Ok((_, mut panel)) = q_panels.get_mut(*entity) else {
+            continue;
+        };
+
+        let z_index = Some(MIN_FLOATING_PANEL_Z_INDEX + i + 1);
+        if panel.z_index != z_index {
+            panel.z_index = z_index;
         }
     }
 }
@@ -128,16 +186,118 @@ fn process_panel_fold_pressed(
         (Entity, &FloatingPanelFoldButton, &FluxInteraction),
         Changed<FluxInteraction>,
     >,
-    mut q_panel_configs: Query<&mut FloatingPanelConfig>,
+    mut q_panels: Query<(&mut FloatingPanel, &mut FloatingPanelConfig)>,
 ) {
     for (entity, button, interaction) in &q_buttons {
         if *interaction == FluxInteraction::Released {
-            let Ok(mut config) = q_panel_configs.get_mut(button.panel) else {
+            let Ok((mut panel, mut config)) = q_panels.get_mut(button.panel) else {
                 warn!("Missing floating panel config for fold button {}", entity);
                 continue;
             };
 
-            config.folded = !config.folded;
+            if panel.state == FloatingPanelState::Minimized {
+                panel.restore(&mut config);
+            } else {
+                config.folded = !config.folded;
+            }
+        }
+    }
+}
+
+type FloatingPanelWindowButton<'a> = AnyOf<(
+    &'a FloatingPanelMinimizeButton,
+    &'a FloatingPanelMaximizeButton,
+)>;
+
+fn process_panel_window_buttons_pressed(
+    q_buttons: Query<(FloatingPanelWindowButton, &FluxInteraction), Changed<FluxInteraction>>,
+    q_panels: Query<&FloatingPanel>,
+    mut commands: Commands,
+) {
+    for ((minimize_button, maximize_button), interaction) in &q_buttons {
+        if *interaction != FluxInteraction::Released {
+            continue;
+        }
+
+        if let Some(button) = minimize_button {
+            let Ok(panel) = q_panels.get(button.panel) else {
+                continue;
+            };
+
+            match panel.state {
+                FloatingPanelState::Minimized => commands.restore_floating_panel(button.panel),
+                _ => commands.minimize_floating_panel(button.panel),
+            };
+        } else if let Some(button) = maximize_button {
+            let Ok(panel) = q_panels.get(button.panel) else {
+                continue;
+            };
+
+            match panel.state {
+                FloatingPanelState::Maximized => commands.restore_floating_panel(button.panel),
+                _ => commands.maximize_floating_panel(button.panel),
+            };
+        }
+    }
+}
+
+type FloatingPanelTitleBar<'a> = AnyOf<(&'a FloatingPanelTitle, &'a FloatingPanelDragHandle)>;
+
+fn process_panel_title_pressed(
+    q_titles: Query<
+        (Entity, FloatingPanelTitleBar, &FluxInteraction, &Draggable),
+        Changed<FluxInteraction>,
+    >,
+    q_panels: Query<(&FloatingPanel, &FloatingPanelConfig)>,
+    r_behavior: Res<FloatingPanelBehavior>,
+    r_time: Res<Time<Real>>,
+    mut last_click: Local<Option<(Entity, f32)>>,
+    mut commands: Commands,
+) {
+    for (entity, (panel_title, drag_handle), interaction, draggable) in &q_titles {
+        let panel_id = if let Some(panel_title) = panel_title {
+            panel_title.panel
+        } else if let Some(drag_handle) = drag_handle {
+            drag_handle.panel
+        } else {
+            continue;
+        };
+
+        if *interaction == FluxInteraction::Pressed {
+            commands.bring_floating_panel_to_front(panel_id);
+            continue;
+        }
+
+        // Releasing after a drag isn't a click
+        if *interaction != FluxInteraction::Released
+            || !(draggable.state == DragState::Inactive
+                || draggable.state == DragState::MaybeDragged)
+        {
+            continue;
+        }
+
+        let now = r_time.elapsed_seconds();
+        let double_click = last_click.is_some_and(|(last_entity, last_time)| {
+            last_entity == entity && now - last_time <= r_behavior.double_click_time
+        });
+        if !double_click {
+            *last_click = Some((entity, now));
+            continue;
+        }
+
+        *last_click = None;
+        let Ok((panel, config)) = q_panels.get(panel_id) else {
+            continue;
+        };
+
+        match panel.state {
+            FloatingPanelState::Normal if config.maximizable => {
+                commands.maximize_floating_panel(panel_id);
+            }
+            FloatingPanelState::Normal => (),
+            _ => {
+                commands.restore_floating_panel(panel_id);
+            }
         }
     }
 }
@@ -155,6 +315,10 @@ fn update_panel_size_on_resize(
             continue;
         };
 
+        if panel.state != FloatingPanelState::Normal {
+            continue;
+        }
+
         if draggable.state == DragState::Inactive
             || draggable.state == DragState::MaybeDragged
             || draggable.state == DragState::DragCanceled
@@ -225,28 +389,15 @@ fn clip_position_change(diff: f32, min: f32, old_size: f32, new_size: f32) -> f3
 }
 
 fn update_panel_on_title_drag(
-    q_draggable: Query<
-        (
-            &Draggable,
-            AnyOf<(&FloatingPanelTitle, &FloatingPanelDragHandle)>,
-        ),
-        Changed<Draggable>,
-    >,
-    mut q_panels: Query<(Entity, &mut FloatingPanel)>,
+    q_draggable: Query<(&Draggable, FloatingPanelTitleBar), Changed<Draggable>>,
+    r_behavior: Res<FloatingPanelBehavior>,
+    panel_windows: PanelWindows,
+    mut q_panels: Query<(Entity, &mut FloatingPanel, &mut FloatingPanelConfig, &Node)>,
 ) {
-    if let Some(_) = q_panels.iter().find(|(_, p)| p.priority) {
+    if q_panels.iter().any(|(_, p, _, _)| p.priority) {
         return;
     }
 
-    let max_index = if let Some(Some(m)) = q_panels.iter().map(|(_, p)| p.z_index).max() {
-        m
-    } else {
-        0
-    };
-    let mut offset = 1;
-
-    let mut panel_updated = false;
-
     for (draggable, (panel_title, drag_handle)) in &q_draggable {
         let panel_id = if let Some(panel_title) = panel_title {
             panel_title.panel
@@ -256,11 +407,25 @@ fn update_panel_on_title_drag(
             continue;
         };
 
-        let Ok((_, mut panel)) = q_panels.get_mut(panel_id) else {
+        let window = panel_windows.window_of(panel_id);
+        let area = panel_windows.size_of(panel_id);
+        let others: Vec<Rect> = q_panels
+            .iter()
+            .filter(|(entity, panel, _, _)| {
+                *entity != panel_id
+                    && panel.state != FloatingPanelState::Minimized
+                    && panel_windows.window_of(*entity) == window
+            })
+            .map(|(_, panel, _, node)| {
+                Rect::from_corners(panel.position, panel.position + node.size())
+            })
+            .collect();
+
+        let Ok((_, mut panel, mut config, node)) = q_panels.get_mut(panel_id) else {
             continue;
         };
 
-        if panel.resizing {
+        if panel.resizing || panel.state == FloatingPanelState::Minimized {
             continue;
         }
 
@@ -269,34 +434,30 @@ fn update_panel_on_title_drag(
             || draggable.state == DragState::DragCanceled
         {
             panel.moving = false;
+            panel.drag_position = None;
             continue;
         }
 
+        let mut size = node.size();
+        if panel.state == FloatingPanelState::Maximized {
+            // Dragging a maximized panel restores it under the cursor
+            let maximized_size = panel.size;
+            panel.restore(&mut config);
+            if let Some(cursor) = draggable.position {
+                let ratio = cursor.x / maximized_size.x.max(1.);
+                panel.position = Vec2::new(cursor.x - panel.size.x * ratio, 0.);
+            }
+            size = panel.size;
+        }
+
         panel.moving = true;
         let Some(diff) = draggable.diff else {
             continue;
         };
 
-        panel.z_index = Some(max_index + offset);
-        panel.position += diff;
-        offset += 1;
-        panel_updated = true;
-    }
-
-    if !panel_updated {
-        return;
-    }
-
-    let mut panel_indices: Vec<(Entity, Option<usize>)> = q_panels
-        .iter()
-        .map(|(entity, panel)| (entity, panel.z_index))
-        .collect();
-    panel_indices.sort_by(|(_, a), (_, b)| a.cmp(b));
-
-    for (i, (entity, _)) in panel_indices.iter().enumerate() {
-        if let Some((_, mut panel)) = q_panels.iter_mut().find(|(e, _)| e == entity) {
-            panel.z_index = (MIN_FLOATING_PANEL_Z_INDEX + i + 1).into();
-        };
+        let position = panel.drag_position.unwrap_or(panel.position) + diff;
+        panel.drag_position = position.into();
+        panel.position = r_behavior.snap(position, size, &others, area);
     }
 }
 
@@ -304,44 +465,122 @@ fn window_resized(e_resized: EventReader<WindowResized>) -> bool {
     e_resized.len() > 0
 }
 
-// TODO: Use the panel's render window
 fn handle_window_resize(
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    mut q_panels: Query<(&mut FloatingPanel, &Node, &GlobalTransform)>,
+    panel_windows: PanelWindows,
+    mut q_panels: Query<(Entity, &mut FloatingPanel, &Node, &GlobalTransform)>,
 ) {
-    let Ok(window) = q_window.get_single() else {
-        return;
-    };
+    for (entity, mut panel, node, transform) in &mut q_panels {
+        let Some(window) = panel_windows.size_of(entity) else {
+            continue;
+        };
+
+        if panel.state == FloatingPanelState::Maximized {
+            panel.size = window;
+            panel.apply_layout = true;
+            continue;
+        } else if panel.state == FloatingPanelState::Minimized {
+            continue;
+        }
 
-    for (mut panel, node, transform) in &mut q_panels {
         let position = transform.translation().truncate() - (node.size() / 2.);
 
-        if position.x > window.width() - WINDOW_RESIZE_PADDING {
+        if position.x > window.x - WINDOW_RESIZE_PADDING {
             panel.position.x = (panel.position.x - panel.size.x + WINDOW_RESIZE_PADDING).max(0.);
-            if position.y > window.height() - panel.size.y {
-                let overflow = position.y - (window.height() - panel.size.y);
+            if position.y > window.y - panel.size.y {
+                let overflow = position.y - (window.y - panel.size.y);
                 panel.position.y = (panel.position.y - overflow).max(0.);
             }
         }
-        if position.y > window.height() - WINDOW_RESIZE_PADDING {
+        if position.y > window.y - WINDOW_RESIZE_PADDING {
             panel.position.y = (panel.position.y - panel.size.y + WINDOW_RESIZE_PADDING).max(0.);
 
-            if position.x > window.width() - panel.size.x {
-                let overflow = position.x - (window.width() - panel.size.x);
+            if position.x > window.x - panel.size.x {
+                let overflow = position.x - (window.x - panel.size.x);
                 panel.position.x = (panel.position.x - overflow).max(0.);
             }
         }
     }
 }
 
+fn update_minimized_panel_tray(
+    mut r_tray: ResMut<FloatingPanelTray>,
+    r_behavior: Res<FloatingPanelBehavior>,
+    r_theme_data: Res<ThemeData>,
+    e_resized: EventReader<WindowResized>,
+    panel_windows: PanelWindows,
+    q_node: Query<&Node>,
+    mut q_panels: Query<(Entity, &mut FloatingPanel)>,
+) {
+    let stale = r_tray.panels.iter().any(|entity| {
+        q_panels.get(*entity).map_or(true, |(_, panel)| {
+            panel.state != FloatingPanelState::Minimized
+        })
+    });
+    let missing = q_panels.iter().any(|(entity, panel)| {
+        panel.state == FloatingPanelState::Minimized && !r_tray.panels.contains(&entity)
+    });
+
+    if !(stale || missing || !e_resized.is_empty()) {
+        return;
+    }
+
+    r_tray.panels.retain(|entity| {
+        q_panels
+            .get(*entity)
+            .is_ok_and(|(_, panel)| panel.state == FloatingPanelState::Minimized)
+    });
+    for (entity, panel) in &q_panels {
+        if panel.state == FloatingPanelState::Minimized && !r_tray.panels.contains(&entity) {
+            r_tray.panels.push(entity);
+        }
+    }
+
+    // Each window has its own tray
+    let windows: Vec<Option<Entity>> = r_tray
+        .panels
+        .iter()
+        .map(|entity| panel_windows.window_of(*entity))
+        .collect();
+
+    let slot_width = r_theme_data.spacing.areas.extra_large;
+    let gap = r_behavior.tile_gap;
+    for (i, entity) in r_tray.panels.iter().enumerate() {
+        let Some(window) = panel_windows.size_of(*entity) else {
+            continue;
+        };
+        let slot = windows[..i].iter().filter(|w| **w == windows[i]).count();
+
+        let Ok((_, mut panel)) = q_panels.get_mut(*entity) else {
+            continue;
+        };
+
+        let bar_height = [panel.title_container, panel.drag_handle]
+            .iter()
+            .filter_map(|bar| q_node.get(*bar).ok())
+            .map(|node| node.size().y)
+            .fold(0., f32::max);
+        let position = Vec2::new(
+            gap + slot as f32 * (slot_width + gap),
+            window.y - bar_height - gap,
+        );
+
+        if panel.position != position {
+            panel.position = position;
+            panel.apply_layout = true;
+        }
+    }
+}
+
+type ChangedFloatingPanelLayout = Or<(Changed<FloatingPanel>, Changed<FloatingPanelConfig>)>;
+
 fn update_panel_layout(
-    q_panels: Query<
-        (Entity, &FloatingPanel, Ref<FloatingPanelConfig>),
-        Or<(Changed<FloatingPanel>, Changed<FloatingPanelConfig>)>,
+    mut q_panels: Query<
+        (Entity, &mut FloatingPanel, Ref<FloatingPanelConfig>),
+        ChangedFloatingPanelLayout,
     >,
     mut commands: Commands,
 ) {
-    for (entity, panel, config) in &q_panels {
+    for (entity, mut panel, config) in &mut q_panels {
         if config.is_changed() {
             commands
                 .style(panel.title_container)
@@ -365,7 +604,25 @@ fn update_panel_layout(
             }
         }
 
-        let render_resize_handles = !config.folded && config.resizable && !panel.moving;
+        match panel.state {
+            FloatingPanelState::Normal => commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Minimized)
+                .remove_pseudo_state(PseudoState::Maximized),
+            FloatingPanelState::Minimized => commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::Minimized)
+                .remove_pseudo_state(PseudoState::Maximized),
+            FloatingPanelState::Maximized => commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Minimized)
+                .add_pseudo_state(PseudoState::Maximized),
+        };
+
+        let render_resize_handles = !config.folded
+            && config.resizable
+            && !panel.moving
+            && panel.state == FloatingPanelState::Normal;
         if render_resize_handles {
             commands
                 .entity(panel.resize_handles)
@@ -402,6 +659,12 @@ fn update_panel_layout(
         commands
             .style(panel.close_button)
             .flux_interaction_enabled(!(panel.moving || panel.resizing));
+        commands
+            .style(panel.minimize_button)
+            .flux_interaction_enabled(!(panel.moving || panel.resizing));
+        commands
+            .style(panel.maximize_button)
+            .flux_interaction_enabled(!(panel.moving || panel.resizing));
 
         if panel.resizing {
             commands
@@ -410,10 +673,21 @@ fn update_panel_layout(
                 .height(Val::Px(panel.size.y.max(MIN_PANEL_SIZE.y)));
         }
 
-        if panel.moving || panel.resizing {
+        if panel.moving || panel.resizing || panel.apply_layout {
             commands.style(entity).absolute_position(panel.position);
         }
 
+        if panel.apply_layout {
+            if panel.state != FloatingPanelState::Minimized {
+                commands
+                    .style(entity)
+                    .width(Val::Px(panel.size.x.max(MIN_PANEL_SIZE.x)))
+                    .height(Val::Px(panel.size.y.max(MIN_PANEL_SIZE.y)));
+            }
+
+            panel.bypass_change_detection().apply_layout = false;
+        }
+
         if panel.priority {
             commands
                 .style(entity)
@@ -508,6 +782,34 @@ impl Default for FloatingPanelCloseButton {
     }
 }
 
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct FloatingPanelMinimizeButton {
+    panel: Entity,
+}
+
+impl Default for FloatingPanelMinimizeButton {
+    fn default() -> Self {
+        Self {
+            panel: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct FloatingPanelMaximizeButton {
+    panel: Entity,
+}
+
+impl Default for FloatingPanelMaximizeButton {
+    fn default() -> Self {
+        Self {
+            panel: Entity::PLACEHOLDER,
+        }
+    }
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct FloatingPanelConfig {
     pub title: Option<String>,
@@ -516,6 +818,8 @@ pub struct FloatingPanelConfig {
     pub foldable: bool,
     pub folded: bool,
     pub closable: bool,
+    pub minimizable: bool,
+    pub maximizable: bool,
     pub restrict_scroll: Option<ScrollAxis>,
 }
 
@@ -528,6 +832,8 @@ impl Default for FloatingPanelConfig {
             foldable: true,
             folded: false,
             closable: true,
+            minimizable: true,
+            maximizable: true,
             restrict_scroll: None,
         }
     }
@@ -539,17 +845,41 @@ impl FloatingPanelConfig {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum FloatingPanelState {
+    #[default]
+    Normal,
+    /// Collapsed to its title bar, in the tray along the bottom of the window
+    Minimized,
+    /// Covering the whole window
+    Maximized,
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+struct FloatingPanelRestore {
+    position: Vec2,
+    size: Vec2,
+    folded: bool,
+}
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct FloatingPanel {
     size: Vec2,
     position: Vec2,
     z_index: Option<usize>,
+    state: FloatingPanelState,
+    restore: Option<FloatingPanelRestore>,
+    auto_place: bool,
+    drag_position: Option<Vec2>,
+    apply_layout: bool,
     drag_handle: Entity,
     fold_button: Entity,
     title_container: Entity,
     title: Entity,
     close_button_container: Entity,
+    minimize_button: Entity,
+    maximize_button: Entity,
     close_button: Entity,
     content_view: Entity,
     content_panel_container: Entity,
@@ -566,11 +896,18 @@ impl Default for FloatingPanel {
             size: Default::default(),
             position: Default::default(),
             z_index: Default::default(),
+            state: Default::default(),
+            restore: None,
+            auto_place: false,
+            drag_position: None,
+            apply_layout: false,
             drag_handle: Entity::PLACEHOLDER,
             fold_button: Entity::PLACEHOLDER,
             title_container: Entity::PLACEHOLDER,
             title: Entity::PLACEHOLDER,
             close_button_container: Entity::PLACEHOLDER,
+            minimize_button: Entity::PLACEHOLDER,
+            maximize_button: Entity::PLACEHOLDER,
             close_button: Entity::PLACEHOLDER,
             content_view: Entity::PLACEHOLDER,
             content_panel_container: Entity::PLACEHOLDER,
@@ -591,6 +928,8 @@ impl UiContext for FloatingPanel {
             FloatingPanel::TITLE => Ok(self.title),
             FloatingPanel::FOLD_BUTTON => Ok(self.fold_button),
             FloatingPanel::CLOSE_BUTTON_CONTAINER => Ok(self.close_button_container),
+            FloatingPanel::MINIMIZE_BUTTON => Ok(self.minimize_button),
+            FloatingPanel::MAXIMIZE_BUTTON => Ok(self.maximize_button),
             FloatingPanel::CLOSE_BUTTON => Ok(self.close_button),
             FloatingPanel::CONTENT_VIEW => Ok(self.content_view),
             _ => Err(format!(
@@ -608,6 +947,8 @@ impl UiContext for FloatingPanel {
             FloatingPanel::TITLE,
             FloatingPanel::FOLD_BUTTON,
             FloatingPanel::CLOSE_BUTTON_CONTAINER,
+            FloatingPanel::MINIMIZE_BUTTON,
+            FloatingPanel::MAXIMIZE_BUTTON,
             FloatingPanel::CLOSE_BUTTON,
             FloatingPanel::CONTENT_VIEW,
         ]
@@ -627,15 +968,42 @@ impl FloatingPanel {
     pub const TITLE: &'static str = "Title";
     pub const FOLD_BUTTON: &'static str = "FoldButton";
     pub const CLOSE_BUTTON_CONTAINER: &'static str = "CloseButtonContainer";
+    pub const MINIMIZE_BUTTON: &'static str = "MinimizeButton";
+    pub const MAXIMIZE_BUTTON: &'static str = "MaximizeButton";
     pub const CLOSE_BUTTON: &'static str = "CloseButton";
     pub const CONTENT_VIEW: &'static str = "ContentView";
 
     pub fn theme() -> Theme<FloatingPanel> {
         let base_theme = PseudoTheme::deferred_context(None, FloatingPanel::primary_style);
         let folded_theme =
-            PseudoTheme::deferred_context(vec![PseudoState::Folded], FloatingPanel::folded_style);
-
-        Theme::new(vec![base_theme, folded_theme])
+            PseudoTheme::deferred_context(vec![PseudoState::Folded], FloatingPanel::folded_style)
+                .depends_on(
+                    ThemeDataSections::COLORS
+                        | ThemeDataSections::SPACING
+                        | ThemeDataSections::ICONS
+                        | ThemeDataSections::ANIMATIONS,
+                );
+        let minimized_theme =
+            PseudoTheme::deferred(vec![PseudoState::Minimized], FloatingPanel::minimized_style)
+                .depends_on(
+                    ThemeDataSections::COLORS
+                        | ThemeDataSections::SPACING
+                        | ThemeDataSections::ICONS,
+                );
+        let maximized_theme =
+            PseudoTheme::deferred(vec![PseudoState::Maximized], FloatingPanel::maximized_style)
+                .depends_on(
+                    ThemeDataSections::COLORS
+                        | ThemeDataSections::SPACING
+                        | ThemeDataSections::ICONS,
+                );
+
+        Theme::new(vec![
+            base_theme,
+            folded_theme,
+            minimized_theme,
+            maximized_theme,
+        ])
     }
 
     fn primary_style(
@@ -747,6 +1115,24 @@ impl FloatingPanel {
             })
             .copy_from(theme_data.interaction_animation);
 
+        for (button, icon) in [
+            (FloatingPanel::MINIMIZE_BUTTON, &theme_data.icons.minimize),
+            (FloatingPanel::MAXIMIZE_BUTTON, &theme_data.icons.maximize),
+        ] {
+            style_builder
+                .switch_context(button, None)
+                .size(Val::Px(theme_spacing.icons.small))
+                .margin(UiRect::all(Val::Px(theme_spacing.gaps.small)))
+                .icon(icon.with(colors.on(On::Surface), theme_spacing.icons.small))
+                .animated()
+                .font_color(AnimatedVals {
+                    idle: colors.on(On::SurfaceVariant),
+                    hover: colors.on(On::Surface).into(),
+                    ..default()
+                })
+                .copy_from(theme_data.interaction_animation);
+        }
+
         style_builder
             .switch_context(FloatingPanel::CLOSE_BUTTON, None)
             .size(Val::Px(theme_spacing.icons.small))
@@ -804,6 +1190,92 @@ impl FloatingPanel {
             );
     }
 
+    fn minimized_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(FloatingPanel::MINIMIZE_BUTTON)
+            .icon(
+                theme_data
+                    .icons
+                    .restore
+                    .with(colors.on(On::Surface), theme_spacing.icons.small),
+            );
+    }
+
+    fn maximized_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .border_radius(BorderRadius::all(Val::Px(0.)))
+            .switch_target(FloatingPanel::MAXIMIZE_BUTTON)
+            .icon(
+                theme_data
+                    .icons
+                    .restore
+                    .with(colors.on(On::Surface), theme_spacing.icons.small),
+            );
+    }
+
+    pub fn state(&self) -> FloatingPanelState {
+        self.state
+    }
+
+    fn rect(&self) -> Rect {
+        Rect::from_corners(self.position, self.position + self.size)
+    }
+
+    fn restore(&mut self, config: &mut FloatingPanelConfig) {
+        if let Some(restore) = self.restore.take() {
+            self.position = restore.position;
+            self.size = restore.size;
+            config.folded = restore.folded;
+        }
+
+        self.state = FloatingPanelState::Normal;
+        self.apply_layout = true;
+    }
+
+    fn minimize(&mut self, config: &mut FloatingPanelConfig) {
+        if self.state == FloatingPanelState::Minimized {
+            return;
+        } else if self.state == FloatingPanelState::Maximized {
+            self.restore(config);
+        }
+
+        self.restore = FloatingPanelRestore {
+            position: self.position,
+            size: self.size,
+            folded: config.folded,
+        }
+        .into();
+        config.folded = true;
+        self.state = FloatingPanelState::Minimized;
+        self.apply_layout = true;
+    }
+
+    fn maximize(&mut self, config: &mut FloatingPanelConfig, area: Vec2) {
+        if self.state == FloatingPanelState::Maximized {
+            return;
+        } else if self.state == FloatingPanelState::Minimized {
+            self.restore(config);
+        }
+
+        self.restore = FloatingPanelRestore {
+            position: self.position,
+            size: self.size,
+            folded: config.folded,
+        }
+        .into();
+        config.folded = false;
+        self.position = Vec2::ZERO;
+        self.size = area;
+        self.state = FloatingPanelState::Maximized;
+        self.apply_layout = true;
+    }
+
     pub fn content_panel_container(&self) -> Entity {
         self.content_panel_container
     }
@@ -888,6 +1360,26 @@ impl FloatingPanel {
         )
     }
 
+    fn minimize_button(panel: Entity) -> impl Bundle {
+        (
+            Name::new("Minimize Button"),
+            ButtonBundle::default(),
+            ContentSize::default(),
+            TrackedInteraction::default(),
+            FloatingPanelMinimizeButton { panel },
+        )
+    }
+
+    fn maximize_button(panel: Entity) -> impl Bundle {
+        (
+            Name::new("Maximize Button"),
+            ButtonBundle::default(),
+            ContentSize::default(),
+            TrackedInteraction::default(),
+            FloatingPanelMaximizeButton { panel },
+        )
+    }
+
     fn close_button(panel: Entity) -> impl Bundle {
         (
             Name::new("Close Button"),
@@ -931,6 +1423,292 @@ pub struct UpdateFloatingPanelPanelId {
     pub panel_id: Entity,
 }
 
+/// Where floating panels spawned without a position are placed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum FloatingPanelPlacement {
+    /// At the top left corner of the window
+    Origin,
+    /// Each new panel slightly offset from the previous one
+    #[default]
+    Cascade,
+    /// In the first free spot that doesn't overlap other panels, cascading when there is none
+    Tile,
+}
+
+/// Controls placement, snapping and the title bar behavior of floating panels
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct FloatingPanelBehavior {
+    pub placement: FloatingPanelPlacement,
+    /// Offset between cascaded panels
+    pub cascade_offset: Vec2,
+    /// Gap between tiled panels and between minimized panels in the tray
+    pub tile_gap: f32,
+    /// Distance in pixels within which dragged panels snap to edges, zero disables snapping
+    pub snap_distance: f32,
+    pub snap_to_window: bool,
+    pub snap_to_panels: bool,
+    /// Maximum time in seconds between title bar clicks to toggle maximize
+    pub double_click_time: f32,
+}
+
+impl Default for FloatingPanelBehavior {
+    fn default() -> Self {
+        Self {
+            placement: Default::default(),
+            cascade_offset: Vec2::splat(30.),
+            tile_gap: 4.,
+            snap_distance: 10.,
+            snap_to_window: true,
+            snap_to_panels: true,
+            double_click_time: 0.4,
+        }
+    }
+}
+
+impl FloatingPanelBehavior {
+    fn place(&self, size: Vec2, placed: &[Rect], area: Vec2) -> Vec2 {
+        match self.placement {
+            FloatingPanelPlacement::Origin => Vec2::ZERO,
+            FloatingPanelPlacement::Cascade => self.cascade(size, placed, area),
+            FloatingPanelPlacement::Tile => {
+                let gap = self.tile_gap;
+                let mut xs: Vec<f32> = placed.iter().map(|rect| rect.max.x + gap).collect();
+                let mut ys: Vec<f32> = placed.iter().map(|rect| rect.max.y + gap).collect();
+                xs.push(gap);
+                ys.push(gap);
+                xs.sort_by(f32::total_cmp);
+                ys.sort_by(f32::total_cmp);
+
+                for y in &ys {
+                    for x in &xs {
+                        let rect = Rect::from_corners(Vec2::new(*x, *y), Vec2::new(*x, *y) + size);
+                        if rect.max.x <= area.x
+                            && rect.max.y <= area.y
+                            && placed.iter().all(|other| other.intersect(rect).is_empty())
+                        {
+                            return rect.min;
+                        }
+                    }
+                }
+
+                self.cascade(size, placed, area)
+            }
+        }
+    }
+
+    fn cascade(&self, size: Vec2, placed: &[Rect], area: Vec2) -> Vec2 {
+        let step = self.cascade_offset.max(Vec2::ONE);
+        let steps = ((area - size) / step).min_element().floor().max(1.) as usize;
+        let taken = |position: Vec2| {
+            placed
+                .iter()
+                .any(|rect| (rect.min - position).abs().max_element() < 1.)
+        };
+
+        (0..=placed.len())
+            .map(|i| step * ((i % steps) + 1) as f32)
+            .find(|position| !taken(*position))
+            .unwrap_or(step)
+    }
+
+    fn snap(&self, position: Vec2, size: Vec2, others: &[Rect], area: Option<Vec2>) -> Vec2 {
+        let distance = self.snap_distance;
+        if distance <= 0. {
+            return position;
+        }
+
+        let mut snapped = position;
+        for axis in 0..2 {
+            let cross = 1 - axis;
+            let start = position[axis];
+            let end = start + size[axis];
+            let mut best: Option<f32> = None;
+            let mut consider = |edge: f32, target: f32| {
+                let offset = target - edge;
+                if offset.abs() <= distance && !best.is_some_and(|best| offset.abs() >= best.abs())
+                {
+                    best = offset.into();
+                }
+            };
+
+            if let (true, Some(area)) = (self.snap_to_window, area) {
+                consider(start, 0.);
+                consider(end, area[axis]);
+            }
+
+            if self.snap_to_panels {
+                for rect in others {
+                    // Only snap to panels that are next to each other on the other axis
+                    if position[cross] > rect.max[cross] + distance
+                        || position[cross] + size[cross] < rect.min[cross] - distance
+                    {
+                        continue;
+                    }
+
+                    consider(start, rect.max[axis]);
+                    consider(end, rect.min[axis]);
+                    consider(start, rect.min[axis]);
+                    consider(end, rect.max[axis]);
+                }
+            }
+
+            if let Some(offset) = best {
+                snapped[axis] += offset;
+            }
+        }
+
+        snapped
+    }
+}
+
+/// Stacking order of floating panels, from bottom to top
+#[derive(Resource, Debug, Default)]
+pub struct FloatingPanelZOrder {
+    order: Vec<Entity>,
+}
+
+impl FloatingPanelZOrder {
+    pub fn iter(&self) -> impl Iterator<Item = &Entity> + '_ {
+        self.order.iter()
+    }
+
+    pub fn top(&self) -> Option<Entity> {
+        self.order.last().copied()
+    }
+}
+
+/// Minimized floating panels, in the order they appear in the tray
+#[derive(Resource, Debug, Default)]
+pub struct FloatingPanelTray {
+    panels: Vec<Entity>,
+}
+
+impl FloatingPanelTray {
+    pub fn iter(&self) -> impl Iterator<Item = &Entity> + '_ {
+        self.panels.iter()
+    }
+}
+
+/// Moves the floating panel above all other floating panels
+pub(crate) struct BringFloatingPanelToFront {
+    pub panel: Entity,
+}
+
+impl Command for BringFloatingPanelToFront {
+    fn apply(self, world: &mut World) {
+        let mut q_panels = world.query::<&mut FloatingPanel>();
+        if q_panels.get(world, self.panel).is_err() {
+            warn!(
+                "Failed to bring {} to front: Not a FloatingPanel",
+                self.panel
+            );
+            return;
+        }
+
+        let order: Vec<Entity> = {
+            let mut z_order = world.resource_mut::<FloatingPanelZOrder>();
+            if z_order.top() == Some(self.panel) {
+                return;
+            }
+
+            z_order.order.retain(|panel| *panel != self.panel);
+            z_order.order.push(self.panel);
+            z_order.order.clone()
+        };
+
+        for (i, entity) in order.iter().enumerate() {
+            let Ok(mut panel) = q_panels.get_mut(world, *entity) else {
+                continue;
+            };
+
+            let z_index = Some(MIN_FLOATING_PANEL_Z_INDEX + i + 1);
+            if panel.z_index != z_index {
+                panel.z_index = z_index;
+            }
+        }
+    }
+}
+
+pub(crate) struct SetFloatingPanelState {
+    pub panel: Entity,
+    pub state: FloatingPanelState,
+}
+
+impl Command for SetFloatingPanelState {
+    fn apply(self, world: &mut World) {
+        let area = UiUtils::find_render_target(self.panel, world)
+            .map(|target| UiUtils::render_target_size(target, world))
+            .unwrap_or_else(|| {
+                UiUtils::resolution_to_vec2(&UiUtils::get_primary_window(world).resolution)
+            });
+
+        let mut q_panels = world.query::<(&mut FloatingPanel, &mut FloatingPanelConfig)>();
+        let Ok((mut panel, mut config)) = q_panels.get_mut(world, self.panel) else {
+            warn!("Failed to set state of {}: Not a FloatingPanel", self.panel);
+            return;
+        };
+
+        match self.state {
+            FloatingPanelState::Normal => {
+                if panel.state != FloatingPanelState::Normal {
+                    panel.restore(&mut config);
+                }
+            }
+            FloatingPanelState::Minimized => panel.minimize(&mut config),
+            FloatingPanelState::Maximized => panel.maximize(&mut config, area),
+        }
+
+        if self.state != FloatingPanelState::Minimized {
+            BringFloatingPanelToFront { panel: self.panel }.apply(world);
+        }
+    }
+}
+
+pub trait FloatingPanelCommandsExt {
+    /// Moves the floating panel above all other floating panels
+    fn bring_floating_panel_to_front(&mut self, panel: Entity) -> &mut Self;
+
+    /// Collapses the floating panel to its title bar, in the tray along the bottom of the window
+    fn minimize_floating_panel(&mut self, panel: Entity) -> &mut Self;
+
+    /// Makes the floating panel cover the whole window
+    fn maximize_floating_panel(&mut self, panel: Entity) -> &mut Self;
+
+    /// Restores a minimized or maximized floating panel to its previous position and size
+    fn restore_floating_panel(&mut self, panel: Entity) -> &mut Self;
+}
+
+impl FloatingPanelCommandsExt for Commands<'_, '_> {
+    fn bring_floating_panel_to_front(&mut self, panel: Entity) -> &mut Self {
+        self.add(BringFloatingPanelToFront { panel });
+        self
+    }
+
+    fn minimize_floating_panel(&mut self, panel: Entity) -> &mut Self {
+        self.add(SetFloatingPanelState {
+            panel,
+            state: FloatingPanelState::Minimized,
+        });
+        self
+    }
+
+    fn maximize_floating_panel(&mut self, panel: Entity) -> &mut Self {
+        self.add(SetFloatingPanelState {
+            panel,
+            state: FloatingPanelState::Maximized,
+        });
+        self
+    }
+
+    fn restore_floating_panel(&mut self, panel: Entity) -> &mut Self {
+        self.add(SetFloatingPanelState {
+            panel,
+            state: FloatingPanelState::Normal,
+        });
+        self
+    }
+}
+
 pub trait UiFloatingPanelExt {
     fn floating_panel<'a>(
         &'a mut self,
@@ -963,6 +1741,7 @@ impl<T: UiContainerExt> UiFloatingPanelExt for T {
             size: layout.size.max(MIN_PANEL_SIZE),
             position: layout.position.unwrap_or_default(),
             z_index: None,
+            auto_place: layout.position.is_none(),
             ..default()
         };
 
@@ -991,6 +1770,16 @@ impl<T: UiContainerExt> UiFloatingPanelExt for T {
                         .container(
                             FloatingPanel::close_button_container(),
                             |close_button_container| {
+                                floating_panel.minimize_button = close_button_container
+                                    .spawn(FloatingPanel::minimize_button(panel))
+                                    .style()
+                                    .render(config.minimizable)
+                                    .id();
+                                floating_panel.maximize_button = close_button_container
+                                    .spawn(FloatingPanel::maximize_button(panel))
+                                    .style()
+                                    .render(config.maximizable)
+                                    .id();
                                 floating_panel.close_button = close_button_container
                                     .spawn(FloatingPanel::close_button(panel))
                                     .style()
@@ -1038,7 +1827,110 @@ impl<T: UiContainerExt> UiFloatingPanelExt for T {
             frame.insert(PseudoStates::from(vec![PseudoState::Folded]));
         }
 
-        frame.insert((config, floating_panel));
+        frame.insert((
+            config,
+            floating_panel,
+            AccessibleWidget::new(Role::Dialog).named(title_text),
+        ));
         frame
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect {
+        Rect::new(x, y, x + width, y + height)
+    }
+
+    #[test]
+    fn cascade_wraps_around_within_the_area() {
+        let behavior = FloatingPanelBehavior::default();
+        let size = Vec2::splat(100.);
+        let area = Vec2::splat(200.);
+
+        let mut placed = Vec::new();
+        for expected in [30., 60., 90., 30.] {
+            let position = behavior.place(size, &placed, area);
+            assert_eq!(position, Vec2::splat(expected));
+            assert!((position + size).cmple(area).all());
+            placed.push(Rect::from_corners(position, position + size));
+        }
+
+        // Freed positions are reused first
+        let placed = [rect(60., 60., 100., 100.)];
+        assert_eq!(behavior.place(size, &placed, area), Vec2::splat(30.));
+    }
+
+    #[test]
+    fn tile_places_next_to_other_panels_within_the_area() {
+        let behavior = FloatingPanelBehavior {
+            placement: FloatingPanelPlacement::Tile,
+            ..default()
+        };
+        let size = Vec2::splat(100.);
+        let area = Vec2::new(220., 120.);
+
+        assert_eq!(behavior.place(size, &[], area), Vec2::splat(4.));
+
+        let placed = [rect(4., 4., 100., 100.)];
+        assert_eq!(behavior.place(size, &placed, area), Vec2::new(108., 4.));
+
+        // No free spot inside the area, falls back to cascading
+        let placed = [rect(4., 4., 100., 100.), rect(108., 4., 100., 100.)];
+        assert_eq!(behavior.place(size, &placed, area), Vec2::splat(30.));
+    }
+
+    #[test]
+    fn snaps_to_a_neighbour_edge_within_the_threshold() {
+        let behavior = FloatingPanelBehavior::default();
+        let size = Vec2::splat(50.);
+        let others = [rect(0., 0., 100., 100.)];
+
+        assert_eq!(
+            behavior.snap(Vec2::new(108., 20.), size, &others, None),
+            Vec2::new(100., 20.)
+        );
+        // Outside the threshold
+        assert_eq!(
+            behavior.snap(Vec2::new(115., 20.), size, &others, None),
+            Vec2::new(115., 20.)
+        );
+        // Not next to the other panel on the cross axis
+        assert_eq!(
+            behavior.snap(Vec2::new(108., 200.), size, &others, None),
+            Vec2::new(108., 200.)
+        );
+        // Aligns with the neighbour's top edge too
+        assert_eq!(
+            behavior.snap(Vec2::new(104., -6.), size, &others, None),
+            Vec2::new(100., 0.)
+        );
+    }
+
+    #[test]
+    fn snaps_to_the_area_edges() {
+        let behavior = FloatingPanelBehavior::default();
+        let size = Vec2::splat(100.);
+        let area = Some(Vec2::new(800., 500.));
+
+        assert_eq!(
+            behavior.snap(Vec2::new(-5., 392.), size, &[], area),
+            Vec2::new(0., 400.)
+        );
+        assert_eq!(
+            behavior.snap(Vec2::new(300., 200.), size, &[], area),
+            Vec2::new(300., 200.)
+        );
+
+        let behavior = FloatingPanelBehavior {
+            snap_distance: 0.,
+            ..default()
+        };
+        assert_eq!(
+            behavior.snap(Vec2::new(-5., 392.), size, &[], area),
+            Vec2::new(-5., 392.)
+        );
+    }
+}
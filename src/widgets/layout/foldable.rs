@@ -1,5 +1,6 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{a11y::accesskit::Role, prelude::*, ui::FocusPolicy};
 
+use sickle_math::ease::{Ease, ValueEasing};
 use sickle_ui_scaffold::prelude::*;
 
 use crate::widgets::{menus::menu_item::MenuItemUpdate, WidgetLibraryUpdate};
@@ -24,7 +25,12 @@ impl Plugin for FoldablePlugin {
         .add_plugins(ComponentThemePlugin::<Foldable>::default())
         .add_systems(
             Update,
-            (handle_foldable_button_press, update_foldable_container)
+            (
+                handle_foldable_button_press,
+                close_grouped_foldables,
+                update_foldable_container,
+                update_foldable_transition,
+            )
                 .chain()
                 .in_set(FoldableUpdate),
         );
@@ -47,11 +53,46 @@ fn handle_foldable_button_press(
     }
 }
 
+/// Closes the other open foldables of the group when a foldable is opened
+fn close_grouped_foldables(mut q_foldables: Query<(Entity, &mut Foldable)>) {
+    let mut opened: Vec<(Entity, String)> = Vec::new();
+    for (entity, mut foldable) in &mut q_foldables {
+        if !foldable.is_changed() || foldable.open == foldable.was_open {
+            continue;
+        }
+
+        let foldable = foldable.bypass_change_detection();
+        foldable.was_open = foldable.open;
+        if let (true, Some(group)) = (foldable.open, foldable.group.clone()) {
+            opened.push((entity, group));
+        }
+    }
+
+    for (opened, group) in opened {
+        for (entity, mut foldable) in &mut q_foldables {
+            if entity != opened && foldable.open && foldable.group.as_ref() == Some(&group) {
+                foldable.open = false;
+                // Changes made here are not seen by this system on its next run
+                foldable.was_open = false;
+            }
+        }
+    }
+}
+
+type FoldableState<'a> = (
+    Entity,
+    &'a mut Foldable,
+    Option<&'a mut FoldableLazyContent>,
+    Option<&'a FoldableTransition>,
+);
+
 fn update_foldable_container(
-    q_foldables: Query<(Entity, &Foldable), Changed<Foldable>>,
+    r_theme_data: Res<ThemeData>,
+    mut q_foldables: Query<FoldableState, Changed<Foldable>>,
+    q_node: Query<&Node>,
     mut commands: Commands,
 ) {
-    for (entity, foldable) in &q_foldables {
+    for (entity, mut foldable, lazy_content, transition) in &mut q_foldables {
         if foldable.empty {
             commands
                 .entity(entity)
@@ -65,23 +106,166 @@ fn update_foldable_container(
                 .remove_pseudo_state(PseudoState::Empty);
         }
 
+        if foldable.open != foldable.folded {
+            if transition.is_none() {
+                if foldable.open {
+                    commands
+                        .entity(entity)
+                        .remove_pseudo_state(PseudoState::Folded);
+                } else {
+                    commands
+                        .entity(entity)
+                        .add_pseudo_state(PseudoState::Folded);
+                }
+            }
+
+            continue;
+        }
+
+        foldable.bypass_change_detection().folded = !foldable.open;
+
         if foldable.open {
+            if let Some(mut lazy_content) = lazy_content {
+                if !lazy_content.built {
+                    (lazy_content.spawn_children)(&mut commands.ui_builder(foldable.container));
+                    lazy_content.built = true;
+                }
+            }
+
             commands
                 .entity(entity)
                 .remove_pseudo_state(PseudoState::Folded);
-        } else {
+        }
+
+        // Continue from the current height if the foldable is interrupted mid-transition
+        let from = match transition {
+            Some(transition) => transition.height,
+            None if foldable.open => 0.,
+            None => q_node
+                .get(foldable.container)
+                .map_or(0., |node| node.size().y),
+        };
+
+        let animation = r_theme_data.enter_animation.enter;
+        match animation.filter(|animation| animation.duration > 0.) {
+            Some(animation) => {
+                commands.entity(entity).insert(FoldableTransition {
+                    opening: foldable.open,
+                    from,
+                    height: from,
+                    elapsed: 0.,
+                    duration: animation.duration,
+                    easing: animation.easing(),
+                });
+            }
+            None => {
+                commands.entity(entity).remove::<FoldableTransition>();
+                commands
+                    .style_unchecked(foldable.container)
+                    .max_height(Val::Auto)
+                    .overflow(Overflow::visible());
+
+                if !foldable.open {
+                    commands
+                        .entity(entity)
+                        .add_pseudo_state(PseudoState::Folded);
+                    despawn_lazy_content(&foldable, entity, &mut commands);
+                }
+            }
+        }
+    }
+}
+
+/// Animates the height of the container towards its content's height, or zero when folding
+fn update_foldable_transition(
+    r_time: Res<Time>,
+    mut q_foldables: Query<(Entity, &Foldable, &mut FoldableTransition)>,
+    q_node: Query<(&Node, &GlobalTransform)>,
+    q_children: Query<&Children>,
+    mut commands: Commands,
+) {
+    for (entity, foldable, mut transition) in &mut q_foldables {
+        transition.elapsed += r_time.delta_seconds();
+
+        if transition.elapsed >= transition.duration {
+            commands.entity(entity).remove::<FoldableTransition>();
             commands
-                .entity(entity)
-                .add_pseudo_state(PseudoState::Folded);
+                .style_unchecked(foldable.container)
+                .max_height(Val::Auto)
+                .overflow(Overflow::visible());
+
+            if !transition.opening {
+                commands
+                    .entity(entity)
+                    .add_pseudo_state(PseudoState::Folded);
+                despawn_lazy_content(foldable, entity, &mut commands);
+            }
+
+            continue;
         }
+
+        // The content height is measured every frame, as lazy content is laid out during the transition
+        let to = match transition.opening {
+            true => content_height(foldable.container, &q_node, &q_children),
+            false => 0.,
+        };
+        let progress = (transition.elapsed / transition.duration).ease(transition.easing);
+        transition.height = transition.from.lerp(to, progress);
+
+        commands
+            .style_unchecked(foldable.container)
+            .max_height(Val::Px(transition.height))
+            .overflow(Overflow::clip_y());
     }
 }
 
+fn content_height(
+    container: Entity,
+    q_node: &Query<(&Node, &GlobalTransform)>,
+    q_children: &Query<&Children>,
+) -> f32 {
+    let Ok((node, transform)) = q_node.get(container) else {
+        return 0.;
+    };
+    let Ok(children) = q_children.get(container) else {
+        return 0.;
+    };
+
+    let top = transform.translation().y - (node.size().y / 2.);
+    children
+        .iter()
+        .filter_map(|child| q_node.get(*child).ok())
+        .map(|(node, transform)| transform.translation().y + (node.size().y / 2.) - top)
+        .fold(0., f32::max)
+}
+
+fn despawn_lazy_content(foldable: &Foldable, entity: Entity, commands: &mut Commands) {
+    let container = foldable.container;
+    commands.add(move |world: &mut World| {
+        let Some(mut lazy_content) = world.get_mut::<FoldableLazyContent>(entity) else {
+            return;
+        };
+        if !lazy_content.despawn_on_close {
+            return;
+        }
+
+        lazy_content.built = false;
+        if let Some(mut container) = world.get_entity_mut(container) {
+            container.despawn_descendants();
+        }
+    });
+}
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct Foldable {
     pub open: bool,
     pub empty: bool,
+    /// Only one foldable of a group can be open at a time
+    pub group: Option<String>,
+    folded: bool,
+    /// Value of `open` last seen by `close_grouped_foldables`
+    was_open: bool,
     icon: Entity,
     label: Entity,
     container: Entity,
@@ -92,6 +276,9 @@ impl Default for Foldable {
         Self {
             open: Default::default(),
             empty: Default::default(),
+            group: None,
+            folded: true,
+            was_open: false,
             icon: Entity::PLACEHOLDER,
             label: Entity::PLACEHOLDER,
             container: Entity::PLACEHOLDER,
@@ -99,6 +286,52 @@ impl Default for Foldable {
     }
 }
 
+type SpawnFoldableContent = Box<dyn Fn(&mut UiBuilder<Entity>) + Send + Sync>;
+
+/// Builds the content of a foldable when it is first opened
+#[derive(Component)]
+pub struct FoldableLazyContent {
+    spawn_children: SpawnFoldableContent,
+    despawn_on_close: bool,
+    built: bool,
+}
+
+impl FoldableLazyContent {
+    pub fn is_built(&self) -> bool {
+        self.built
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+struct FoldableTransition {
+    opening: bool,
+    from: f32,
+    height: f32,
+    elapsed: f32,
+    duration: f32,
+    easing: Ease,
+}
+
+#[derive(Debug, Default)]
+pub struct FoldableConfig {
+    pub name: String,
+    pub open: bool,
+    pub empty: bool,
+    /// Opening a foldable closes the other foldables of its group, like an accordion
+    pub group: Option<String>,
+    /// Despawns the content of lazy foldables when they are folded, to be built again on open
+    pub despawn_on_close: bool,
+}
+
+impl FoldableConfig {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..default()
+        }
+    }
+}
+
 impl UiContext for Foldable {
     fn get(&self, target: &str) -> Result<Entity, String> {
         match target {
@@ -136,8 +369,14 @@ impl Foldable {
 
     pub fn theme() -> Theme<Foldable> {
         let base_theme = PseudoTheme::deferred(None, Foldable::primary_style);
-        let folded_theme = PseudoTheme::deferred(vec![PseudoState::Folded], Foldable::folded_style);
-        let empty_theme = PseudoTheme::deferred(vec![PseudoState::Empty], Foldable::empty_style);
+        let folded_theme = PseudoTheme::deferred(vec![PseudoState::Folded], Foldable::folded_style)
+            .depends_on(
+                ThemeDataSections::COLORS | ThemeDataSections::SPACING | ThemeDataSections::ICONS,
+            );
+        let empty_theme = PseudoTheme::deferred(vec![PseudoState::Empty], Foldable::empty_style)
+            .depends_on(
+                ThemeDataSections::COLORS | ThemeDataSections::SPACING | ThemeDataSections::ICONS,
+            );
 
         Theme::new(vec![base_theme, folded_theme, empty_theme])
     }
@@ -249,6 +488,18 @@ pub trait UiFoldableExt {
         empty: bool,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity>;
+
+    fn foldable_with_config(
+        &mut self,
+        config: FoldableConfig,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<Entity>;
+
+    fn lazy_foldable(
+        &mut self,
+        config: FoldableConfig,
+        spawn_children: impl Fn(&mut UiBuilder<Entity>) + Send + Sync + 'static,
+    ) -> UiBuilder<Entity>;
 }
 
 impl UiFoldableExt for UiBuilder<'_, Entity> {
@@ -264,11 +515,36 @@ impl UiFoldableExt for UiBuilder<'_, Entity> {
         empty: bool,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity> {
-        let name = name.into();
+        self.foldable_with_config(
+            FoldableConfig {
+                name: name.into(),
+                open,
+                empty,
+                ..default()
+            },
+            spawn_children,
+        )
+    }
+
+    /// A foldable panel, animating its height with the theme's `enter_animation` when
+    /// folded or unfolded.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Folded` is used when the panel is folded, it is added once folding finished
+    /// - `PseudoState::Empty` is used when the folded panel is set to be empty. This is used for styling its icon
+    fn foldable_with_config(
+        &mut self,
+        config: FoldableConfig,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<Entity> {
+        let name = config.name;
+        let open = config.open;
 
         let mut foldable = Foldable {
             open,
-            empty,
+            empty: config.empty,
+            group: config.group,
+            folded: !open,
             ..default()
         };
 
@@ -284,6 +560,7 @@ impl UiFoldableExt for UiBuilder<'_, Entity> {
             })
             .id();
 
+        let accessible = AccessibleWidget::new(Role::DisclosureTriangle).named(name.clone());
         foldable.container = self.panel(name, spawn_children).id();
         if !open {
             self.commands().style(foldable.container).hide();
@@ -292,7 +569,49 @@ impl UiFoldableExt for UiBuilder<'_, Entity> {
                 .add_pseudo_state(PseudoState::Folded);
         }
 
-        self.commands().entity(button).insert(foldable);
+        self.commands()
+            .entity(button)
+            .insert((foldable, accessible));
         self.commands().ui_builder(button)
     }
+
+    /// A foldable panel that builds its content when it is first opened, instead of up front.
+    /// With `despawn_on_close`, the content is despawned when folded and built again on open.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Folded` is used when the panel is folded, it is added once folding finished
+    fn lazy_foldable(
+        &mut self,
+        config: FoldableConfig,
+        spawn_children: impl Fn(&mut UiBuilder<Entity>) + Send + Sync + 'static,
+    ) -> UiBuilder<Entity> {
+        let despawn_on_close = config.despawn_on_close;
+        let open = config.open;
+
+        let mut foldable = self.foldable_with_config(
+            FoldableConfig {
+                empty: false,
+                ..config
+            },
+            |_| {},
+        );
+        foldable.insert(FoldableLazyContent {
+            spawn_children: Box::new(spawn_children),
+            despawn_on_close,
+            built: false,
+        });
+
+        if open {
+            // Open foldables are built on their first update
+            foldable
+                .entity_commands()
+                .add(|mut entity: EntityWorldMut| {
+                    if let Some(mut foldable) = entity.get_mut::<Foldable>() {
+                        foldable.folded = true;
+                    }
+                });
+        }
+
+        foldable
+    }
 }
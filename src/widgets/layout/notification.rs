@@ -0,0 +1,799 @@
+use bevy::{a11y::accesskit::Role, prelude::*};
+
+use sickle_math::ease::{Ease, ValueEasing};
+use sickle_ui_scaffold::{prelude::*, ui_commands::RefreshThemeExt};
+
+use crate::widgets::WidgetLibraryUpdate;
+
+use super::{
+    container::UiContainerExt,
+    label::{LabelConfig, UiLabelExt},
+};
+
+const TOAST_STACK_Z_INDEX: i32 = 100100;
+const TOAST_WIDTH: f32 = 320.;
+
+pub struct NotificationPlugin;
+
+impl Plugin for NotificationPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            NotificationUpdate
+                .after(FluxInteractionUpdate)
+                .before(WidgetLibraryUpdate),
+        )
+        .add_plugins((
+            ComponentThemePlugin::<ToastStack>::default(),
+            ComponentThemePlugin::<Toast>::default(),
+        ))
+        .init_resource::<Notifications>()
+        .add_event::<ToastActionPressed>()
+        .add_event::<ToastDismissed>()
+        .add_systems(
+            Update,
+            (
+                update_toast_stack_corner,
+                spawn_pushed_toasts,
+                handle_toast_button_press,
+                update_toast_timers,
+                dismiss_toasts,
+                update_toast_exit,
+            )
+                .chain()
+                .in_set(NotificationUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct NotificationUpdate;
+
+/// Sent when the action button of a toast is pressed. The toast is dismissed afterwards.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct ToastActionPressed {
+    pub toast: ToastId,
+    pub action: String,
+}
+
+/// Sent when a toast starts its exit, by timeout, user action, or [`Notifications::dismiss`]
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToastDismissed {
+    pub toast: ToastId,
+}
+
+fn update_toast_stack_corner(
+    r_notifications: Res<Notifications>,
+    mut q_stacks: Query<(Entity, &mut ToastStack)>,
+    mut q_toasts: Query<(Entity, &mut Toast)>,
+    mut commands: Commands,
+) {
+    for (entity, mut stack) in &mut q_stacks {
+        if stack.corner == r_notifications.corner {
+            continue;
+        }
+
+        stack.corner = r_notifications.corner;
+        commands.entity(entity).refresh_theme::<ToastStack>();
+    }
+
+    // Visible toasts slide in and out from the new corner
+    for (entity, mut toast) in &mut q_toasts {
+        if toast.corner != r_notifications.corner {
+            toast.corner = r_notifications.corner;
+            commands.entity(entity).refresh_theme::<Toast>();
+        }
+    }
+}
+
+fn spawn_pushed_toasts(
+    mut r_notifications: ResMut<Notifications>,
+    q_stacks: Query<Entity, With<ToastStack>>,
+    q_toasts: Query<(&Toast, Has<ToastExit>)>,
+    mut commands: Commands,
+) {
+    if r_notifications.pending.is_empty() {
+        return;
+    }
+
+    let corner = r_notifications.corner;
+    let stack = match q_stacks.iter().next() {
+        Some(stack) => stack,
+        None => commands
+            .ui_builder(UiRoot)
+            .container(ToastStack::frame(), |_| {})
+            .insert(ToastStack { corner })
+            .id(),
+    };
+
+    let mut pending: Vec<(ToastId, ToastConfig)> = r_notifications.pending.drain(..).collect();
+    let mut visible: Vec<ToastId> = q_toasts
+        .iter()
+        .filter(|(_, exiting)| !exiting)
+        .map(|(toast, _)| toast.id)
+        .collect();
+    visible.sort();
+
+    let (dismissed, skipped) =
+        toast_overflow(visible.len(), pending.len(), r_notifications.max_visible);
+    pending.drain(..skipped);
+    r_notifications
+        .dismissed
+        .extend(visible.into_iter().take(dismissed));
+
+    for (id, config) in pending {
+        let duration = match config.auto_dismiss {
+            true => config.duration.or(Some(r_notifications.duration)),
+            false => None,
+        };
+
+        commands
+            .ui_builder(stack)
+            .toast(id, config, corner, duration);
+    }
+}
+
+/// The number of oldest visible toasts to dismiss and of oldest pending toasts to skip so that
+/// at most `max_visible` toasts are shown. Pending toasts that would be dismissed right away
+/// are not spawned at all.
+fn toast_overflow(visible: usize, pending: usize, max_visible: usize) -> (usize, usize) {
+    let overflow = (visible + pending).saturating_sub(max_visible.max(1));
+    let skipped = overflow.saturating_sub(visible);
+
+    (overflow - skipped, skipped)
+}
+
+fn handle_toast_button_press(
+    q_buttons: Query<(&ToastButton, &FluxInteraction), Changed<FluxInteraction>>,
+    q_toasts: Query<&Toast, Without<ToastExit>>,
+    mut r_notifications: ResMut<Notifications>,
+    mut writer: EventWriter<ToastActionPressed>,
+) {
+    for (button, interaction) in &q_buttons {
+        if !interaction.is_released() {
+            continue;
+        }
+
+        let Ok(toast) = q_toasts.get(button.toast) else {
+            continue;
+        };
+
+        if button.is_action {
+            if let Some(action) = toast.action.clone() {
+                writer.send(ToastActionPressed {
+                    toast: toast.id,
+                    action,
+                });
+            }
+        }
+
+        r_notifications.dismiss(toast.id);
+    }
+}
+
+/// Counts down the visible toasts, unless the pointer is over them
+fn update_toast_timers(
+    r_time: Res<Time>,
+    mut q_toasts: Query<(&mut Toast, &Interaction), Without<ToastExit>>,
+    q_interaction: Query<&Interaction, Without<Toast>>,
+    mut r_notifications: ResMut<Notifications>,
+) {
+    for (mut toast, interaction) in &mut q_toasts {
+        let Some(remaining) = toast.remaining else {
+            continue;
+        };
+
+        let hovered = *interaction != Interaction::None
+            || [toast.action_button, toast.close_button]
+                .iter()
+                .filter_map(|button| q_interaction.get(*button).ok())
+                .any(|interaction| *interaction != Interaction::None);
+        if hovered {
+            continue;
+        }
+
+        let remaining = remaining - r_time.delta_seconds();
+        toast.remaining = Some(remaining);
+        if remaining <= 0. {
+            r_notifications.dismiss(toast.id);
+        }
+    }
+}
+
+fn dismiss_toasts(
+    mut r_notifications: ResMut<Notifications>,
+    r_theme_data: Res<ThemeData>,
+    q_toasts: Query<(Entity, &Toast, &Node), Without<ToastExit>>,
+    mut writer: EventWriter<ToastDismissed>,
+    mut commands: Commands,
+) {
+    if r_notifications.dismissed.is_empty() {
+        return;
+    }
+
+    let dismissed: Vec<ToastId> = r_notifications.dismissed.drain(..).collect();
+    let animation = r_theme_data
+        .enter_animation
+        .enter
+        .filter(|animation| animation.duration > 0.);
+
+    for (entity, toast, node) in &q_toasts {
+        if !dismissed.contains(&toast.id) {
+            continue;
+        }
+
+        writer.send(ToastDismissed { toast: toast.id });
+
+        let Some(animation) = animation else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+
+        commands.entity(entity).insert(ToastExit {
+            height: node.size().y,
+            elapsed: 0.,
+            duration: animation.duration,
+            easing: animation.easing(),
+        });
+        commands
+            .style(entity)
+            .lock_attribute(LockableStyleAttribute::Left)
+            .lock_attribute(LockableStyleAttribute::MaxHeight)
+            .lock_attribute(LockableStyleAttribute::Overflow);
+    }
+}
+
+/// Slides exiting toasts off screen while collapsing their height, then despawns them
+fn update_toast_exit(
+    r_time: Res<Time>,
+    mut q_toasts: Query<(Entity, &Toast, &mut ToastExit)>,
+    mut commands: Commands,
+) {
+    for (entity, toast, mut exit) in &mut q_toasts {
+        exit.elapsed += r_time.delta_seconds();
+
+        if exit.elapsed >= exit.duration {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let progress = (exit.elapsed / exit.duration).ease(exit.easing);
+        commands
+            .style_unchecked(entity)
+            .left(Val::Px(toast.corner.exit_offset() * progress))
+            .max_height(Val::Px(exit.height * (1. - progress)))
+            .overflow(Overflow::clip());
+    }
+}
+
+/// Uniquely identifies a toast pushed with [`Notifications::push`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+pub struct ToastId(u64);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum ToastSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    fn colors(&self) -> (Container, On) {
+        match self {
+            ToastSeverity::Info => (Container::Primary, On::PrimaryContainer),
+            ToastSeverity::Success => (Container::Tertiary, On::TertiaryContainer),
+            ToastSeverity::Warning => (Container::Secondary, On::SecondaryContainer),
+            ToastSeverity::Error => (Container::Error, On::ErrorContainer),
+        }
+    }
+}
+
+/// The screen corner toasts stack in. The newest toast is closest to the corner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum ToastCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl ToastCorner {
+    fn is_top(&self) -> bool {
+        matches!(self, ToastCorner::TopLeft | ToastCorner::TopRight)
+    }
+
+    fn is_left(&self) -> bool {
+        matches!(self, ToastCorner::TopLeft | ToastCorner::BottomLeft)
+    }
+
+    /// Horizontal offset that moves a toast off screen
+    fn exit_offset(&self) -> f32 {
+        match self.is_left() {
+            true => -TOAST_WIDTH,
+            false => TOAST_WIDTH,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ToastConfig {
+    pub title: Option<String>,
+    pub message: String,
+    pub severity: ToastSeverity,
+    /// Label of the action button. Pressing it sends a [`ToastActionPressed`] with the label.
+    pub action: Option<String>,
+    /// Seconds until the toast is dismissed, defaults to [`Notifications::duration`]
+    pub duration: Option<f32>,
+    /// Toasts that don't auto dismiss stay until closed or dismissed
+    pub auto_dismiss: bool,
+}
+
+impl ToastConfig {
+    pub fn new(severity: ToastSeverity, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            severity,
+            auto_dismiss: true,
+            ..default()
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(ToastSeverity::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(ToastSeverity::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(ToastSeverity::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ToastSeverity::Error, message)
+    }
+}
+
+/// Pushes toast notifications and configures where they stack
+#[derive(Resource, Debug)]
+pub struct Notifications {
+    pub corner: ToastCorner,
+    /// Seconds a toast stays visible, unless its config overrides it
+    pub duration: f32,
+    /// The oldest toasts are dismissed when more would be visible
+    pub max_visible: usize,
+    next_id: u64,
+    pending: Vec<(ToastId, ToastConfig)>,
+    dismissed: Vec<ToastId>,
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self {
+            corner: Default::default(),
+            duration: 5.,
+            max_visible: 5,
+            next_id: 0,
+            pending: Vec::new(),
+            dismissed: Vec::new(),
+        }
+    }
+}
+
+impl Notifications {
+    /// Queues a toast to be shown in the next update
+    pub fn push(&mut self, config: ToastConfig) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+        self.pending.push((id, config));
+
+        id
+    }
+
+    pub fn dismiss(&mut self, toast: ToastId) {
+        if let Some(index) = self.pending.iter().position(|(id, _)| *id == toast) {
+            self.pending.remove(index);
+        } else if !self.dismissed.contains(&toast) {
+            self.dismissed.push(toast);
+        }
+    }
+
+    pub fn dismiss_all(&mut self) {
+        self.pending.clear();
+        self.dismissed.extend((0..self.next_id).map(ToastId));
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ToastStack {
+    corner: ToastCorner,
+}
+
+impl DefaultTheme for ToastStack {
+    fn default_theme() -> Option<Theme<ToastStack>> {
+        ToastStack::theme().into()
+    }
+}
+
+impl UiContext for ToastStack {}
+
+impl ToastStack {
+    pub fn corner(&self) -> ToastCorner {
+        self.corner
+    }
+
+    pub fn theme() -> Theme<ToastStack> {
+        let base_theme = PseudoTheme::deferred_context(None, ToastStack::primary_style)
+            .depends_on(ThemeDataSections::SPACING);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, stack: &ToastStack, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let margin = Val::Px(theme_spacing.gaps.large);
+        let corner = stack.corner;
+
+        style_builder
+            .position_type(PositionType::Absolute)
+            .top(if corner.is_top() { margin } else { Val::Auto })
+            .bottom(if corner.is_top() { Val::Auto } else { margin })
+            .left(if corner.is_left() { margin } else { Val::Auto })
+            .right(if corner.is_left() { Val::Auto } else { margin })
+            .flex_direction(match corner.is_top() {
+                true => FlexDirection::ColumnReverse,
+                false => FlexDirection::Column,
+            })
+            .align_items(match corner.is_left() {
+                true => AlignItems::FlexStart,
+                false => AlignItems::FlexEnd,
+            })
+            .row_gap(Val::Px(theme_spacing.gaps.small))
+            .z_index(ZIndex::Global(TOAST_STACK_Z_INDEX));
+    }
+
+    fn frame() -> impl Bundle {
+        (Name::new("Toast Stack"), NodeBundle::default())
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+struct ToastButton {
+    toast: Entity,
+    is_action: bool,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+struct ToastExit {
+    height: f32,
+    elapsed: f32,
+    duration: f32,
+    easing: Ease,
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Toast {
+    id: ToastId,
+    severity: ToastSeverity,
+    corner: ToastCorner,
+    action: Option<String>,
+    /// Seconds left until the toast is dismissed
+    remaining: Option<f32>,
+    title: Entity,
+    message: Entity,
+    action_button: Entity,
+    action_label: Entity,
+    close_button: Entity,
+}
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self {
+            id: Default::default(),
+            severity: Default::default(),
+            corner: Default::default(),
+            action: None,
+            remaining: None,
+            title: Entity::PLACEHOLDER,
+            message: Entity::PLACEHOLDER,
+            action_button: Entity::PLACEHOLDER,
+            action_label: Entity::PLACEHOLDER,
+            close_button: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for Toast {
+    fn default_theme() -> Option<Theme<Toast>> {
+        Toast::theme().into()
+    }
+}
+
+impl UiContext for Toast {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            Toast::TITLE => Ok(self.title),
+            Toast::MESSAGE => Ok(self.message),
+            Toast::ACTION_BUTTON => Ok(self.action_button),
+            Toast::ACTION_LABEL => Ok(self.action_label),
+            Toast::CLOSE_BUTTON => Ok(self.close_button),
+            _ => Err(format!(
+                "{} doesn't exist for Toast. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [
+            Toast::TITLE,
+            Toast::MESSAGE,
+            Toast::ACTION_BUTTON,
+            Toast::ACTION_LABEL,
+            Toast::CLOSE_BUTTON,
+        ]
+        .into_iter()
+    }
+}
+
+impl Toast {
+    pub const TITLE: &'static str = "Title";
+    pub const MESSAGE: &'static str = "Message";
+    pub const ACTION_BUTTON: &'static str = "ActionButton";
+    pub const ACTION_LABEL: &'static str = "ActionLabel";
+    pub const CLOSE_BUTTON: &'static str = "CloseButton";
+
+    pub fn id(&self) -> ToastId {
+        self.id
+    }
+
+    pub fn severity(&self) -> ToastSeverity {
+        self.severity
+    }
+
+    /// Seconds left until the toast is dismissed, if it auto dismisses
+    pub fn remaining(&self) -> Option<f32> {
+        self.remaining
+    }
+
+    pub fn theme() -> Theme<Toast> {
+        let base_theme = PseudoTheme::deferred_context(None, Toast::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, toast: &Toast, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let (container, on) = toast.severity.colors();
+        let title_font = theme_data
+            .text
+            .get(FontStyle::Title, FontScale::Small, FontType::Bold);
+        let message_font =
+            theme_data
+                .text
+                .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let action_font = theme_data
+            .text
+            .get(FontStyle::Label, FontScale::Large, FontType::Bold);
+
+        style_builder
+            .width(Val::Px(TOAST_WIDTH))
+            .max_width(Val::Vw(90.))
+            .flex_direction(FlexDirection::Row)
+            .align_items(AlignItems::Center)
+            .column_gap(Val::Px(theme_spacing.gaps.small))
+            .padding(UiRect::all(Val::Px(theme_spacing.gaps.medium)))
+            .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.small)))
+            .background_color(colors.container(container))
+            .animated()
+            .left(AnimatedVals {
+                idle: Val::Px(0.),
+                enter_from: Val::Px(toast.corner.exit_offset()).into(),
+                ..default()
+            })
+            .copy_from(theme_data.enter_animation);
+
+        style_builder
+            .switch_target(Toast::TITLE)
+            .align_self(AlignSelf::Start)
+            .sized_font(title_font)
+            .font_color(colors.on(on));
+
+        style_builder
+            .switch_target(Toast::MESSAGE)
+            .align_self(AlignSelf::Start)
+            .sized_font(message_font)
+            .font_color(colors.on(on));
+
+        style_builder
+            .switch_target(Toast::ACTION_BUTTON)
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.medium),
+                Val::Px(theme_spacing.gaps.small),
+            ))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.on(on).with_alpha(0.),
+                hover: colors.on(on).with_alpha(0.12).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(Toast::ACTION_LABEL)
+            .sized_font(action_font)
+            .font_color(colors.on(on));
+
+        style_builder
+            .switch_target(Toast::CLOSE_BUTTON)
+            .size(Val::Px(theme_spacing.icons.small))
+            .icon(
+                theme_data
+                    .icons
+                    .close
+                    .with(colors.on(on), theme_spacing.icons.small),
+            )
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.on(on).with_alpha(0.),
+                hover: colors.on(on).with_alpha(0.12).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn frame(id: ToastId) -> impl Bundle {
+        (
+            Name::new(format!("Toast [{}]", id.0)),
+            NodeBundle {
+                focus_policy: bevy::ui::FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+            TrackedInteraction::default(),
+        )
+    }
+
+    fn content() -> impl Bundle {
+        (
+            Name::new("Content"),
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    flex_grow: 1.,
+                    ..default()
+                },
+                ..default()
+            },
+        )
+    }
+
+    fn button(name: &str, toast: Entity, is_action: bool) -> impl Bundle {
+        (
+            Name::new(name.to_string()),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+            ToastButton { toast, is_action },
+        )
+    }
+}
+
+trait UiToastExt {
+    fn toast(
+        &mut self,
+        id: ToastId,
+        config: ToastConfig,
+        corner: ToastCorner,
+        duration: Option<f32>,
+    ) -> UiBuilder<Entity>;
+}
+
+impl UiToastExt for UiBuilder<'_, Entity> {
+    /// A toast notification, spawned in the toast stack by [`Notifications::push`].
+    fn toast(
+        &mut self,
+        id: ToastId,
+        config: ToastConfig,
+        corner: ToastCorner,
+        duration: Option<f32>,
+    ) -> UiBuilder<Entity> {
+        let mut toast = Toast {
+            id,
+            severity: config.severity,
+            corner,
+            action: config.action.clone(),
+            remaining: duration,
+            ..default()
+        };
+        let role = match config.severity {
+            ToastSeverity::Error | ToastSeverity::Warning => Role::Alert,
+            _ => Role::Status,
+        };
+
+        let mut frame = self.container(Toast::frame(id), |container| {
+            let toast_id = container.id();
+
+            container.container(Toast::content(), |content| {
+                toast.title = content
+                    .label(LabelConfig {
+                        label: config.title.clone().unwrap_or_default(),
+                        wrap: FlexWrap::Wrap,
+                        ..default()
+                    })
+                    .style()
+                    .render(config.title.is_some())
+                    .id();
+                toast.message = content
+                    .label(LabelConfig {
+                        label: config.message.clone(),
+                        wrap: FlexWrap::Wrap,
+                        ..default()
+                    })
+                    .id();
+            });
+
+            toast.action_button = container
+                .container(Toast::button("Action", toast_id, true), |button| {
+                    toast.action_label = button
+                        .label(LabelConfig {
+                            label: config.action.clone().unwrap_or_default(),
+                            ..default()
+                        })
+                        .id();
+                })
+                .style()
+                .render(config.action.is_some())
+                .id();
+
+            toast.close_button = container
+                .spawn(Toast::button("Close", toast_id, false))
+                .id();
+        });
+
+        frame.insert((
+            toast,
+            AccessibleWidget::new(role).named(config.message.clone()),
+        ));
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toast_overflow_within_limit_keeps_everything() {
+        assert_eq!(toast_overflow(0, 0, 5), (0, 0));
+        assert_eq!(toast_overflow(2, 3, 5), (0, 0));
+    }
+
+    #[test]
+    fn toast_overflow_dismisses_the_oldest_visible_toasts_first() {
+        assert_eq!(toast_overflow(4, 1, 5), (0, 0));
+        assert_eq!(toast_overflow(5, 1, 5), (1, 0));
+        assert_eq!(toast_overflow(3, 4, 5), (2, 0));
+    }
+
+    #[test]
+    fn toast_overflow_skips_pending_toasts_that_would_not_fit() {
+        assert_eq!(toast_overflow(2, 7, 5), (2, 2));
+        assert_eq!(toast_overflow(0, 8, 5), (0, 3));
+    }
+
+    #[test]
+    fn toast_overflow_shows_at_least_one_toast() {
+        assert_eq!(toast_overflow(1, 2, 0), (1, 1));
+        assert_eq!(toast_overflow(0, 1, 0), (0, 0));
+    }
+}
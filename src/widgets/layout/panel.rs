@@ -42,6 +42,18 @@ impl Panel {
     }
 }
 
+/// Identifies a panel for the docking commands, independently of its title.
+/// The key stays with the panel as it is docked, moved, or floated.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct PanelKey(pub String);
+
+impl PanelKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+}
+
 pub trait UiPanelExt {
     fn panel(
         &mut self,
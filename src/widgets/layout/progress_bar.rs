@@ -0,0 +1,328 @@
+use bevy::{a11y::accesskit::Role, prelude::*};
+
+use sickle_math::ease::Ease;
+use sickle_ui_scaffold::{prelude::*, ui_commands::RefreshThemeExt};
+
+use crate::widgets::WidgetLibraryUpdate;
+
+use super::container::UiContainerExt;
+
+pub struct ProgressBarPlugin;
+
+impl Plugin for ProgressBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, ProgressBarUpdate.before(WidgetLibraryUpdate))
+            .add_plugins(ComponentThemePlugin::<ProgressBar>::default())
+            .add_systems(
+                Update,
+                (
+                    update_progress_bar_mode,
+                    update_progress_bar_fill,
+                    update_progress_bar_accessibility,
+                )
+                    .chain()
+                    .in_set(ProgressBarUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct ProgressBarUpdate;
+
+/// Switches the theme between the determinate and the indeterminate animation
+fn update_progress_bar_mode(
+    mut q_progress_bars: Query<(Entity, &mut ProgressBar), Changed<ProgressBar>>,
+    mut commands: Commands,
+) {
+    for (entity, mut progress_bar) in &mut q_progress_bars {
+        if progress_bar.indeterminate == progress_bar.applied_indeterminate {
+            continue;
+        }
+
+        progress_bar.applied_indeterminate = progress_bar.indeterminate;
+        commands.entity(entity).refresh_theme::<ProgressBar>();
+    }
+}
+
+fn update_progress_bar_fill(
+    q_progress_bars: Query<&ProgressBar, Changed<ProgressBar>>,
+    mut q_fill_style: Query<&mut Style, With<ProgressBarFill>>,
+) {
+    for progress_bar in &q_progress_bars {
+        if progress_bar.indeterminate {
+            continue;
+        }
+
+        let Ok(mut style) = q_fill_style.get_mut(progress_bar.fill) else {
+            continue;
+        };
+
+        let width = Val::Percent(progress_bar.clamped_value() * 100.);
+        if style.width != width {
+            style.width = width;
+        }
+    }
+}
+
+fn update_progress_bar_accessibility(
+    mut q_progress_bars: Query<(&ProgressBar, &mut AccessibleWidget), Changed<ProgressBar>>,
+) {
+    for (progress_bar, mut accessible) in &mut q_progress_bars {
+        let value = match progress_bar.indeterminate {
+            true => None,
+            false => Some(AccessibleValue {
+                value: progress_bar.clamped_value(),
+                min: 0.,
+                max: 1.,
+                step: None,
+            }),
+        };
+
+        if accessible.value != value {
+            accessible.value = value;
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBarFill {
+    pub progress_bar: Entity,
+}
+
+#[derive(Debug, Default)]
+pub struct ProgressBarConfig {
+    /// Progress between 0 and 1
+    pub value: f32,
+    pub indeterminate: bool,
+}
+
+impl ProgressBarConfig {
+    pub fn determinate(value: f32) -> Self {
+        Self {
+            value,
+            indeterminate: false,
+        }
+    }
+
+    pub fn indeterminate() -> Self {
+        Self {
+            value: 0.,
+            indeterminate: true,
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBar {
+    /// Progress between 0 and 1, ignored while indeterminate
+    pub value: f32,
+    /// Shows a looping animation instead of the value, for tasks of unknown length
+    pub indeterminate: bool,
+    applied_indeterminate: bool,
+    fill: Entity,
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self {
+            value: 0.,
+            indeterminate: false,
+            applied_indeterminate: false,
+            fill: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for ProgressBar {
+    fn default_theme() -> Option<Theme<ProgressBar>> {
+        ProgressBar::theme().into()
+    }
+}
+
+impl UiContext for ProgressBar {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            ProgressBar::FILL => Ok(self.fill),
+            _ => Err(format!(
+                "{} doesn't exist for ProgressBar. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [ProgressBar::FILL].into_iter()
+    }
+}
+
+impl ProgressBar {
+    pub const FILL: &'static str = "Fill";
+
+    pub fn fill(&self) -> Entity {
+        self.fill
+    }
+
+    /// The value limited to 0..=1, 0 if it isn't a number
+    pub fn clamped_value(&self) -> f32 {
+        match self.value.is_nan() {
+            true => 0.,
+            false => self.value.clamp(0., 1.),
+        }
+    }
+
+    pub fn theme() -> Theme<ProgressBar> {
+        let base_theme = PseudoTheme::deferred_context(None, ProgressBar::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        progress_bar: &ProgressBar,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .width(Val::Percent(100.))
+            .min_width(Val::Px(theme_spacing.areas.medium))
+            .height(Val::Px(theme_spacing.gaps.small))
+            .align_self(AlignSelf::Center)
+            .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.tiny)))
+            .background_color(colors.container(Container::SurfaceHighest));
+
+        style_builder
+            .switch_target(ProgressBar::FILL)
+            .height(Val::Percent(100.))
+            .border_radius(BorderRadius::all(Val::Px(theme_spacing.corners.tiny)))
+            .background_color(colors.accent(Accent::Primary));
+
+        if !progress_bar.indeterminate {
+            style_builder
+                .switch_target(ProgressBar::FILL)
+                .left(Val::Px(0.));
+            return;
+        }
+
+        // A segment sweeps across the track, leaving on the right before it enters again on the left
+        style_builder
+            .switch_target(ProgressBar::FILL)
+            .width(Val::Percent(30.))
+            .animated()
+            .left(AnimatedVals {
+                idle: Val::Percent(-30.),
+                idle_alt: Val::Percent(100.).into(),
+                ..default()
+            })
+            .idle(1.2, Ease::InOutCubic, 0., 0., AnimationLoop::Continous);
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Progress Bar"),
+            NodeBundle {
+                style: Style {
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::Overflow),
+        )
+    }
+
+    fn fill_bundle(progress_bar: Entity) -> impl Bundle {
+        (
+            Name::new("Fill"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                ..default()
+            },
+            // Drives the looping animation without reacting to the pointer
+            TrackedInteraction::default(),
+            ProgressBarFill { progress_bar },
+            LockedStyleAttributes::lock(LockableStyleAttribute::PositionType),
+        )
+    }
+}
+
+pub trait UiProgressBarExt {
+    /// A horizontal bar showing the progress of a task, or a looping animation while the
+    /// progress is indeterminate. Set its `value` or `indeterminate` to update it.
+    fn progress_bar(&mut self, config: ProgressBarConfig) -> UiBuilder<Entity>;
+}
+
+impl UiProgressBarExt for UiBuilder<'_, Entity> {
+    fn progress_bar(&mut self, config: ProgressBarConfig) -> UiBuilder<Entity> {
+        let mut progress_bar = ProgressBar {
+            value: config.value,
+            indeterminate: config.indeterminate,
+            applied_indeterminate: config.indeterminate,
+            ..default()
+        };
+
+        let mut frame = self.container(ProgressBar::frame(), |container| {
+            let progress_bar_id = container.id();
+            progress_bar.fill = container
+                .spawn(ProgressBar::fill_bundle(progress_bar_id))
+                .id();
+        });
+
+        frame.insert((progress_bar, AccessibleWidget::new(Role::ProgressIndicator)));
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn value(value: f32) -> f32 {
+        ProgressBar { value, ..default() }.clamped_value()
+    }
+
+    #[test]
+    fn clamped_value_stays_between_zero_and_one() {
+        assert_eq!(value(0.25), 0.25);
+        assert_eq!(value(-0.5), 0.);
+        assert_eq!(value(1.5), 1.);
+        assert_eq!(value(f32::INFINITY), 1.);
+        assert_eq!(value(f32::NAN), 0.);
+    }
+
+    #[test]
+    fn fill_width_uses_the_clamped_value() {
+        let mut world = World::new();
+        let fill = world
+            .spawn((
+                Style::default(),
+                ProgressBarFill {
+                    progress_bar: Entity::PLACEHOLDER,
+                },
+            ))
+            .id();
+        let progress_bar = world
+            .spawn(ProgressBar {
+                value: 2.,
+                fill,
+                ..default()
+            })
+            .id();
+
+        world.run_system_once(update_progress_bar_fill);
+        assert_eq!(world.get::<Style>(fill).unwrap().width, Val::Percent(100.));
+
+        world.get_mut::<ProgressBar>(progress_bar).unwrap().value = -1.;
+        world.run_system_once(update_progress_bar_fill);
+        assert_eq!(world.get::<Style>(fill).unwrap().width, Val::Percent(0.));
+    }
+}
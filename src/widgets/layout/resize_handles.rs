@@ -28,14 +28,15 @@ fn should_update_resize_handle_cursor(
 }
 
 fn update_cursor_on_resize_handles(
-    q_flux: Query<(&ResizeHandle, &FluxInteraction)>,
+    q_flux: Query<(Entity, &ResizeHandle, &FluxInteraction)>,
     mut locked: Local<bool>,
     mut commands: Commands,
 ) {
     let mut new_cursor: Option<CursorIcon> = None;
+    let mut cursor_node: Option<Entity> = None;
     let multiple_active = q_flux
         .iter()
-        .filter(|(_, flux)| {
+        .filter(|(_, _, flux)| {
             (**flux == FluxInteraction::PointerEnter && !*locked)
                 || **flux == FluxInteraction::Pressed
         })
@@ -45,7 +46,8 @@ fn update_cursor_on_resize_handles(
     // TODO: use the correct diagonal when the active handles have the same parent
     let omni_cursor = CursorIcon::Move;
 
-    for (handle, flux) in &q_flux {
+    for (entity, handle, flux) in &q_flux {
+        let previous_cursor = new_cursor;
         match *flux {
             FluxInteraction::PointerEnter => {
                 if !*locked {
@@ -81,11 +83,17 @@ fn update_cursor_on_resize_handles(
             }
             _ => (),
         }
-    }
 
-    if let Some(new_cursor) = new_cursor {
-        commands.set_cursor(new_cursor);
+        if new_cursor != previous_cursor {
+            cursor_node = entity.into();
+        }
     }
+
+    let (Some(new_cursor), Some(cursor_node)) = (new_cursor, cursor_node) else {
+        return;
+    };
+
+    commands.entity(cursor_node).set_cursor(new_cursor);
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
@@ -157,6 +165,10 @@ pub struct ResizeHandle {
 }
 
 impl ResizeHandle {
+    pub(crate) fn new(direction: ResizeDirection) -> Self {
+        Self { direction }
+    }
+
     pub fn direction(&self) -> ResizeDirection {
         self.direction
     }
@@ -241,102 +253,123 @@ impl ResizeHandles {
     pub const HANDLE_NORTH_WEST: &'static str = "HandleNorthWest";
 
     pub fn theme() -> Theme<ResizeHandles> {
-        let base_theme = PseudoTheme::deferred_world(None, ResizeHandles::primary_style);
+        let base_theme = PseudoTheme::deferred_world(None, ResizeHandles::primary_style)
+            .depends_on(
+                ThemeDataSections::COLORS
+                    | ThemeDataSections::SPACING
+                    | ThemeDataSections::ANIMATIONS,
+            );
         let theme_north = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::North)],
             ResizeHandles::resizable_north,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
         let theme_north_north_east = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::North),
                 PseudoState::Resizable(CardinalDirection::NorthEast),
             ],
             ResizeHandles::resizable_north_north_east,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_north_north_west = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::North),
                 PseudoState::Resizable(CardinalDirection::NorthWest),
             ],
             ResizeHandles::resizable_north_north_west,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
 
         let theme_north_east = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::NorthEast)],
             ResizeHandles::resizable_north_east,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
 
         let theme_east = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::East)],
             ResizeHandles::resizable_east,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
         let theme_east_north_east = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::East),
                 PseudoState::Resizable(CardinalDirection::NorthEast),
             ],
             ResizeHandles::resizable_east_north_east,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_east_south_east = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::East),
                 PseudoState::Resizable(CardinalDirection::SouthEast),
             ],
             ResizeHandles::resizable_east_south_east,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
 
         let theme_south_east = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::SouthEast)],
             ResizeHandles::resizable_south_east,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
 
         let theme_south = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::South)],
             ResizeHandles::resizable_south,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
         let theme_south_south_east = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::South),
                 PseudoState::Resizable(CardinalDirection::SouthEast),
             ],
             ResizeHandles::resizable_south_south_east,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_south_south_west = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::South),
                 PseudoState::Resizable(CardinalDirection::SouthWest),
             ],
             ResizeHandles::resizable_south_south_west,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
 
         let theme_south_west = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::SouthWest)],
             ResizeHandles::resizable_south_west,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
 
         let theme_west = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::West)],
             ResizeHandles::resizable_west,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
         let theme_west_south_west = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::West),
                 PseudoState::Resizable(CardinalDirection::SouthWest),
             ],
             ResizeHandles::resizable_west_south_west,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_west_north_west = PseudoTheme::deferred(
             vec![
                 PseudoState::Resizable(CardinalDirection::West),
                 PseudoState::Resizable(CardinalDirection::NorthWest),
             ],
             ResizeHandles::resizable_west_north_west,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
 
         let theme_north_west = PseudoTheme::deferred(
             vec![PseudoState::Resizable(CardinalDirection::NorthWest)],
             ResizeHandles::resizable_north_west,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
 
         Theme::new(vec![
             base_theme,
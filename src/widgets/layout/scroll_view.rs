@@ -1,11 +1,16 @@
 use bevy::{
+    a11y::Focus,
+    ecs::system::{EntityCommand, EntityCommands},
     input::mouse::MouseScrollUnit,
     prelude::*,
     ui::{FocusPolicy, RelativeCursorPosition},
 };
 
+use sickle_math::ease::{Ease, ValueEasing};
 use sickle_ui_scaffold::prelude::*;
 
+use crate::widgets::menus::menu_scroll::MenuScroll;
+
 use super::container::UiContainerExt;
 
 // TODO: implement click-to-scroll
@@ -13,7 +18,8 @@ pub struct ScrollViewPlugin;
 
 impl Plugin for ScrollViewPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ComponentThemePlugin::<ScrollView>::default())
+        app.init_resource::<ScrollViewConfig>()
+            .add_plugins(ComponentThemePlugin::<ScrollView>::default())
             .add_systems(
                 Update,
                 (
@@ -21,6 +27,11 @@ impl Plugin for ScrollViewPlugin {
                     update_scroll_view_on_content_change,
                     update_scroll_view_on_scroll.after(ScrollableUpdate),
                     update_scroll_view_on_drag.after(DraggableUpdate),
+                    update_scroll_view_on_touch,
+                    update_scroll_view_on_key_press,
+                    update_scroll_view_on_focus_change,
+                    update_scroll_view_on_scroll_into_view,
+                    update_scroll_view_motion,
                     update_scroll_view_offset.in_set(ScrollViewOffsetUpdate),
                     update_scroll_view_layout.in_set(ScrollViewLayoutUpdate),
                 )
@@ -57,8 +68,7 @@ fn update_scroll_view_on_content_change(
             continue;
         };
 
-        // Touch for change
-        container.scroll_offset = container.scroll_offset;
+        container.set_changed();
     }
 }
 
@@ -67,8 +77,11 @@ fn update_scroll_view_on_scroll(
         (AnyOf<(&ScrollViewViewport, &ScrollBarHandle)>, &Scrollable),
         Changed<Scrollable>,
     >,
+    q_parent: Query<&Parent>,
+    r_config: Res<ScrollViewConfig>,
     mut q_scroll_view: Query<&mut ScrollView>,
 ) {
+    let mut targets: Vec<(Entity, Vec2)> = Vec::new();
     for ((viewport, handle), scrollable) in &q_scrollables {
         let Some((axis, diff, unit)) = scrollable.last_change() else {
             continue;
@@ -82,11 +95,10 @@ fn update_scroll_view_on_scroll(
             continue;
         };
 
-        let Ok(mut scroll_view) = q_scroll_view.get_mut(scroll_container_id) else {
-            continue;
-        };
-
-        if scroll_view.disabled {
+        if targets
+            .iter()
+            .any(|(target, _)| *target == scroll_container_id)
+        {
             continue;
         }
 
@@ -95,10 +107,40 @@ fn update_scroll_view_on_scroll(
             ScrollAxis::Vertical => Vec2 { x: 0., y: diff },
         };
         let diff = match unit {
-            MouseScrollUnit::Line => offset * 20.,
+            MouseScrollUnit::Line => offset * r_config.line_height,
             MouseScrollUnit::Pixel => offset,
         };
-        scroll_view.scroll_offset = scroll_view.scroll_offset + diff;
+        targets.push((scroll_container_id, diff));
+    }
+
+    // Nested viewports are all hovered, only the innermost receives the scroll,
+    // its ancestors get what it doesn't consume.
+    let outer: Vec<Entity> = targets
+        .iter()
+        .flat_map(|(target, _)| q_parent.iter_ancestors(*target))
+        .filter(|ancestor| q_scroll_view.contains(*ancestor))
+        .collect();
+
+    for (target, diff) in targets {
+        if outer.contains(&target) {
+            continue;
+        }
+
+        let mut remainder = diff;
+        let mut current = Some(target);
+        while let Some(scroll_container_id) = current {
+            if let Ok(mut scroll_view) = q_scroll_view.get_mut(scroll_container_id) {
+                remainder = scroll_view.consume_scroll(remainder);
+            }
+
+            if remainder == Vec2::ZERO {
+                break;
+            }
+
+            current = q_parent
+                .iter_ancestors(scroll_container_id)
+                .find(|ancestor| q_scroll_view.contains(*ancestor));
+        }
     }
 }
 
@@ -161,6 +203,7 @@ fn update_scroll_view_on_drag(
             ScrollAxis::Vertical => diff.y,
         } * ratio;
 
+        scroll_view.stop();
         scroll_view.scroll_offset += match bar_handle.axis {
             ScrollAxis::Horizontal => Vec2 { x: diff, y: 0. },
             ScrollAxis::Vertical => Vec2 { x: 0., y: diff },
@@ -168,6 +211,339 @@ fn update_scroll_view_on_drag(
     }
 }
 
+fn update_scroll_view_on_touch(
+    r_touches: Res<Touches>,
+    r_time: Res<Time<Real>>,
+    r_config: Res<ScrollViewConfig>,
+    q_viewports: Query<(Entity, &ScrollViewViewport, &Node, &GlobalTransform)>,
+    q_parent: Query<&Parent>,
+    mut q_scroll_view: Query<&mut ScrollView>,
+) {
+    for touch in r_touches.iter_just_pressed() {
+        let position = touch.position();
+        let Some((_, viewport, _, _)) = q_viewports
+            .iter()
+            .filter(|(_, _, node, transform)| node.logical_rect(transform).contains(position))
+            .max_by_key(|(entity, _, _, _)| q_parent.iter_ancestors(*entity).count())
+        else {
+            continue;
+        };
+
+        let Ok(mut scroll_view) = q_scroll_view.get_mut(viewport.scroll_view) else {
+            continue;
+        };
+
+        if scroll_view.disabled || scroll_view.pan.is_some() {
+            continue;
+        }
+
+        // Catch the content if it is still moving
+        scroll_view.stop();
+        scroll_view.pan = ScrollPan {
+            touch: touch.id(),
+            origin: position,
+            last: position,
+            panning: false,
+        }
+        .into();
+    }
+
+    let delta_seconds = r_time.delta_seconds();
+    for mut scroll_view in &mut q_scroll_view {
+        let Some(pan) = scroll_view.pan else {
+            continue;
+        };
+
+        let Some(touch) = r_touches.get_pressed(pan.touch) else {
+            scroll_view.pan = None;
+            if !pan.panning || scroll_view.velocity.length() < r_config.min_momentum {
+                scroll_view.velocity = Vec2::ZERO;
+            }
+            if scroll_view.velocity == Vec2::ZERO && scroll_view.overscroll != Vec2::ZERO {
+                scroll_view.start_bounce(&r_config);
+            }
+            continue;
+        };
+
+        if scroll_view.disabled {
+            scroll_view.pan = None;
+            continue;
+        }
+
+        let position = touch.position();
+        let panning = pan.panning || position.distance(pan.origin) > r_config.pan_threshold;
+        if !panning {
+            continue;
+        }
+
+        let delta = pan.last - position;
+        scroll_view.pan_by(delta, r_config.max_overscroll);
+        if delta_seconds > 0. {
+            scroll_view.velocity = scroll_view.velocity.lerp(delta / delta_seconds, 0.8);
+        }
+        scroll_view.pan = ScrollPan {
+            last: position,
+            panning,
+            ..pan
+        }
+        .into();
+    }
+}
+
+fn update_scroll_view_on_key_press(
+    r_keys: Res<ButtonInput<KeyCode>>,
+    r_config: Res<ScrollViewConfig>,
+    r_focus: Option<Res<Focus>>,
+    q_viewports: Query<(Entity, &ScrollViewViewport, &Interaction)>,
+    q_parent: Query<&Parent>,
+    mut q_scroll_view: Query<(&mut ScrollView, &Node)>,
+) {
+    if !r_keys.any_just_pressed([
+        KeyCode::PageUp,
+        KeyCode::PageDown,
+        KeyCode::Home,
+        KeyCode::End,
+    ]) {
+        return;
+    }
+
+    let focused = r_focus.and_then(|focus| focus.0).and_then(|focused| {
+        std::iter::once(focused)
+            .chain(q_parent.iter_ancestors(focused))
+            .find(|entity| q_scroll_view.contains(*entity))
+    });
+    let hovered = || {
+        q_viewports
+            .iter()
+            .filter(|(_, _, interaction)| **interaction == Interaction::Hovered)
+            .max_by_key(|(entity, _, _)| q_parent.iter_ancestors(*entity).count())
+            .map(|(_, viewport, _)| viewport.scroll_view)
+    };
+
+    let Some(scroll_view_id) = focused.or_else(hovered) else {
+        return;
+    };
+
+    let Ok((mut scroll_view, node)) = q_scroll_view.get_mut(scroll_view_id) else {
+        return;
+    };
+
+    if scroll_view.disabled {
+        return;
+    }
+
+    let axis = match scroll_view.restricted_to {
+        Some(ScrollAxis::Horizontal) => Vec2::X,
+        _ => Vec2::Y,
+    };
+    let page = node.size() * axis * r_config.page_ratio;
+    let target = scroll_view.scroll_target();
+
+    if r_keys.just_pressed(KeyCode::PageUp) {
+        scroll_view.scroll_by(-page);
+    } else if r_keys.just_pressed(KeyCode::PageDown) {
+        scroll_view.scroll_by(page);
+    } else if r_keys.just_pressed(KeyCode::Home) {
+        scroll_view.scroll_to(target * (Vec2::ONE - axis));
+    } else if r_keys.just_pressed(KeyCode::End) {
+        let overflow = scroll_view.overflow.max(Vec2::ZERO);
+        scroll_view.scroll_to(target * (Vec2::ONE - axis) + overflow * axis);
+    }
+}
+
+fn update_scroll_view_on_focus_change(
+    r_focus: Option<Res<Focus>>,
+    q_content: Query<&ScrollViewContent>,
+    q_parent: Query<&Parent>,
+    mut q_scroll_view: Query<&mut ScrollView>,
+) {
+    let Some(focus) = r_focus else {
+        return;
+    };
+
+    if !focus.is_changed() {
+        return;
+    }
+
+    let Some(focused) = focus.0 else {
+        return;
+    };
+
+    let Some(content) = q_parent
+        .iter_ancestors(focused)
+        .find_map(|ancestor| q_content.get(ancestor).ok())
+    else {
+        return;
+    };
+
+    let Ok(mut scroll_view) = q_scroll_view.get_mut(content.scroll_view) else {
+        return;
+    };
+
+    scroll_view.scroll_into_view(focused, ScrollAlignment::Nearest);
+}
+
+fn update_scroll_view_on_scroll_into_view(
+    r_config: Res<ScrollViewConfig>,
+    r_time: Res<Time<Real>>,
+    mut q_scroll_views: Query<&mut ScrollView>,
+    q_node: Query<(&Node, &GlobalTransform)>,
+) {
+    for mut scroll_view in &mut q_scroll_views {
+        let Some(request) = scroll_view.scroll_into_view else {
+            continue;
+        };
+        let ScrollIntoViewRequest {
+            child, alignment, ..
+        } = request;
+
+        // Wait for the view to become scrollable
+        if scroll_view.disabled {
+            continue;
+        }
+
+        let Ok((child_node, child_transform)) = q_node.get(child) else {
+            scroll_view.scroll_into_view = None;
+            continue;
+        };
+
+        // Wait for the child to be laid out, but not for hidden or empty children
+        if child_node.size() == Vec2::ZERO {
+            let waited = request.waited + r_time.delta_seconds();
+            scroll_view.scroll_into_view = if waited < r_config.scroll_into_view_timeout {
+                ScrollIntoViewRequest { waited, ..request }.into()
+            } else {
+                debug!(
+                    "Dropped scroll into view request: {} has no size after {}s",
+                    child, waited
+                );
+                None
+            };
+            continue;
+        }
+
+        let Ok((content_node, content_transform)) = q_node.get(scroll_view.content_container)
+        else {
+            continue;
+        };
+        let Ok((viewport_node, _)) = q_node.get(scroll_view.viewport) else {
+            continue;
+        };
+
+        let content_min = content_transform.translation().truncate() - content_node.size() / 2.;
+        let child_size = child_node.size();
+        let child_min = child_transform.translation().truncate() - child_size / 2. - content_min;
+        let view_size = viewport_node.size();
+        let current = scroll_view.scroll_target();
+
+        let align = |offset: f32, min: f32, size: f32, view: f32| -> f32 {
+            match alignment {
+                ScrollAlignment::Start => min,
+                ScrollAlignment::Center => min + (size - view) / 2.,
+                ScrollAlignment::End => min + size - view,
+                ScrollAlignment::Nearest => {
+                    if min < offset || size > view {
+                        min
+                    } else if min + size > offset + view {
+                        min + size - view
+                    } else {
+                        offset
+                    }
+                }
+            }
+        };
+
+        let target = Vec2 {
+            x: align(current.x, child_min.x, child_size.x, view_size.x),
+            y: align(current.y, child_min.y, child_size.y, view_size.y),
+        };
+
+        scroll_view.scroll_into_view = None;
+        if target != current {
+            scroll_view.scroll_to(target);
+        }
+    }
+}
+
+fn update_scroll_view_motion(
+    r_time: Res<Time<Real>>,
+    r_config: Res<ScrollViewConfig>,
+    mut q_scroll_views: Query<&mut ScrollView>,
+) {
+    let delta_seconds = r_time.delta_seconds();
+    for mut scroll_view in &mut q_scroll_views {
+        if scroll_view.animation.is_none()
+            && scroll_view.bounce.is_none()
+            && scroll_view.scroll_target.is_none()
+            && scroll_view.velocity == Vec2::ZERO
+        {
+            continue;
+        }
+
+        if scroll_view.disabled {
+            scroll_view.stop();
+            scroll_view.overscroll = Vec2::ZERO;
+            continue;
+        }
+
+        if scroll_view.pan.is_some() {
+            continue;
+        }
+
+        if let Some(target) = scroll_view.scroll_target.take() {
+            scroll_view.animation = ScrollAnimation {
+                from: scroll_view.scroll_offset,
+                to: target,
+                elapsed: 0.,
+                duration: r_config.scroll_duration,
+                ease: r_config.scroll_ease,
+                last: scroll_view.scroll_offset,
+            }
+            .into();
+        }
+
+        if let Some(mut animation) = scroll_view.animation {
+            // The offset was set directly, let it win
+            if scroll_view.scroll_offset != animation.last {
+                scroll_view.animation = None;
+            } else {
+                let offset = animation.advance(delta_seconds);
+                scroll_view.scroll_offset = offset;
+                scroll_view.animation = match animation.finished() {
+                    true => None,
+                    false => animation.into(),
+                };
+            }
+        } else if scroll_view.velocity != Vec2::ZERO {
+            let velocity = scroll_view.velocity;
+            scroll_view.pan_by(velocity * delta_seconds, r_config.max_overscroll);
+
+            // Content pulled past its edges slows down faster
+            let decay = Vec2::select(
+                scroll_view.overscroll.cmpeq(Vec2::ZERO),
+                Vec2::splat(r_config.momentum_decay),
+                Vec2::splat(r_config.momentum_decay * 8.),
+            );
+            scroll_view.velocity = velocity * (-decay * delta_seconds).exp();
+
+            if scroll_view.velocity.length() < r_config.min_momentum {
+                scroll_view.velocity = Vec2::ZERO;
+                if scroll_view.overscroll != Vec2::ZERO {
+                    scroll_view.start_bounce(&r_config);
+                }
+            }
+        }
+
+        if let Some(mut bounce) = scroll_view.bounce {
+            scroll_view.overscroll = bounce.advance(delta_seconds);
+            scroll_view.bounce = match bounce.finished() {
+                true => None,
+                false => bounce.into(),
+            };
+        }
+    }
+}
+
 fn update_scroll_view_offset(
     mut q_scroll_views: Query<(&Node, &mut ScrollView), Or<(Changed<ScrollView>, Changed<Node>)>>,
     q_node: Query<&Node>,
@@ -244,7 +620,7 @@ fn update_scroll_view_layout(
         if overflow_x > 0. {
             commands
                 .style_unchecked(scroll_view.content_container)
-                .left(Val::Px(-scroll_offset_x));
+                .left(Val::Px(-scroll_offset_x - scroll_view.overscroll.x));
             commands
                 .entity(entity)
                 .add_pseudo_state(PseudoState::OverflowX);
@@ -273,7 +649,7 @@ fn update_scroll_view_layout(
         if overflow_y > 0. {
             commands
                 .style_unchecked(scroll_view.content_container)
-                .top(Val::Px(-scroll_offset_y));
+                .top(Val::Px(-scroll_offset_y - scroll_view.overscroll.y));
             commands
                 .entity(entity)
                 .add_pseudo_state(PseudoState::OverflowY);
@@ -388,12 +764,21 @@ pub struct ScrollView {
     ///
     /// If updated before `ScrollViewOffsetUpdate` the change will be
     /// made in the same frame. It is clamped to the scroll overflow,
-    /// so it is safe to set it to any value.
+    /// so it is safe to set it to any value. Use `scroll_to` for eased scrolling.
     pub scroll_offset: Vec2,
     overflow: Vec2,
     visible_ratio: Vec2,
     restricted_to: Option<ScrollAxis>,
     pub disabled: bool,
+    scroll_target: Option<Vec2>,
+    animation: Option<ScrollAnimation>,
+    bounce: Option<ScrollAnimation>,
+    velocity: Vec2,
+    overscroll: Vec2,
+    pan: Option<ScrollPan>,
+    scroll_into_view: Option<ScrollIntoViewRequest>,
+    /// What happens to wheel scroll this view cannot use
+    pub chaining: ScrollChaining,
 }
 
 impl Default for ScrollView {
@@ -410,6 +795,14 @@ impl Default for ScrollView {
             visible_ratio: Vec2::ZERO,
             disabled: false,
             restricted_to: None,
+            scroll_target: None,
+            animation: None,
+            bounce: None,
+            velocity: Vec2::ZERO,
+            overscroll: Vec2::ZERO,
+            pan: None,
+            scroll_into_view: None,
+            chaining: Default::default(),
         }
     }
 }
@@ -479,18 +872,133 @@ impl ScrollView {
         self.visible_ratio
     }
 
+    /// Eased scroll to the offset, clamped to the overflow
+    pub fn scroll_to(&mut self, offset: Vec2) {
+        self.velocity = Vec2::ZERO;
+        self.scroll_target = offset
+            .clamp(Vec2::ZERO, self.overflow.max(Vec2::ZERO))
+            .into();
+    }
+
+    /// Eased scroll by the difference, relative to where the view is already scrolling to
+    pub fn scroll_by(&mut self, diff: Vec2) {
+        self.scroll_to(self.scroll_target() + diff);
+    }
+
+    /// The offset the view is scrolling to, or the current offset if it is not scrolling
+    pub fn scroll_target(&self) -> Vec2 {
+        self.scroll_target
+            .or(self.animation.map(|animation| animation.to))
+            .unwrap_or(self.scroll_offset)
+    }
+
+    /// Scrolls the view until the child is visible, once both are laid out.
+    /// The request is dropped if the child still has no size after
+    /// `ScrollViewConfig::scroll_into_view_timeout`.
+    pub fn scroll_into_view(&mut self, child: Entity, alignment: ScrollAlignment) {
+        self.scroll_into_view = ScrollIntoViewRequest {
+            child,
+            alignment,
+            waited: 0.,
+        }
+        .into();
+    }
+
+    /// Scrolls by as much of the difference as the view can take, returning what is
+    /// left over for its ancestors according to the chaining policy
+    fn consume_scroll(&mut self, diff: Vec2) -> Vec2 {
+        if self.disabled {
+            return diff;
+        }
+
+        let mut scrollable = Vec2::ONE;
+        match self.restricted_to {
+            Some(ScrollAxis::Horizontal) => scrollable.y = 0.,
+            Some(ScrollAxis::Vertical) => scrollable.x = 0.,
+            None => (),
+        }
+
+        let from = self.scroll_target();
+        let to = (from + diff * scrollable).clamp(Vec2::ZERO, self.overflow.max(Vec2::ZERO));
+        let moved = to - from;
+        if moved != Vec2::ZERO {
+            self.scroll_to(to);
+        }
+
+        let remainder = diff - moved;
+        match self.chaining {
+            ScrollChaining::Contain => remainder * (Vec2::ONE - scrollable),
+            ScrollChaining::Chain => remainder,
+            ScrollChaining::Auto => Vec2::select(moved.cmpeq(Vec2::ZERO), remainder, Vec2::ZERO),
+        }
+    }
+
+    fn stop(&mut self) {
+        self.scroll_target = None;
+        self.animation = None;
+        self.bounce = None;
+        self.velocity = Vec2::ZERO;
+        self.overscroll = Vec2::ZERO;
+    }
+
+    fn start_bounce(&mut self, config: &ScrollViewConfig) {
+        self.bounce = ScrollAnimation {
+            from: self.overscroll,
+            to: Vec2::ZERO,
+            elapsed: 0.,
+            duration: config.bounce_duration,
+            ease: config.bounce_ease,
+            last: self.overscroll,
+        }
+        .into();
+    }
+
+    /// Moves the content directly, pulling it past its edges with increasing resistance
+    fn pan_by(&mut self, delta: Vec2, max_overscroll: f32) {
+        let overflow = self.overflow.max(Vec2::ZERO);
+        let mut delta = delta;
+        for axis in 0..2 {
+            let restricted = match self.restricted_to {
+                Some(ScrollAxis::Horizontal) => axis == 1,
+                Some(ScrollAxis::Vertical) => axis == 0,
+                None => false,
+            };
+
+            if restricted || overflow[axis] == 0. {
+                delta[axis] = 0.;
+                continue;
+            }
+
+            let excess = self.overscroll[axis];
+            if excess != 0. && excess.signum() == delta[axis].signum() {
+                delta[axis] *= (1. - excess.abs() / max_overscroll.max(1.)).max(0.);
+            }
+        }
+
+        let position = self.scroll_offset + self.overscroll + delta;
+        self.scroll_offset = position.clamp(Vec2::ZERO, overflow);
+        self.overscroll = (position - self.scroll_offset)
+            .clamp(Vec2::splat(-max_overscroll), Vec2::splat(max_overscroll));
+    }
+
     pub fn theme() -> Theme<ScrollView> {
-        let base_theme = PseudoTheme::deferred_context(None, ScrollView::primary_style);
+        let base_theme = PseudoTheme::deferred_context(None, ScrollView::primary_style).depends_on(
+            ThemeDataSections::COLORS | ThemeDataSections::SPACING | ThemeDataSections::ANIMATIONS,
+        );
         let disabled_theme =
-            PseudoTheme::deferred(vec![PseudoState::Disabled], ScrollView::disabled_style);
+            PseudoTheme::deferred(vec![PseudoState::Disabled], ScrollView::disabled_style)
+                .depends_on(ThemeDataSections::NONE);
         let overflow_x_theme =
-            PseudoTheme::deferred(vec![PseudoState::OverflowX], ScrollView::overflow_x_style);
+            PseudoTheme::deferred(vec![PseudoState::OverflowX], ScrollView::overflow_x_style)
+                .depends_on(ThemeDataSections::SPACING);
         let overflow_y_theme =
-            PseudoTheme::deferred(vec![PseudoState::OverflowY], ScrollView::overflow_y_style);
+            PseudoTheme::deferred(vec![PseudoState::OverflowY], ScrollView::overflow_y_style)
+                .depends_on(ThemeDataSections::SPACING);
         let overflow_xy_theme = PseudoTheme::deferred(
             vec![PseudoState::OverflowX, PseudoState::OverflowY],
             ScrollView::overflow_xy_style,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
 
         Theme::new(vec![
             base_theme,
@@ -772,6 +1280,179 @@ impl ScrollView {
     }
 }
 
+/// Where a child should end up in the viewport when it is scrolled into view
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum ScrollAlignment {
+    Start,
+    Center,
+    End,
+    /// Scroll as little as possible to make the child visible
+    #[default]
+    Nearest,
+}
+
+/// What a nested scroll view does with wheel scroll it cannot use
+///
+/// Axes excluded by `restricted_to` always pass scroll on to the nearest scrollable ancestor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum ScrollChaining {
+    /// Never scroll ancestors on the axes this view scrolls on
+    Contain,
+    /// Pass whatever is left after reaching an edge on to the ancestor
+    Chain,
+    /// Scroll ancestors only once this view is already at the edge when the scroll arrives
+    #[default]
+    Auto,
+}
+
+/// Controls how scroll views react to input
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ScrollViewConfig {
+    /// Pixels scrolled per line of mouse wheel scroll
+    pub line_height: f32,
+    /// Duration of eased scrolls in seconds, zero scrolls immediately
+    pub scroll_duration: f32,
+    pub scroll_ease: Ease,
+    /// Ratio of the viewport scrolled by page up and page down
+    pub page_ratio: f32,
+    /// Distance in pixels a touch must move before it pans the content
+    pub pan_threshold: f32,
+    /// Exponential decay rate of the momentum after a pan, per second
+    pub momentum_decay: f32,
+    /// Speed in pixels per second under which the momentum stops
+    pub min_momentum: f32,
+    /// Maximum distance in pixels the content can be pulled past its edges
+    pub max_overscroll: f32,
+    /// Duration in seconds of the content springing back after an overscroll
+    pub bounce_duration: f32,
+    pub bounce_ease: Ease,
+    /// Seconds a scroll into view request waits for its child to be laid out
+    pub scroll_into_view_timeout: f32,
+}
+
+impl Default for ScrollViewConfig {
+    fn default() -> Self {
+        Self {
+            line_height: 20.,
+            scroll_duration: 0.15,
+            scroll_ease: Ease::OutCubic,
+            page_ratio: 0.9,
+            pan_threshold: 8.,
+            momentum_decay: 4.,
+            min_momentum: 20.,
+            max_overscroll: 80.,
+            bounce_duration: 0.3,
+            bounce_ease: Ease::OutCubic,
+            scroll_into_view_timeout: 1.,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+struct ScrollAnimation {
+    from: Vec2,
+    to: Vec2,
+    elapsed: f32,
+    duration: f32,
+    ease: Ease,
+    last: Vec2,
+}
+
+impl ScrollAnimation {
+    fn advance(&mut self, delta_seconds: f32) -> Vec2 {
+        self.elapsed += delta_seconds;
+        self.last = match self.finished() {
+            true => self.to,
+            false => self
+                .from
+                .lerp(self.to, (self.elapsed / self.duration).ease(self.ease)),
+        };
+
+        self.last
+    }
+
+    fn finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+struct ScrollIntoViewRequest {
+    child: Entity,
+    alignment: ScrollAlignment,
+    /// Seconds spent waiting for the child to be laid out
+    waited: f32,
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+struct ScrollPan {
+    touch: u64,
+    origin: Vec2,
+    last: Vec2,
+    panning: bool,
+}
+
+struct ScrollIntoView {
+    child: Entity,
+    alignment: ScrollAlignment,
+}
+
+impl EntityCommand for ScrollIntoView {
+    fn apply(self, entity: Entity, world: &mut World) {
+        if let Some(mut scroll_view) = world.get_mut::<ScrollView>(entity) {
+            scroll_view.scroll_into_view(self.child, self.alignment);
+        } else if let Some(mut menu_scroll) = world.get_mut::<MenuScroll>(entity) {
+            menu_scroll.scroll_into_view(self.child, self.alignment);
+        } else {
+            warn!(
+                "Failed to scroll {} into view: {} is not a ScrollView or a menu panel",
+                self.child, entity
+            );
+        }
+    }
+}
+
+pub trait ScrollIntoViewExt {
+    fn scroll_into_view(&mut self, child: Entity, alignment: ScrollAlignment) -> &mut Self;
+}
+
+impl ScrollIntoViewExt for EntityCommands<'_> {
+    /// Scrolls the `ScrollView` until the child is visible, using eased scrolling.
+    /// On menu panels, the panel scrolls by whole items until the item is visible.
+    fn scroll_into_view(&mut self, child: Entity, alignment: ScrollAlignment) -> &mut Self {
+        self.add(ScrollIntoView { child, alignment });
+        self
+    }
+}
+
+struct SetScrollChaining(ScrollChaining);
+
+impl EntityCommand for SetScrollChaining {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let Some(mut scroll_view) = world.get_mut::<ScrollView>(entity) else {
+            warn!(
+                "Failed to set scroll chaining on {}: Not a ScrollView",
+                entity
+            );
+            return;
+        };
+
+        scroll_view.chaining = self.0;
+    }
+}
+
+pub trait ScrollChainingExt {
+    fn scroll_chaining(&mut self, chaining: ScrollChaining) -> &mut Self;
+}
+
+impl ScrollChainingExt for EntityCommands<'_> {
+    /// Sets what the `ScrollView` does with wheel scroll it cannot use
+    fn scroll_chaining(&mut self, chaining: ScrollChaining) -> &mut Self {
+        self.add(SetScrollChaining(chaining));
+        self
+    }
+}
+
 pub trait UiScrollViewExt {
     fn scroll_view(
         &mut self,
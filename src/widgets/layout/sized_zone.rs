@@ -1,5 +1,6 @@
 use bevy::{prelude::*, ui::UiSystem};
 
+use sickle_math::ease::{Ease, ValueEasing};
 use sickle_ui_scaffold::{prelude::*, ui_commands::LogHierarchyExt};
 
 use super::{
@@ -9,6 +10,8 @@ use super::{
 };
 
 const MIN_SIZED_ZONE_SIZE: f32 = 50.;
+const COLLAPSE_DURATION: f32 = 0.2;
+const COLLAPSE_DOUBLE_CLICK_TIME: f32 = 0.4;
 
 pub struct SizedZonePlugin;
 
@@ -30,7 +33,12 @@ impl Plugin for SizedZonePlugin {
         )
         .add_systems(
             Update,
-            (update_sized_zone_on_resize, update_sized_zone_style)
+            (
+                update_sized_zone_on_resize,
+                update_sized_zone_on_handle_double_click,
+                update_sized_zone_collapse,
+                update_sized_zone_style,
+            )
                 .after(DockingZoneUpdate)
                 .chain(),
         )
@@ -129,7 +137,10 @@ fn preset_sized_zone_children_size(
 
     for entity in &q_sized_zones {
         let zone = q_sized_zone.get(entity).unwrap();
-        let zone_size = zone.min_size;
+        let zone_size = match zone.mode {
+            SizedZoneMode::Fixed => zone.fixed_size.max(zone.min_size),
+            SizedZoneMode::Proportional => zone.min_size,
+        };
         let direction = zone.flex_direction;
 
         for parent in q_parents.iter_ancestors(entity) {
@@ -372,6 +383,14 @@ fn update_sized_zone_on_resize(
             continue;
         }
 
+        if current_zone.collapsed
+            || neighbour_zone.collapsed
+            || current_zone.transition.is_some()
+            || neighbour_zone.transition.is_some()
+        {
+            continue;
+        }
+
         let size_diff = match current_zone.flex_direction {
             FlexDirection::Row => handle.direction().to_size_diff(diff).y,
             FlexDirection::Column => handle.direction().to_size_diff(diff).x,
@@ -389,51 +408,143 @@ fn update_sized_zone_on_resize(
             continue;
         };
 
-        let total_size = match current_zone.flex_direction {
-            FlexDirection::Row => node.size().y,
-            FlexDirection::Column => node.size().x,
-            _ => 0.,
-        };
+        let total_size = current_zone.axis_size(node.size());
         if total_size == 0. {
             continue;
         }
 
-        let current_min_size = current_zone.children_size;
-        let current_size = (current_zone.size_percent / 100.) * total_size;
-        let mut current_new_size = current_size;
-        let neighbour_min_size = neighbour_zone.children_size;
-        let neighbour_size = (neighbour_zone.size_percent / 100.) * total_size;
-        let mut neighbour_new_size = neighbour_size;
+        let current_size = current_zone.pixel_size(total_size);
+        let neighbour_size = neighbour_zone.pixel_size(total_size);
 
-        if size_diff < 0. {
-            if current_size + size_diff >= current_min_size {
-                current_new_size += size_diff;
-                neighbour_new_size -= size_diff;
-            } else {
-                current_new_size = current_min_size;
-                neighbour_new_size += current_size - current_min_size;
-            }
-        } else if size_diff > 0. {
-            if neighbour_size - size_diff >= neighbour_min_size {
-                neighbour_new_size -= size_diff;
-                current_new_size += size_diff;
-            } else {
-                neighbour_new_size = neighbour_min_size;
-                current_new_size += neighbour_size - neighbour_min_size;
-            }
+        // The current zone grows by as much as the neighbour shrinks, within both of their limits
+        let lower_limit = (current_zone.children_size - current_size)
+            .max(neighbour_size - neighbour_zone.max_pixel_size());
+        let upper_limit = (current_zone.max_pixel_size() - current_size)
+            .min(neighbour_size - neighbour_zone.children_size);
+        if lower_limit > upper_limit {
+            continue;
+        }
+
+        let size_diff = size_diff.clamp(lower_limit, upper_limit);
+        if size_diff == 0. {
+            continue;
         }
 
         q_sized_zone
             .get_mut(current_zone_id)
             .unwrap()
             .0
-            .size_percent = (current_new_size / total_size) * 100.;
+            .set_pixel_size(current_size + size_diff, total_size);
 
         q_sized_zone
             .get_mut(neighbour_zone_id)
             .unwrap()
             .0
-            .size_percent = (neighbour_new_size / total_size) * 100.;
+            .set_pixel_size(neighbour_size - size_diff, total_size);
+    }
+}
+
+fn update_sized_zone_on_handle_double_click(
+    q_handles: Query<
+        (Entity, &SizedZoneResizeHandle, &FluxInteraction, &Draggable),
+        Changed<FluxInteraction>,
+    >,
+    mut q_sized_zone: Query<&mut SizedZone>,
+    r_time: Res<Time<Real>>,
+    mut last_click: Local<Option<(Entity, f32)>>,
+) {
+    for (entity, handle_ref, interaction, draggable) in &q_handles {
+        // Releasing after a drag isn't a click
+        if *interaction != FluxInteraction::Released
+            || !(draggable.state == DragState::Inactive
+                || draggable.state == DragState::MaybeDragged)
+        {
+            continue;
+        }
+
+        let now = r_time.elapsed_seconds();
+        let double_click = last_click.is_some_and(|(last_entity, last_time)| {
+            last_entity == entity && now - last_time <= COLLAPSE_DOUBLE_CLICK_TIME
+        });
+        if !double_click {
+            *last_click = Some((entity, now));
+            continue;
+        }
+
+        *last_click = None;
+
+        // The handle of a collapsed zone overlaps its neighbour's, so either can expand it
+        let collapsed_neighbour = handle_ref.neighbour.filter(|neighbour| {
+            q_sized_zone
+                .get(*neighbour)
+                .is_ok_and(|zone| zone.collapsible && zone.collapsed)
+        });
+        if let Some(neighbour) = collapsed_neighbour {
+            q_sized_zone.get_mut(neighbour).unwrap().expand();
+            continue;
+        }
+
+        let Ok(mut zone) = q_sized_zone.get_mut(handle_ref.sized_zone) else {
+            continue;
+        };
+
+        if zone.collapsible {
+            zone.toggle_collapsed();
+        }
+    }
+}
+
+fn update_sized_zone_collapse(
+    r_time: Res<Time<Real>>,
+    mut q_sized_zones: Query<(&mut SizedZone, &Node, &Parent)>,
+    q_node: Query<&Node>,
+) {
+    for (mut zone, node, parent) in &mut q_sized_zones {
+        if let Some(collapse) = zone.collapse_request {
+            zone.collapse_request = None;
+
+            let current_size = zone
+                .transition
+                .map(|transition| transition.value())
+                .unwrap_or(zone.axis_size(node.size()));
+
+            if collapse && !zone.collapsed {
+                if let Some(transition) = zone.transition {
+                    zone.restore_size = transition.to;
+                } else {
+                    zone.restore_size = current_size;
+                }
+
+                zone.collapsed = true;
+                zone.transition = SizedZoneTransition::new(current_size, 0.).into();
+            } else if !collapse && zone.collapsed {
+                let restore_size = zone.restore_size.max(zone.children_size);
+                zone.collapsed = false;
+                zone.transition = SizedZoneTransition::new(current_size, restore_size).into();
+            }
+        }
+
+        let Some(mut transition) = zone.transition else {
+            continue;
+        };
+
+        transition.elapsed += r_time.delta_seconds();
+        if transition.elapsed < COLLAPSE_DURATION {
+            zone.transition = transition.into();
+            continue;
+        }
+
+        zone.transition = None;
+        if !zone.collapsed {
+            let total_size = q_node
+                .get(parent.get())
+                .map(|parent_node| zone.axis_size(parent_node.size()))
+                .unwrap_or(0.);
+
+            if total_size > 0. {
+                zone.set_pixel_size(transition.to, total_size);
+            }
+        }
     }
 }
 
@@ -443,14 +554,19 @@ fn update_sized_zone_style(mut q_sized_zones: Query<(&SizedZone, &mut Style), Ch
         match zone.flex_direction {
             FlexDirection::Row => {
                 style.width = Val::Percent(100.);
-                style.height = Val::Percent(zone.size_percent);
+                style.height = zone.style_size();
             }
             FlexDirection::Column => {
-                style.width = Val::Percent(zone.size_percent);
+                style.width = zone.style_size();
                 style.height = Val::Percent(100.);
             }
             _ => (),
         }
+
+        style.overflow = match zone.collapsed || zone.transition.is_some() {
+            true => Overflow::clip(),
+            false => Overflow::DEFAULT,
+        };
     }
 }
 
@@ -499,51 +615,97 @@ fn fit_sized_zones_on_window_resize(
             }
         }
 
-        let mut sum_zone_size = Vec2::ZERO;
+        // Fixed, collapsed and animating zones keep their size, proportional zones share the rest
+        let mut fixed_size = Vec2::ZERO;
+        let mut proportional_zones: Vec<Entity> = Vec::new();
         for child in q_children.get(parent).unwrap().iter() {
-            if let Ok((_, node)) = q_sized_zone.get(*child) {
-                sum_zone_size += node.size();
+            let Ok((sized_zone, node)) = q_sized_zone.get(*child) else {
+                continue;
             };
+
+            if sized_zone.is_proportional() {
+                proportional_zones.push(*child);
+            } else {
+                fixed_size += node.size();
+            }
         }
 
-        for child in q_children.get(parent).unwrap().iter() {
-            let Ok((mut sized_zone, zone_node)) = q_sized_zone.get_mut(*child) else {
-                continue;
-            };
+        let Some(first_zone) = proportional_zones.first() else {
+            continue;
+        };
+        let (first_zone, _) = q_sized_zone.get(*first_zone).unwrap();
+        let total_size = first_zone.axis_size(parent_node.size());
+        let available_size =
+            total_size - first_zone.axis_size(non_sized_size) - first_zone.axis_size(fixed_size);
 
-            let total_size = match sized_zone.flex_direction {
-                FlexDirection::Row => parent_node.size().y,
-                FlexDirection::Column => parent_node.size().x,
-                _ => 0.,
-            };
-            let non_sized_size = match sized_zone.flex_direction {
-                FlexDirection::Row => non_sized_size.y,
-                FlexDirection::Column => non_sized_size.x,
-                _ => 0.,
-            };
-            let sum_zone_size = match sized_zone.flex_direction {
-                FlexDirection::Row => sum_zone_size.y,
-                FlexDirection::Column => sum_zone_size.x,
-                _ => 0.,
-            };
+        if total_size == 0. || available_size <= 0. {
+            continue;
+        }
+
+        let limits: Vec<(f32, f32, f32)> = proportional_zones
+            .iter()
+            .map(|zone| {
+                let (sized_zone, node) = q_sized_zone.get(*zone).unwrap();
+                (
+                    sized_zone.axis_size(node.size()),
+                    sized_zone.children_size,
+                    sized_zone.max_pixel_size(),
+                )
+            })
+            .collect();
+
+        let sizes = distribute_sized_zone_sizes(&limits, available_size);
+        for (zone, size) in proportional_zones.iter().zip(sizes) {
+            let (mut sized_zone, _) = q_sized_zone.get_mut(*zone).unwrap();
+            sized_zone.size_percent = (size / total_size) * 100.;
+        }
+    }
+}
 
-            let sized_size = total_size - non_sized_size;
+/// Scales the (current, min, max) sizes to fill the available size, respecting the limits.
+/// Space a zone can't take due to its limits is shared among the others.
+fn distribute_sized_zone_sizes(limits: &[(f32, f32, f32)], available_size: f32) -> Vec<f32> {
+    let mut sizes: Vec<f32> = limits.iter().map(|(size, min, _)| size.max(*min)).collect();
+    let mut settled: Vec<bool> = vec![false; limits.len()];
 
-            if total_size == 0. || sum_zone_size == 0. || sized_size <= 0. {
+    for _ in 0..limits.len() {
+        let settled_size: f32 = sizes
+            .iter()
+            .zip(settled.iter())
+            .filter(|(_, settled)| **settled)
+            .map(|(size, _)| *size)
+            .sum();
+        let free_size: f32 = sizes
+            .iter()
+            .zip(settled.iter())
+            .filter(|(_, settled)| !**settled)
+            .map(|(size, _)| *size)
+            .sum();
+        if free_size <= 0. {
+            break;
+        }
+
+        let multiplier = (available_size - settled_size).max(0.) / free_size;
+        let mut clamped = false;
+        for (i, (_, min, max)) in limits.iter().enumerate() {
+            if settled[i] {
                 continue;
             }
 
-            let multiplier = sized_size / sum_zone_size;
-            let own_size = match sized_zone.flex_direction {
-                FlexDirection::Row => zone_node.size().y,
-                FlexDirection::Column => zone_node.size().x,
-                _ => 0.,
-            };
+            let size = sizes[i] * multiplier;
+            sizes[i] = size.clamp(*min, max.max(*min));
+            if sizes[i] != size {
+                settled[i] = true;
+                clamped = true;
+            }
+        }
 
-            sized_zone.size_percent =
-                (own_size.max(sized_zone.children_size) / total_size) * 100. * multiplier;
+        if !clamped {
+            break;
         }
     }
+
+    sizes
 }
 
 #[derive(Component, Clone, Copy, Debug, Default, Reflect)]
@@ -566,17 +728,64 @@ impl Default for SizedZoneResizeHandle {
     }
 }
 
+/// How a sized zone's size reacts to its container changing size
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum SizedZoneMode {
+    /// The size is a percentage of the container, zones share the container's space
+    #[default]
+    Proportional,
+    /// The size is in pixels and stays the same when the container is resized
+    Fixed,
+}
+
 #[derive(Debug, Default)]
 pub struct SizedZoneConfig {
+    /// Percentage of the container, or pixels in `SizedZoneMode::Fixed`
     pub size: f32,
+    /// Minimum size in pixels
     pub min_size: f32,
+    /// Maximum size in pixels
+    pub max_size: Option<f32>,
+    pub mode: SizedZoneMode,
+    /// Double-clicking the resize handle collapses the zone, and expands it again
+    pub collapsible: bool,
+}
+
+#[derive(Clone, Copy, Debug, Reflect)]
+struct SizedZoneTransition {
+    from: f32,
+    to: f32,
+    elapsed: f32,
+}
+
+impl SizedZoneTransition {
+    fn new(from: f32, to: f32) -> Self {
+        Self {
+            from,
+            to,
+            elapsed: 0.,
+        }
+    }
+
+    fn value(&self) -> f32 {
+        let progress = (self.elapsed / COLLAPSE_DURATION).ease(Ease::OutCubic);
+        self.from.lerp(self.to, progress)
+    }
 }
 
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct SizedZone {
     size_percent: f32,
+    fixed_size: f32,
     min_size: f32,
+    max_size: Option<f32>,
+    mode: SizedZoneMode,
+    collapsible: bool,
+    collapsed: bool,
+    collapse_request: Option<bool>,
+    restore_size: f32,
+    transition: Option<SizedZoneTransition>,
     children_size: f32,
     flex_direction: FlexDirection,
     resize_handles: Entity,
@@ -590,7 +799,15 @@ impl Default for SizedZone {
     fn default() -> Self {
         Self {
             size_percent: Default::default(),
+            fixed_size: Default::default(),
             min_size: MIN_SIZED_ZONE_SIZE,
+            max_size: None,
+            mode: Default::default(),
+            collapsible: false,
+            collapsed: false,
+            collapse_request: None,
+            restore_size: Default::default(),
+            transition: None,
             children_size: Default::default(),
             flex_direction: Default::default(),
             resize_handles: Entity::PLACEHOLDER,
@@ -649,18 +866,103 @@ impl SizedZone {
         self.min_size
     }
 
+    pub fn max_size(&self) -> Option<f32> {
+        self.max_size
+    }
+
+    pub fn mode(&self) -> SizedZoneMode {
+        self.mode
+    }
+
+    /// The size in pixels of a `SizedZoneMode::Fixed` zone
+    pub fn fixed_size(&self) -> f32 {
+        self.fixed_size
+    }
+
+    pub fn set_fixed_size(&mut self, size: f32) {
+        self.fixed_size = size.clamp(self.min_size, self.max_pixel_size().max(self.min_size));
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Animates the zone to zero size, remembering its size to expand to
+    pub fn collapse(&mut self) {
+        self.collapse_request = Some(true);
+    }
+
+    /// Animates a collapsed zone back to its size before collapsing
+    pub fn expand(&mut self) {
+        self.collapse_request = Some(false);
+    }
+
+    pub fn toggle_collapsed(&mut self) {
+        match self.collapse_request.unwrap_or(self.collapsed) {
+            true => self.expand(),
+            false => self.collapse(),
+        }
+    }
+
+    fn is_proportional(&self) -> bool {
+        self.mode == SizedZoneMode::Proportional && !self.collapsed && self.transition.is_none()
+    }
+
+    fn axis_size(&self, size: Vec2) -> f32 {
+        match self.flex_direction {
+            FlexDirection::Row => size.y,
+            FlexDirection::Column => size.x,
+            _ => 0.,
+        }
+    }
+
+    fn max_pixel_size(&self) -> f32 {
+        self.max_size.unwrap_or(f32::INFINITY)
+    }
+
+    fn pixel_size(&self, total_size: f32) -> f32 {
+        match self.mode {
+            SizedZoneMode::Fixed => self.fixed_size,
+            SizedZoneMode::Proportional => (self.size_percent / 100.) * total_size,
+        }
+    }
+
+    fn set_pixel_size(&mut self, size: f32, total_size: f32) {
+        match self.mode {
+            SizedZoneMode::Fixed => self.set_fixed_size(size),
+            SizedZoneMode::Proportional => self.set_size((size / total_size) * 100.),
+        }
+    }
+
+    fn style_size(&self) -> Val {
+        if let Some(transition) = self.transition {
+            return Val::Px(transition.value());
+        } else if self.collapsed {
+            return Val::Px(0.);
+        }
+
+        match self.mode {
+            SizedZoneMode::Fixed => Val::Px(self.fixed_size),
+            SizedZoneMode::Proportional => Val::Percent(self.size_percent),
+        }
+    }
+
     pub fn theme() -> Theme<SizedZone> {
-        let base_theme = PseudoTheme::deferred(None, SizedZone::primary_style);
+        let base_theme = PseudoTheme::deferred(None, SizedZone::primary_style)
+            .depends_on(ThemeDataSections::COLORS);
 
-        let theme_row = PseudoTheme::deferred(vec![PseudoState::LayoutRow], SizedZone::style_row);
+        let theme_row = PseudoTheme::deferred(vec![PseudoState::LayoutRow], SizedZone::style_row)
+            .depends_on(ThemeDataSections::SPACING);
         let theme_row_first = PseudoTheme::deferred(
             vec![PseudoState::LayoutRow, PseudoState::FirstChild],
             SizedZone::style_row_first,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_row_last = PseudoTheme::deferred(
             vec![PseudoState::LayoutRow, PseudoState::LastChild],
             SizedZone::style_row_last,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_row_single = PseudoTheme::deferred(
             vec![
                 PseudoState::LayoutRow,
@@ -669,18 +971,22 @@ impl SizedZone {
                 PseudoState::SingleChild,
             ],
             SizedZone::style_row_single,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
 
         let theme_column =
-            PseudoTheme::deferred(vec![PseudoState::LayoutColumn], SizedZone::style_column);
+            PseudoTheme::deferred(vec![PseudoState::LayoutColumn], SizedZone::style_column)
+                .depends_on(ThemeDataSections::SPACING);
         let theme_column_first = PseudoTheme::deferred(
             vec![PseudoState::LayoutColumn, PseudoState::FirstChild],
             SizedZone::style_column_first,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_column_last = PseudoTheme::deferred(
             vec![PseudoState::LayoutColumn, PseudoState::LastChild],
             SizedZone::style_column_last,
-        );
+        )
+        .depends_on(ThemeDataSections::SPACING);
         let theme_column_single = PseudoTheme::deferred(
             vec![
                 PseudoState::LayoutColumn,
@@ -689,7 +995,8 @@ impl SizedZone {
                 PseudoState::SingleChild,
             ],
             SizedZone::style_column_single,
-        );
+        )
+        .depends_on(ThemeDataSections::NONE);
 
         Theme::new(vec![
             base_theme,
@@ -789,13 +1096,18 @@ impl UiSizedZoneExt for UiBuilder<'_, Entity> {
         config: SizedZoneConfig,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity> {
-        let size = config.size.clamp(0., 100.);
         let min_size = config.min_size.max(MIN_SIZED_ZONE_SIZE);
         let mut sized_zone = SizedZone {
-            size_percent: size,
             min_size,
+            max_size: config.max_size,
+            mode: config.mode,
+            collapsible: config.collapsible,
             ..Default::default()
         };
+        match config.mode {
+            SizedZoneMode::Fixed => sized_zone.set_fixed_size(config.size),
+            SizedZoneMode::Proportional => sized_zone.set_size(config.size),
+        }
 
         let mut frame = self.container(SizedZone::frame(), |container| {
             let zone_id = container.id();
@@ -821,3 +1133,55 @@ impl UiSizedZoneExt for UiBuilder<'_, Entity> {
         frame
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_sizes(sizes: Vec<f32>, expected: &[f32]) {
+        assert_eq!(sizes.len(), expected.len());
+        for (size, expected) in sizes.iter().zip(expected) {
+            assert!(
+                (size - expected).abs() < 0.01,
+                "{:?} != {:?}",
+                sizes,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn distribute_clamps_to_min_and_max() {
+        let limits = [(100., 150., f32::INFINITY), (100., 0., f32::INFINITY)];
+        assert_sizes(distribute_sized_zone_sizes(&limits, 200.), &[150., 50.]);
+
+        let limits = [(100., 0., 120.), (100., 0., f32::INFINITY)];
+        assert_sizes(distribute_sized_zone_sizes(&limits, 400.), &[120., 280.]);
+
+        // The minimum wins over a smaller maximum
+        let limits = [(100., 80., 50.), (100., 0., f32::INFINITY)];
+        assert_sizes(distribute_sized_zone_sizes(&limits, 100.), &[80., 20.]);
+    }
+
+    #[test]
+    fn distribute_shares_space_proportionally_when_a_zone_hits_its_max() {
+        let limits = [
+            (100., 0., 150.),
+            (100., 0., f32::INFINITY),
+            (200., 0., f32::INFINITY),
+        ];
+        let sizes = distribute_sized_zone_sizes(&limits, 800.);
+
+        assert_sizes(sizes.clone(), &[150., 216.67, 433.33]);
+        assert!((sizes[2] / sizes[1] - 2.).abs() < 0.001);
+    }
+
+    #[test]
+    fn distribute_keeps_mins_when_the_space_is_too_small() {
+        let limits = [(100., 150., f32::INFINITY), (100., 150., f32::INFINITY)];
+        assert_sizes(distribute_sized_zone_sizes(&limits, 200.), &[150., 150.]);
+
+        let limits = [(100., 150., f32::INFINITY), (100., 0., f32::INFINITY)];
+        assert_sizes(distribute_sized_zone_sizes(&limits, 100.), &[150., 0.]);
+    }
+}
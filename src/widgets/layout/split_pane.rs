@@ -0,0 +1,682 @@
+use bevy::{
+    a11y::{accesskit::Role, Focus},
+    prelude::*,
+    ui::RelativeCursorPosition,
+    utils::HashMap,
+};
+
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::prelude::*;
+
+use super::{
+    container::UiContainerExt,
+    resize_handles::{ResizeDirection, ResizeHandle},
+};
+
+const KEYBOARD_STEP: f32 = 10.;
+const KEYBOARD_LARGE_STEP: f32 = 50.;
+
+pub struct SplitPanePlugin;
+
+impl Plugin for SplitPanePlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, SplitPaneUpdate.after(DraggableUpdate))
+            .init_resource::<SplitPaneRatios>()
+            .register_type::<SplitPaneRatios>()
+            .add_plugins(ComponentThemePlugin::<SplitPaneGutter>::default())
+            .add_systems(
+                PreUpdate,
+                (update_split_pane_gutters, restore_split_pane_ratios).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    focus_split_pane_gutter_on_press,
+                    update_split_pane_on_gutter_drag,
+                    update_split_pane_on_key_press,
+                    update_split_pane_gutter_focus,
+                    update_split_pane_item_style,
+                )
+                    .chain()
+                    .in_set(SplitPaneUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct SplitPaneUpdate;
+
+/// Keeps exactly one gutter between each pair of neighbouring items
+fn update_split_pane_gutters(
+    q_split_panes: Query<(Entity, &SplitPane, &Children), Changed<Children>>,
+    q_items: Query<(), With<SplitPaneItem>>,
+    q_gutters: Query<&SplitPaneGutter>,
+    mut commands: Commands,
+) {
+    for (entity, split_pane, children) in &q_split_panes {
+        let items: Vec<Entity> = children
+            .iter()
+            .copied()
+            .filter(|child| q_items.contains(*child))
+            .collect();
+        let gutters: Vec<Entity> = children
+            .iter()
+            .copied()
+            .filter(|child| q_gutters.contains(*child))
+            .collect();
+
+        let in_order = gutters.len() == items.len().saturating_sub(1)
+            && gutters.iter().enumerate().all(|(i, gutter)| {
+                q_gutters
+                    .get(*gutter)
+                    .is_ok_and(|gutter| gutter.before == items[i] && gutter.after == items[i + 1])
+            })
+            && children
+                .iter()
+                .filter(|child| q_items.contains(**child) || q_gutters.contains(**child))
+                .enumerate()
+                .all(|(i, child)| q_gutters.contains(*child) == (i % 2 == 1));
+
+        if in_order {
+            continue;
+        }
+
+        for gutter in gutters {
+            commands.entity(gutter).despawn_recursive();
+        }
+
+        let mut ordered: Vec<Entity> = Vec::with_capacity(children.len() + items.len());
+        let mut previous_item: Option<Entity> = None;
+        for child in children.iter().filter(|child| !q_gutters.contains(**child)) {
+            if q_items.contains(*child) {
+                if let Some(before) = previous_item {
+                    let gutter = SplitPaneGutter {
+                        split_pane: entity,
+                        before,
+                        after: *child,
+                    };
+                    let gutter = commands
+                        .spawn(SplitPaneGutter::frame(gutter, split_pane.direction))
+                        .id();
+                    ordered.push(gutter);
+                }
+                previous_item = Some(*child);
+            }
+
+            ordered.push(*child);
+        }
+
+        commands.entity(entity).replace_children(&ordered);
+    }
+}
+
+fn restore_split_pane_ratios(
+    r_ratios: Res<SplitPaneRatios>,
+    q_split_panes: Query<(&SplitPaneKey, &Children), Added<SplitPaneKey>>,
+    mut q_items: Query<&mut SplitPaneItem>,
+) {
+    for (key, children) in &q_split_panes {
+        let Some(ratios) = r_ratios.get(&key.0) else {
+            continue;
+        };
+
+        let items: Vec<Entity> = children
+            .iter()
+            .copied()
+            .filter(|child| q_items.contains(*child))
+            .collect();
+        if items.len() != ratios.len() {
+            continue;
+        }
+
+        for (item, ratio) in items.iter().zip(ratios) {
+            if let Ok(mut item) = q_items.get_mut(*item) {
+                item.set_ratio(*ratio);
+            }
+        }
+    }
+}
+
+type ChangedGutterInteraction = (With<SplitPaneGutter>, Changed<FluxInteraction>);
+
+fn focus_split_pane_gutter_on_press(
+    q_gutters: Query<(Entity, &FluxInteraction), ChangedGutterInteraction>,
+    r_focus: Option<ResMut<Focus>>,
+) {
+    let Some(mut focus) = r_focus else {
+        return;
+    };
+
+    for (entity, interaction) in &q_gutters {
+        if *interaction == FluxInteraction::Pressed {
+            focus.0 = Some(entity);
+        }
+    }
+}
+
+fn update_split_pane_on_gutter_drag(
+    q_gutters: Query<(&SplitPaneGutter, &ResizeHandle, &Draggable), Changed<Draggable>>,
+    q_split_panes: Query<(&SplitPane, Option<&SplitPaneKey>, &Children)>,
+    mut q_items: Query<(&mut SplitPaneItem, &Node)>,
+    mut r_ratios: ResMut<SplitPaneRatios>,
+) {
+    for (gutter, handle, draggable) in &q_gutters {
+        if draggable.state == DragState::Inactive
+            || draggable.state == DragState::MaybeDragged
+            || draggable.state == DragState::DragCanceled
+        {
+            continue;
+        }
+
+        let Some(diff) = draggable.diff else {
+            continue;
+        };
+
+        let size_diff = handle.direction().to_size_diff(diff);
+        resize_split_pane(
+            gutter,
+            size_diff.x + size_diff.y,
+            &q_split_panes,
+            &mut q_items,
+            &mut r_ratios,
+        );
+    }
+}
+
+fn update_split_pane_on_key_press(
+    r_keys: Res<ButtonInput<KeyCode>>,
+    r_focus: Option<Res<Focus>>,
+    q_gutters: Query<&SplitPaneGutter>,
+    q_split_panes: Query<(&SplitPane, Option<&SplitPaneKey>, &Children)>,
+    mut q_items: Query<(&mut SplitPaneItem, &Node)>,
+    mut r_ratios: ResMut<SplitPaneRatios>,
+) {
+    let Some(focused) = r_focus.and_then(|focus| focus.0) else {
+        return;
+    };
+    let Ok(gutter) = q_gutters.get(focused) else {
+        return;
+    };
+    let Ok((split_pane, _, _)) = q_split_panes.get(gutter.split_pane) else {
+        return;
+    };
+
+    let (decrease, increase) = match split_pane.direction {
+        SplitPaneDirection::Horizontal => (KeyCode::ArrowLeft, KeyCode::ArrowRight),
+        SplitPaneDirection::Vertical => (KeyCode::ArrowUp, KeyCode::ArrowDown),
+    };
+    let step = match r_keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        true => KEYBOARD_LARGE_STEP,
+        false => KEYBOARD_STEP,
+    };
+
+    let diff = if r_keys.just_pressed(decrease) {
+        -step
+    } else if r_keys.just_pressed(increase) {
+        step
+    } else if r_keys.just_pressed(KeyCode::Home) {
+        f32::NEG_INFINITY
+    } else if r_keys.just_pressed(KeyCode::End) {
+        f32::INFINITY
+    } else {
+        return;
+    };
+
+    resize_split_pane(gutter, diff, &q_split_panes, &mut q_items, &mut r_ratios);
+}
+
+/// Moves the gutter by `diff` pixels, trading size between its neighbouring items only.
+/// Items are kept within their min and max sizes, ratios of keyed split panes are saved.
+fn resize_split_pane(
+    gutter: &SplitPaneGutter,
+    diff: f32,
+    q_split_panes: &Query<(&SplitPane, Option<&SplitPaneKey>, &Children)>,
+    q_items: &mut Query<(&mut SplitPaneItem, &Node)>,
+    r_ratios: &mut SplitPaneRatios,
+) {
+    if diff == 0. {
+        return;
+    }
+
+    let Ok((split_pane, key, children)) = q_split_panes.get(gutter.split_pane) else {
+        return;
+    };
+    let Ok([(mut before, before_node), (mut after, after_node)]) =
+        q_items.get_many_mut([gutter.before, gutter.after])
+    else {
+        return;
+    };
+
+    let before_size = split_pane.direction.axis_size(before_node.size());
+    let after_size = split_pane.direction.axis_size(after_node.size());
+    let total_size = before_size + after_size;
+    if total_size <= 0. {
+        return;
+    }
+
+    let new_before_size = before.clamp_size(before_size + diff);
+    let new_before_size = total_size - after.clamp_size(total_size - new_before_size);
+    if new_before_size == before_size {
+        return;
+    }
+
+    let total_ratio = before.ratio + after.ratio;
+    before.set_ratio(total_ratio * (new_before_size / total_size));
+    after.set_ratio(total_ratio - before.ratio);
+
+    let Some(key) = key else {
+        return;
+    };
+
+    let ratios: Vec<f32> = children
+        .iter()
+        .filter_map(|child| q_items.get(*child).ok())
+        .map(|(item, _)| item.ratio)
+        .collect();
+    r_ratios.set(key.0.clone(), ratios);
+}
+
+fn update_split_pane_gutter_focus(
+    r_focus: Option<Res<Focus>>,
+    q_gutters: Query<(Entity, &PseudoStates), With<SplitPaneGutter>>,
+    mut commands: Commands,
+) {
+    let Some(focus) = r_focus else {
+        return;
+    };
+    if !focus.is_changed() {
+        return;
+    }
+
+    for (entity, pseudo_states) in &q_gutters {
+        let focused = focus.0 == Some(entity);
+        if focused && !pseudo_states.has(&PseudoState::Focused) {
+            commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::Focused);
+        } else if !focused && pseudo_states.has(&PseudoState::Focused) {
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Focused);
+        }
+    }
+}
+
+fn update_split_pane_item_style(
+    q_split_panes: Query<&SplitPane>,
+    mut q_items: Query<(&SplitPaneItem, &Parent, &mut Style), Changed<SplitPaneItem>>,
+) {
+    for (item, parent, mut style) in &mut q_items {
+        let Ok(split_pane) = q_split_panes.get(parent.get()) else {
+            continue;
+        };
+
+        style.flex_grow = item.ratio;
+        let min_size = Val::Px(item.min_size);
+        let max_size = item.max_size.map_or(Val::Auto, Val::Px);
+        match split_pane.direction {
+            SplitPaneDirection::Horizontal => {
+                style.min_width = min_size;
+                style.max_width = max_size;
+            }
+            SplitPaneDirection::Vertical => {
+                style.min_height = min_size;
+                style.max_height = max_size;
+            }
+        }
+    }
+}
+
+/// Ratios of keyed split panes, updated as their gutters are moved.
+///
+/// Split panes spawned with a key they were saved under start with the saved ratios,
+/// as long as they have the same number of items.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct SplitPaneRatios {
+    ratios: HashMap<String, Vec<f32>>,
+}
+
+impl SplitPaneRatios {
+    pub fn get(&self, key: &str) -> Option<&Vec<f32>> {
+        self.ratios.get(key)
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, ratios: Vec<f32>) {
+        self.ratios.insert(key.into(), ratios);
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Vec<f32>> {
+        self.ratios.remove(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Vec<f32>)> + '_ {
+        self.ratios.iter()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum SplitPaneDirection {
+    /// Items are laid out side by side, separated by vertical gutters
+    #[default]
+    Horizontal,
+    /// Items are stacked, separated by horizontal gutters
+    Vertical,
+}
+
+impl SplitPaneDirection {
+    fn axis_size(&self, size: Vec2) -> f32 {
+        match self {
+            SplitPaneDirection::Horizontal => size.x,
+            SplitPaneDirection::Vertical => size.y,
+        }
+    }
+
+    fn flex_direction(&self) -> FlexDirection {
+        match self {
+            SplitPaneDirection::Horizontal => FlexDirection::Row,
+            SplitPaneDirection::Vertical => FlexDirection::Column,
+        }
+    }
+
+    fn resize_direction(&self) -> ResizeDirection {
+        match self {
+            SplitPaneDirection::Horizontal => ResizeDirection::East,
+            SplitPaneDirection::Vertical => ResizeDirection::South,
+        }
+    }
+
+    fn pseudo_state(&self) -> PseudoState {
+        match self {
+            SplitPaneDirection::Horizontal => PseudoState::LayoutRow,
+            SplitPaneDirection::Vertical => PseudoState::LayoutColumn,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SplitPaneConfig {
+    pub direction: SplitPaneDirection,
+    /// Saves the ratios in `SplitPaneRatios` under this key, and restores them on spawn
+    pub key: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct SplitPaneItemConfig {
+    /// Share of the split pane's space, relative to the ratios of the other items
+    pub ratio: f32,
+    /// Minimum size in pixels
+    pub min_size: f32,
+    /// Maximum size in pixels
+    pub max_size: Option<f32>,
+}
+
+impl Default for SplitPaneItemConfig {
+    fn default() -> Self {
+        Self {
+            ratio: 1.,
+            min_size: 0.,
+            max_size: None,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SplitPane {
+    direction: SplitPaneDirection,
+}
+
+impl SplitPane {
+    pub fn direction(&self) -> SplitPaneDirection {
+        self.direction
+    }
+
+    fn frame(direction: SplitPaneDirection) -> impl Bundle {
+        (
+            Name::new("Split Pane"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: direction.flex_direction(),
+                    ..default()
+                },
+                ..default()
+            },
+            SplitPane { direction },
+        )
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct SplitPaneKey(pub String);
+
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct SplitPaneItem {
+    ratio: f32,
+    min_size: f32,
+    max_size: Option<f32>,
+}
+
+impl Default for SplitPaneItem {
+    fn default() -> Self {
+        Self {
+            ratio: 1.,
+            min_size: 0.,
+            max_size: None,
+        }
+    }
+}
+
+impl SplitPaneItem {
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.max(0.);
+    }
+
+    pub fn min_size(&self) -> f32 {
+        self.min_size
+    }
+
+    pub fn max_size(&self) -> Option<f32> {
+        self.max_size
+    }
+
+    fn clamp_size(&self, size: f32) -> f32 {
+        size.min(self.max_size.unwrap_or(f32::INFINITY))
+            .max(self.min_size)
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Split Pane Item"),
+            NodeBundle {
+                style: Style {
+                    flex_basis: Val::Px(0.),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ..default()
+            },
+        )
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Reflect, UiContext)]
+#[reflect(Component)]
+pub struct SplitPaneGutter {
+    split_pane: Entity,
+    before: Entity,
+    after: Entity,
+}
+
+impl Default for SplitPaneGutter {
+    fn default() -> Self {
+        Self {
+            split_pane: Entity::PLACEHOLDER,
+            before: Entity::PLACEHOLDER,
+            after: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for SplitPaneGutter {
+    fn default_theme() -> Option<Theme<SplitPaneGutter>> {
+        SplitPaneGutter::theme().into()
+    }
+}
+
+impl SplitPaneGutter {
+    pub fn split_pane_id(&self) -> Entity {
+        self.split_pane
+    }
+
+    /// The item before the gutter, grown by moving the gutter forward
+    pub fn before_id(&self) -> Entity {
+        self.before
+    }
+
+    /// The item after the gutter, shrunk by moving the gutter forward
+    pub fn after_id(&self) -> Entity {
+        self.after
+    }
+
+    pub fn theme() -> Theme<SplitPaneGutter> {
+        let base_theme = PseudoTheme::deferred(None, SplitPaneGutter::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::ANIMATIONS);
+        let row_theme =
+            PseudoTheme::deferred(vec![PseudoState::LayoutRow], SplitPaneGutter::row_style)
+                .depends_on(ThemeDataSections::SPACING);
+        let column_theme = PseudoTheme::deferred(
+            vec![PseudoState::LayoutColumn],
+            SplitPaneGutter::column_style,
+        )
+        .depends_on(ThemeDataSections::SPACING);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], SplitPaneGutter::focused_style)
+                .depends_on(ThemeDataSections::COLORS);
+
+        Theme::new(vec![base_theme, row_theme, column_theme, focused_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .flex_shrink(0.)
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.accent(Accent::OutlineVariant),
+                hover: colors.accent(Accent::Outline).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn row_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        style_builder
+            .width(Val::Px(theme_data.spacing.gaps.small))
+            .height(Val::Percent(100.));
+    }
+
+    fn column_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        style_builder
+            .width(Val::Percent(100.))
+            .height(Val::Px(theme_data.spacing.gaps.small));
+    }
+
+    fn focused_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        style_builder.background_color(theme_data.colors().accent(Accent::Primary));
+    }
+
+    fn frame(gutter: SplitPaneGutter, direction: SplitPaneDirection) -> impl Bundle {
+        (
+            Name::new("Split Pane Gutter"),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+            Draggable::default(),
+            RelativeCursorPosition::default(),
+            ResizeHandle::new(direction.resize_direction()),
+            AccessibleWidget::new(Role::Splitter),
+            PseudoStates::from(vec![direction.pseudo_state()]),
+            gutter,
+        )
+    }
+}
+
+pub trait UiSplitPaneExt {
+    fn split_pane(
+        &mut self,
+        config: SplitPaneConfig,
+        spawn_children: impl FnOnce(&mut UiBuilder<(Entity, SplitPane)>),
+    ) -> UiBuilder<Entity>;
+}
+
+impl UiSplitPaneExt for UiBuilder<'_, Entity> {
+    /// A container that splits its space between its items, resized by dragging the gutters
+    /// between them. Gutters are added automatically, and can be moved with the arrow keys
+    /// while focused. Split panes can be nested in items of other split panes.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::LayoutRow` and `PseudoState::LayoutColumn` are set on the gutters
+    /// - `PseudoState::Focused` is set on the gutter with the accessibility focus
+    fn split_pane(
+        &mut self,
+        config: SplitPaneConfig,
+        spawn_children: impl FnOnce(&mut UiBuilder<(Entity, SplitPane)>),
+    ) -> UiBuilder<Entity> {
+        let split_pane = SplitPane {
+            direction: config.direction,
+        };
+
+        let mut container = self.container(SplitPane::frame(config.direction), |container| {
+            let id = container.id();
+            spawn_children(&mut container.commands().ui_builder((id, split_pane)));
+        });
+
+        if let Some(key) = config.key {
+            container.insert(SplitPaneKey(key));
+        }
+
+        container
+    }
+}
+
+pub trait UiSplitPaneSubExt {
+    fn split_pane_item(
+        &mut self,
+        config: SplitPaneItemConfig,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<Entity>;
+}
+
+impl UiSplitPaneSubExt for UiBuilder<'_, (Entity, SplitPane)> {
+    /// An item of the split pane, taking `ratio` share of its space
+    fn split_pane_item(
+        &mut self,
+        config: SplitPaneItemConfig,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<Entity> {
+        let split_pane = self.context().0;
+        let mut item = SplitPaneItem {
+            min_size: config.min_size.max(0.),
+            max_size: config.max_size,
+            ..default()
+        };
+        item.set_ratio(config.ratio);
+
+        let item_id = self
+            .commands()
+            .ui_builder(split_pane)
+            .container((SplitPaneItem::frame(), item), spawn_children)
+            .id();
+
+        self.commands().ui_builder(item_id)
+    }
+}
@@ -0,0 +1,506 @@
+use bevy::{a11y::accesskit::Role, prelude::*, ui::FocusPolicy};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::WidgetLibraryUpdate;
+
+use super::{
+    container::UiContainerExt,
+    label::{LabelConfig, SetLabelTextExt, UiLabelExt},
+    progress_bar::{ProgressBarConfig, UiProgressBarExt},
+};
+
+pub struct StatusBarPlugin;
+
+impl Plugin for StatusBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            StatusBarUpdate
+                .after(FluxInteractionUpdate)
+                .before(WidgetLibraryUpdate),
+        )
+        .add_plugins((
+            ComponentThemePlugin::<StatusBar>::default(),
+            ComponentThemePlugin::<StatusItem>::default(),
+        ))
+        .add_event::<StatusItemClicked>()
+        .add_systems(
+            Update,
+            (update_status_item_text, send_status_item_clicked).in_set(StatusBarUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct StatusBarUpdate;
+
+/// Sent when a clickable status item is clicked
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StatusItemClicked {
+    pub item: Entity,
+}
+
+fn update_status_item_text(
+    q_status_items: Query<&StatusItem, Changed<StatusItem>>,
+    mut commands: Commands,
+) {
+    for item in &q_status_items {
+        commands
+            .entity(item.label)
+            .set_label_text(item.text.clone());
+        commands.style(item.label).render(!item.text.is_empty());
+    }
+}
+
+fn send_status_item_clicked(
+    q_status_items: Query<(Entity, &StatusItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut writer: EventWriter<StatusItemClicked>,
+) {
+    for (entity, item, interaction) in &q_status_items {
+        if item.clickable && interaction.is_released() {
+            writer.send(StatusItemClicked { item: entity });
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum StatusBarSection {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct StatusBar {
+    left: Entity,
+    center: Entity,
+    right: Entity,
+}
+
+impl Default for StatusBar {
+    fn default() -> Self {
+        Self {
+            left: Entity::PLACEHOLDER,
+            center: Entity::PLACEHOLDER,
+            right: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for StatusBar {
+    fn default_theme() -> Option<Theme<StatusBar>> {
+        StatusBar::theme().into()
+    }
+}
+
+impl UiContext for StatusBar {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            StatusBar::LEFT => Ok(self.left),
+            StatusBar::CENTER => Ok(self.center),
+            StatusBar::RIGHT => Ok(self.right),
+            _ => Err(format!(
+                "{} doesn't exist for StatusBar. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [StatusBar::LEFT, StatusBar::CENTER, StatusBar::RIGHT].into_iter()
+    }
+}
+
+impl StatusBar {
+    pub const LEFT: &'static str = "Left";
+    pub const CENTER: &'static str = "Center";
+    pub const RIGHT: &'static str = "Right";
+
+    pub fn section(&self, section: StatusBarSection) -> Entity {
+        match section {
+            StatusBarSection::Left => self.left,
+            StatusBarSection::Center => self.center,
+            StatusBarSection::Right => self.right,
+        }
+    }
+
+    pub fn theme() -> Theme<StatusBar> {
+        let base_theme = PseudoTheme::deferred(None, StatusBar::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .width(Val::Percent(100.))
+            .height(Val::Px(theme_spacing.areas.small))
+            .flex_shrink(0.)
+            .align_items(AlignItems::Center)
+            .border(UiRect::top(Val::Px(theme_spacing.borders.extra_small)))
+            .background_color(colors.container(Container::SurfaceMid))
+            .border_color(colors.accent(Accent::Shadow))
+            .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)));
+
+        // The side sections share the remaining space evenly, which keeps the center section centered
+        for (section, justify) in [
+            (StatusBar::LEFT, JustifyContent::Start),
+            (StatusBar::CENTER, JustifyContent::Center),
+            (StatusBar::RIGHT, JustifyContent::End),
+        ] {
+            style_builder
+                .switch_target(section)
+                .height(Val::Percent(100.))
+                .align_items(AlignItems::Center)
+                .justify_content(justify)
+                .column_gap(Val::Px(theme_spacing.gaps.small))
+                .overflow(Overflow::clip());
+        }
+
+        for section in [StatusBar::LEFT, StatusBar::RIGHT] {
+            style_builder
+                .switch_target(section)
+                .flex_grow(1.)
+                .flex_basis(Val::Px(0.));
+        }
+    }
+
+    fn frame() -> impl Bundle {
+        (Name::new("Status Bar"), NodeBundle::default())
+    }
+
+    fn section_bundle(name: &str) -> impl Bundle {
+        (
+            Name::new(format!("{} Section", name)),
+            NodeBundle::default(),
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StatusItemConfig {
+    pub text: String,
+    pub icon: IconData,
+    /// Clickable items highlight on hover and send [`StatusItemClicked`] events
+    pub clickable: bool,
+}
+
+impl StatusItemConfig {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..default()
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct StatusItem {
+    /// The text shown by the item. Changing it updates the label.
+    pub text: String,
+    icon_data: IconData,
+    clickable: bool,
+    icon: Entity,
+    label: Entity,
+    progress_bar: Option<Entity>,
+}
+
+impl Default for StatusItem {
+    fn default() -> Self {
+        Self {
+            text: Default::default(),
+            icon_data: Default::default(),
+            clickable: false,
+            icon: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+            progress_bar: None,
+        }
+    }
+}
+
+impl DefaultTheme for StatusItem {
+    fn default_theme() -> Option<Theme<StatusItem>> {
+        StatusItem::theme().into()
+    }
+}
+
+impl UiContext for StatusItem {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            StatusItem::ICON => Ok(self.icon),
+            StatusItem::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for StatusItem. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [StatusItem::ICON, StatusItem::LABEL].into_iter()
+    }
+}
+
+impl StatusItem {
+    pub const ICON: &'static str = "Icon";
+    pub const LABEL: &'static str = "Label";
+
+    /// The progress bar of items added with `status_progress`
+    pub fn progress_bar(&self) -> Option<Entity> {
+        self.progress_bar
+    }
+
+    pub fn theme() -> Theme<StatusItem> {
+        let base_theme = PseudoTheme::deferred_context(None, StatusItem::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, item: &StatusItem, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let font = theme_data
+            .text
+            .get(FontStyle::Label, FontScale::Medium, FontType::Regular);
+
+        style_builder
+            .height(Val::Percent(100.))
+            .flex_shrink(0.)
+            .align_items(AlignItems::Center)
+            .column_gap(Val::Px(theme_spacing.gaps.small))
+            .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )));
+
+        if item.progress_bar.is_some() {
+            style_builder.width(Val::Px(theme_spacing.areas.extra_large));
+        }
+
+        if item.clickable {
+            style_builder
+                .animated()
+                .background_color(AnimatedVals {
+                    idle: colors.container(Container::SurfaceMid),
+                    hover: colors.container(Container::SurfaceHighest).into(),
+                    ..default()
+                })
+                .copy_from(theme_data.interaction_animation);
+        }
+
+        style_builder
+            .switch_target(StatusItem::ICON)
+            .size(Val::Px(theme_spacing.icons.small))
+            .icon(
+                item.icon_data
+                    .with(colors.on(On::SurfaceVariant), theme_spacing.icons.small),
+            );
+
+        style_builder
+            .switch_target(StatusItem::LABEL)
+            .flex_shrink(0.)
+            .sized_font(font)
+            .font_color(colors.on(On::SurfaceVariant));
+    }
+
+    fn frame(clickable: bool) -> impl Bundle {
+        // Non-clickable items leave the pointer to the bar
+        let focus_policy = match clickable {
+            true => FocusPolicy::Block,
+            false => FocusPolicy::Pass,
+        };
+
+        (
+            Name::new("Status Item"),
+            ButtonBundle {
+                focus_policy,
+                ..default()
+            },
+            TrackedInteraction::default(),
+        )
+    }
+
+    fn icon_bundle() -> impl Bundle {
+        (
+            Name::new("Icon"),
+            ImageBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            BorderColor::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+}
+
+pub trait UiStatusBarExt {
+    /// A bar for status text, icons and progress, with left, center, and right sections.
+    /// It is usually placed at the bottom of the window, below the main content.
+    fn status_bar(
+        &mut self,
+        spawn_items: impl FnOnce(&mut UiBuilder<StatusBar>),
+    ) -> UiBuilder<Entity>;
+}
+
+impl UiStatusBarExt for UiBuilder<'_, Entity> {
+    fn status_bar(
+        &mut self,
+        spawn_items: impl FnOnce(&mut UiBuilder<StatusBar>),
+    ) -> UiBuilder<Entity> {
+        let mut status_bar = StatusBar::default();
+
+        let mut frame = self.container(StatusBar::frame(), |container| {
+            status_bar.left = container.spawn(StatusBar::section_bundle("Left")).id();
+            status_bar.center = container.spawn(StatusBar::section_bundle("Center")).id();
+            status_bar.right = container.spawn(StatusBar::section_bundle("Right")).id();
+        });
+        frame.insert((status_bar, AccessibleWidget::new(Role::Status)));
+        let id = frame.id();
+
+        let mut status_bar_builder = self.commands().ui_builder(status_bar);
+        spawn_items(&mut status_bar_builder);
+
+        self.commands().ui_builder(id)
+    }
+}
+
+pub trait UiStatusBarSubExt {
+    fn section(&self, section: StatusBarSection) -> Entity;
+
+    /// A text item, optionally with a leading icon
+    fn status_item(
+        &mut self,
+        section: StatusBarSection,
+        config: StatusItemConfig,
+    ) -> UiBuilder<Entity>;
+
+    /// An item with a progress bar after its text. The progress bar entity is available
+    /// through [`StatusItem::progress_bar`].
+    fn status_progress(
+        &mut self,
+        section: StatusBarSection,
+        config: StatusItemConfig,
+        progress: ProgressBarConfig,
+    ) -> UiBuilder<Entity>;
+}
+
+impl UiStatusBarSubExt for UiBuilder<'_, StatusBar> {
+    fn section(&self, section: StatusBarSection) -> Entity {
+        self.context().section(section)
+    }
+
+    fn status_item(
+        &mut self,
+        section: StatusBarSection,
+        config: StatusItemConfig,
+    ) -> UiBuilder<Entity> {
+        let section_id = self.section(section);
+        let id = spawn_status_item(&mut self.commands().ui_builder(section_id), config, None);
+
+        self.commands().ui_builder(id)
+    }
+
+    fn status_progress(
+        &mut self,
+        section: StatusBarSection,
+        config: StatusItemConfig,
+        progress: ProgressBarConfig,
+    ) -> UiBuilder<Entity> {
+        let section_id = self.section(section);
+        let id = spawn_status_item(
+            &mut self.commands().ui_builder(section_id),
+            config,
+            Some(progress),
+        );
+
+        self.commands().ui_builder(id)
+    }
+}
+
+fn spawn_status_item(
+    builder: &mut UiBuilder<Entity>,
+    config: StatusItemConfig,
+    progress: Option<ProgressBarConfig>,
+) -> Entity {
+    let mut item = StatusItem {
+        text: config.text.clone(),
+        icon_data: config.icon.clone(),
+        clickable: config.clickable,
+        ..default()
+    };
+    let has_icon = !config.icon.is_none();
+    let has_text = !config.text.is_empty();
+
+    let mut frame = builder.container(StatusItem::frame(config.clickable), |container| {
+        item.icon = container
+            .spawn(StatusItem::icon_bundle())
+            .style()
+            .render(has_icon)
+            .id();
+        item.label = container
+            .label(LabelConfig {
+                label: config.text.clone(),
+                ..default()
+            })
+            .style()
+            .render(has_text)
+            .id();
+
+        if let Some(progress) = progress {
+            item.progress_bar = container.progress_bar(progress).id().into();
+        }
+    });
+
+    let role = match config.clickable {
+        true => Role::Button,
+        false => Role::StaticText,
+    };
+    frame.insert((item, AccessibleWidget::new(role).named(config.text)));
+    frame.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn only_clickable_items_send_clicks() {
+        let mut world = World::new();
+        world.init_resource::<Events<StatusItemClicked>>();
+        let clickable = world
+            .spawn((
+                StatusItem {
+                    clickable: true,
+                    ..default()
+                },
+                FluxInteraction::Released,
+            ))
+            .id();
+        world.spawn((StatusItem::default(), FluxInteraction::Released));
+        world.spawn((
+            StatusItem {
+                clickable: true,
+                ..default()
+            },
+            FluxInteraction::Pressed,
+        ));
+
+        world.run_system_once(send_status_item_clicked);
+
+        let events = world.resource::<Events<StatusItemClicked>>();
+        let clicked: Vec<Entity> = events
+            .iter_current_update_events()
+            .map(|event| event.item)
+            .collect();
+        assert_eq!(clicked, vec![clickable]);
+    }
+}
@@ -1,4 +1,9 @@
-use bevy::{ecs::world::Command, prelude::*, ui::RelativeCursorPosition};
+use bevy::{
+    a11y::accesskit::Role,
+    ecs::world::Command,
+    prelude::*,
+    ui::{ContentSize, RelativeCursorPosition},
+};
 
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
@@ -13,16 +18,83 @@ use crate::widgets::menus::{
 
 use super::{
     container::UiContainerExt,
+    docking_zone::PopoutTabToWindow,
     floating_panel::{
         FloatingPanel, FloatingPanelConfig, FloatingPanelLayout, FloatingPanelUpdate,
         UiFloatingPanelExt, UpdateFloatingPanelPanelId,
     },
     label::{LabelConfig, UiLabelExt},
-    panel::{Panel, UiPanelExt},
+    panel::{Panel, PanelKey, UiPanelExt},
     scroll_view::UiScrollViewExt,
     sized_zone::{SizedZonePreUpdate, SizedZoneResizeHandleContainer},
 };
 
+/// Triggered on a tab when it becomes the selected tab of its container
+#[cfg(feature = "observable")]
+#[derive(Event, Clone, Debug)]
+pub struct TabActivated {
+    pub tab: Entity,
+    pub panel: Entity,
+    pub container: Entity,
+    /// The tab previously selected in the container, if it is still open
+    pub previous: Option<Entity>,
+    pub key: Option<String>,
+}
+
+/// Triggered on a tab right before it and its panel are despawned
+#[cfg(feature = "observable")]
+#[derive(Event, Clone, Debug)]
+pub struct TabClosed {
+    pub tab: Entity,
+    pub panel: Entity,
+    pub container: Entity,
+    pub key: Option<String>,
+}
+
+/// Triggered on a tab after it was moved to another tab container
+#[cfg(feature = "observable")]
+#[derive(Event, Clone, Debug)]
+pub struct TabMoved {
+    pub tab: Entity,
+    pub panel: Entity,
+    pub from: Entity,
+    pub to: Entity,
+    pub key: Option<String>,
+}
+
+/// Triggered on a tab right before it is despawned, its panel moved to a new floating panel
+#[cfg(feature = "observable")]
+#[derive(Event, Clone, Debug)]
+pub struct TabPoppedOut {
+    pub tab: Entity,
+    pub panel: Entity,
+    pub container: Entity,
+    pub floating_panel: Entity,
+    pub key: Option<String>,
+}
+
+/// Triggered on a new tab created from the panel of a floating panel docked in a tab container
+#[cfg(feature = "observable")]
+#[derive(Event, Clone, Debug)]
+pub struct TabDocked {
+    pub tab: Entity,
+    pub panel: Entity,
+    pub container: Entity,
+    pub floating_panel: Entity,
+    pub key: Option<String>,
+}
+
+/// Sent to a modified tab instead of closing it from the tab bar.
+///
+/// Observers are expected to either save the content and close the tab with
+/// `force_close_tab`, or leave it open.
+#[cfg(feature = "observable")]
+#[derive(Event, Copy, Clone, Debug)]
+pub struct TabCloseRequested {
+    pub tab: Entity,
+    pub panel: Entity,
+}
+
 pub struct TabContainerPlugin;
 
 impl Plugin for TabContainerPlugin {
@@ -39,22 +111,37 @@ impl Plugin for TabContainerPlugin {
             ComponentThemePlugin::<Tab>::default(),
         ))
         .register_type::<Tab>()
+        .register_type::<TabOverflowMenu>()
         .add_systems(PreUpdate, popout_panel_from_tab.before(SizedZonePreUpdate))
         .add_systems(
             Update,
             (
                 close_tab_on_context_menu_press,
+                close_other_tabs_on_context_menu_press,
+                close_tabs_to_the_right_on_context_menu_press,
+                pin_tab_on_context_menu_press,
                 popout_tab_on_context_menu_press,
+                popout_tab_to_window_on_context_menu_press,
+                reveal_tab_on_overflow_menu_press,
             )
                 .after(MenuItemUpdate)
                 .before(ContextMenuUpdate)
                 .before(TabContainerUpdate),
         )
+        .add_systems(
+            Update,
+            open_tab_overflow_menu
+                .after(ContextMenuUpdate)
+                .before(TabContainerUpdate),
+        )
         .add_systems(
             Update,
             (
+                close_tab_on_middle_click,
                 update_tab_container_on_tab_press,
                 update_tab_container_on_change,
+                update_tab_bar_scroll,
+                update_tab_container_overflow,
                 update_sized_zone_resize_handles_on_tab_drag,
                 handle_tab_dragging,
             )
@@ -62,6 +149,14 @@ impl Plugin for TabContainerPlugin {
                 .in_set(TabContainerUpdate),
         )
         .add_systems(PostUpdate, dock_panel_in_tab_container.before(ThemeUpdate));
+
+        #[cfg(feature = "observable")]
+        app.add_systems(
+            Update,
+            trigger_tab_activated
+                .after(handle_tab_dragging)
+                .in_set(TabContainerUpdate),
+        );
     }
 }
 
@@ -75,6 +170,7 @@ fn dock_panel_in_tab_container(
     >,
     q_floating_panel: Query<&FloatingPanel>,
     q_panel: Query<&Panel>,
+    #[cfg(feature = "observable")] q_panel_key: Query<&PanelKey>,
     mut commands: Commands,
 ) {
     for (container_id, mut tab_container, dock_ref) in &mut q_docking_panels {
@@ -108,7 +204,7 @@ fn dock_panel_in_tab_container(
             ..default()
         };
 
-        commands
+        let tab_id = commands
             .ui_builder(bar_id)
             .container(
                 Tab::frame(format!("Tab [{}]", panel.title())),
@@ -121,17 +217,34 @@ fn dock_panel_in_tab_container(
                                     ..default()
                                 })
                                 .id();
+                            tab.modified_marker = container.spawn(Tab::modified_marker()).id();
                         })
                         .id();
                 },
             )
-            .insert(tab);
+            .insert((tab, AccessibleWidget::new(Role::Tab).named(panel.title())))
+            .id();
 
+        commands
+            .entity(panel_id)
+            .insert(AccessibleWidget::new(Role::TabPanel).named(panel.title()));
         commands.entity(viewport_id).add_child(panel_id);
         commands.entity(dock_ref.floating_panel).despawn_recursive();
 
         tab_container.tab_count += 1;
         tab_container.active = tab_container.tab_count - 1;
+
+        #[cfg(feature = "observable")]
+        commands.trigger_targets(
+            TabDocked {
+                tab: tab_id,
+                panel: panel_id,
+                container: container_id,
+                floating_panel: dock_ref.floating_panel,
+                key: q_panel_key.get(panel_id).ok().map(|key| key.0.clone()),
+            },
+            tab_id,
+        );
     }
 }
 
@@ -140,8 +253,9 @@ fn popout_panel_from_tab(
         (Entity, &Tab, &PopoutPanelFromTabContainer),
         Added<PopoutPanelFromTabContainer>,
     >,
-    q_panel: Query<&Panel>,
+    q_panel: Query<(&Panel, Option<&PanelKey>)>,
     q_parent: Query<&Parent>,
+    q_children: Query<&Children>,
     q_ui_context_root: Query<&UiContextRoot>,
     mut q_tab_container: Query<&mut TabContainer>,
     mut commands: Commands,
@@ -160,20 +274,14 @@ fn popout_panel_from_tab(
             );
             continue;
         };
-        tab_container.tab_count = match tab_container.tab_count > 1 {
-            true => tab_container.tab_count - 1,
-            false => 0,
-        };
-
-        if tab_container.active >= tab_container.tab_count {
-            tab_container.active = match tab_container.tab_count > 0 {
-                true => tab_container.tab_count - 1,
-                false => 0,
-            };
-        }
+        let index = q_children
+            .get(tab.bar)
+            .ok()
+            .and_then(|children| children.iter().position(|child| *child == entity));
+        tab_container.remove_tab(index);
 
         let panel_id = tab.panel;
-        let Ok(panel) = q_panel.get(panel_id) else {
+        let Ok((panel, _)) = q_panel.get(panel_id) else {
             warn!("Cannot pop out panel {}: Not a Panel", panel_id);
             continue;
         };
@@ -185,7 +293,6 @@ fn popout_panel_from_tab(
             .or(q_parent.iter_ancestors(tab_contaier_id).last())
             .unwrap_or(tab_contaier_id);
 
-        commands.entity(entity).despawn_recursive();
         let floating_panel_id = commands
             .ui_builder(root_node)
             .floating_panel(
@@ -203,6 +310,23 @@ fn popout_panel_from_tab(
             )
             .id();
 
+        #[cfg(feature = "observable")]
+        commands.trigger_targets(
+            TabPoppedOut {
+                tab: entity,
+                panel: panel_id,
+                container: tab_contaier_id,
+                floating_panel: floating_panel_id,
+                key: q_panel
+                    .get(panel_id)
+                    .ok()
+                    .and_then(|(_, key)| key)
+                    .map(|key| key.0.clone()),
+            },
+            entity,
+        );
+
+        commands.entity(entity).despawn_recursive();
         commands.entity(panel_id).set_parent(root_node);
         commands.style(panel_id).hide();
         commands
@@ -212,69 +336,171 @@ fn popout_panel_from_tab(
 }
 
 fn close_tab_on_context_menu_press(
-    q_menu_items: Query<(Entity, &CloseTabContextMenu, &MenuItem), Changed<MenuItem>>,
-    q_tab: Query<&Tab>,
-    mut q_tab_container: Query<&mut TabContainer>,
+    q_menu_items: Query<(&CloseTabContextMenu, &MenuItem), Changed<MenuItem>>,
     mut commands: Commands,
 ) {
-    for (entity, context_menu, menu_item) in &q_menu_items {
+    for (context_menu, menu_item) in &q_menu_items {
         if menu_item.interacted() {
-            let Ok(tab_data) = q_tab.get(context_menu.tab) else {
-                warn!(
-                    "Context menu {} refers to missing tab {}",
-                    entity, context_menu.tab
-                );
-                continue;
-            };
+            commands.add(RequestCloseTab {
+                tab: context_menu.tab,
+            });
+        }
+    }
+}
+
+fn close_other_tabs_on_context_menu_press(
+    q_menu_items: Query<(&CloseOtherTabsContextMenu, &MenuItem), Changed<MenuItem>>,
+    mut commands: Commands,
+) {
+    for (context_menu, menu_item) in &q_menu_items {
+        if menu_item.interacted() {
+            commands.add(CloseOtherTabs {
+                tab: context_menu.tab,
+                to_the_right_only: false,
+            });
+        }
+    }
+}
+
+fn close_tabs_to_the_right_on_context_menu_press(
+    q_menu_items: Query<(&CloseTabsToTheRightContextMenu, &MenuItem), Changed<MenuItem>>,
+    mut commands: Commands,
+) {
+    for (context_menu, menu_item) in &q_menu_items {
+        if menu_item.interacted() {
+            commands.add(CloseOtherTabs {
+                tab: context_menu.tab,
+                to_the_right_only: true,
+            });
+        }
+    }
+}
 
-            let tab_contaier_id = tab_data.container;
-            let Ok(mut tab_container) = q_tab_container.get_mut(tab_contaier_id) else {
-                warn!(
-                    "Failed to remove Tab {}: {} is not a TabContainer!",
-                    entity, tab_contaier_id,
-                );
+fn pin_tab_on_context_menu_press(
+    q_menu_items: Query<(&PinTabContextMenu, &MenuItem), Changed<MenuItem>>,
+    q_tab: Query<&Tab>,
+    mut commands: Commands,
+) {
+    for (context_menu, menu_item) in &q_menu_items {
+        if menu_item.interacted() {
+            let Ok(tab) = q_tab.get(context_menu.tab) else {
                 continue;
             };
-            tab_container.tab_count = match tab_container.tab_count > 1 {
-                true => tab_container.tab_count - 1,
-                false => 0,
-            };
 
-            commands.entity(context_menu.tab).despawn_recursive();
-            commands.entity(tab_data.panel).despawn_recursive();
+            commands.add(SetTabPinned {
+                tab: context_menu.tab,
+                pinned: !tab.pinned,
+            });
         }
     }
 }
 
 fn popout_tab_on_context_menu_press(
-    q_menu_items: Query<(Entity, &PopoutTabContextMenu, &MenuItem), Changed<MenuItem>>,
-    q_tab: Query<(&Tab, &GlobalTransform)>,
-    q_node: Query<&Node>,
+    q_menu_items: Query<(&PopoutTabContextMenu, &MenuItem), Changed<MenuItem>>,
     mut commands: Commands,
 ) {
-    for (entity, tab_ref, menu_item) in &q_menu_items {
+    for (tab_ref, menu_item) in &q_menu_items {
         if menu_item.interacted() {
-            let Ok((tab, transform)) = q_tab.get(tab_ref.tab) else {
-                warn!(
-                    "Context menu tab reference {} refers to missing tab {}",
-                    entity, tab_ref.tab
-                );
-                continue;
-            };
+            commands.add(PopoutTab { tab: tab_ref.tab });
+        }
+    }
+}
 
-            let Ok(container) = q_node.get(tab.container) else {
-                warn!(
-                    "Context menu tab reference {} refers to a tab without a container {}",
-                    entity, tab_ref.tab
-                );
-                continue;
-            };
+fn popout_tab_to_window_on_context_menu_press(
+    q_menu_items: Query<(&PopoutTabToWindowContextMenu, &MenuItem), Changed<MenuItem>>,
+    mut commands: Commands,
+) {
+    for (tab_ref, menu_item) in &q_menu_items {
+        if menu_item.interacted() {
+            commands.add(PopoutTabToWindow { tab: tab_ref.tab });
+        }
+    }
+}
+
+fn reveal_tab_on_overflow_menu_press(
+    q_menu_items: Query<(&TabOverflowMenuItem, &MenuItem), Changed<MenuItem>>,
+    q_tab: Query<&Tab>,
+    q_tab_container: Query<&TabContainer>,
+    q_node: Query<(&Node, &GlobalTransform)>,
+    mut q_tab_bar: Query<&mut TabBar>,
+    mut commands: Commands,
+) {
+    for (overflow_item, menu_item) in &q_menu_items {
+        if !menu_item.interacted() {
+            continue;
+        }
+
+        commands.add(FocusTab {
+            tab: overflow_item.tab,
+        });
+
+        let Ok(tab) = q_tab.get(overflow_item.tab) else {
+            continue;
+        };
+        let Ok(container) = q_tab_container.get(tab.container) else {
+            continue;
+        };
+        let Ok(mut tab_bar) = q_tab_bar.get_mut(container.bar) else {
+            continue;
+        };
+        let (Ok((bar_node, bar_transform)), Ok((tab_node, tab_transform))) =
+            (q_node.get(container.bar), q_node.get(overflow_item.tab))
+        else {
+            continue;
+        };
+        let button_width = q_node
+            .get(container.overflow_button)
+            .map(|(node, _)| node.size().x)
+            .unwrap_or_default();
+
+        // Tab edges in bar content space, i.e. as if the bar wasn't scrolled
+        let bar_left = bar_transform.translation().x - (bar_node.size().x / 2.);
+        let tab_left = tab_transform.translation().x - (tab_node.size().x / 2.) - bar_left
+            + tab_bar.scroll_offset;
+        let tab_right = tab_left + tab_node.size().x;
+        let visible_width = (bar_node.size().x - button_width).max(0.);
+
+        let scroll_offset = if tab_left < tab_bar.scroll_offset {
+            tab_left
+        } else if tab_right > tab_bar.scroll_offset + visible_width {
+            tab_right - visible_width
+        } else {
+            tab_bar.scroll_offset
+        };
+
+        if scroll_offset != tab_bar.scroll_offset {
+            tab_bar.scroll_offset = scroll_offset;
+        }
+    }
+}
+
+type ChangedTabOverflowButton = (With<TabOverflowMenu>, Changed<FluxInteraction>);
+
+fn open_tab_overflow_menu(
+    mut q_overflow_buttons: Query<
+        (&FluxInteraction, &mut GenerateContextMenu),
+        ChangedTabOverflowButton,
+    >,
+) {
+    for (interaction, mut gen_menu) in &mut q_overflow_buttons {
+        if *interaction == FluxInteraction::Released && !gen_menu.is_open() {
+            gen_menu.open();
+        }
+    }
+}
+
+fn close_tab_on_middle_click(
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    q_tabs: Query<(Entity, &Interaction), With<Tab>>,
+    mut commands: Commands,
+) {
+    if !r_mouse.just_pressed(MouseButton::Middle) {
+        return;
+    }
 
-            let size = container.size() * 0.8;
-            let position = transform.translation().truncate();
-            commands
-                .entity(tab_ref.tab)
-                .insert(PopoutPanelFromTabContainer { size, position });
+    for (entity, interaction) in &q_tabs {
+        if *interaction == Interaction::Hovered {
+            commands.add(RequestCloseTab { tab: entity });
         }
     }
 }
@@ -333,6 +559,186 @@ fn update_tab_container_on_change(
     }
 }
 
+fn update_tab_container_overflow(
+    q_tab_containers: Query<(Entity, &TabContainer, Option<&PseudoStates>)>,
+    q_node: Query<(&Node, &GlobalTransform)>,
+    q_children: Query<&Children>,
+    q_tab: Query<&Tab>,
+    q_panel: Query<&Panel>,
+    mut q_overflow_menu: Query<&mut TabOverflowMenu>,
+    mut commands: Commands,
+) {
+    for (entity, tab_container, pseudo_states) in &q_tab_containers {
+        let Ok((bar_node, bar_transform)) = q_node.get(tab_container.bar) else {
+            continue;
+        };
+        let Ok(children) = q_children.get(tab_container.bar) else {
+            continue;
+        };
+
+        let tabs: Vec<(Entity, &Tab, &Node, &GlobalTransform)> = children
+            .iter()
+            .filter_map(|child| {
+                let tab = q_tab.get(*child).ok()?;
+                let (node, transform) = q_node.get(*child).ok()?;
+                Some((*child, tab, node, transform))
+            })
+            .collect();
+
+        // Dragged tabs are taken out of the flow, their placeholder takes up the space
+        if tabs.iter().any(|(_, tab, _, _)| tab.placeholder.is_some()) {
+            continue;
+        }
+
+        let bar_width = bar_node.size().x;
+        let overflowing = tabs
+            .iter()
+            .map(|(_, _, node, _)| node.size().x)
+            .sum::<f32>()
+            > bar_width + 0.5;
+
+        if overflowing != pseudo_states.is_some_and(|states| states.has(&PseudoState::OverflowX)) {
+            if overflowing {
+                commands
+                    .entity(entity)
+                    .add_pseudo_state(PseudoState::OverflowX);
+            } else {
+                commands
+                    .entity(entity)
+                    .remove_pseudo_state(PseudoState::OverflowX);
+            }
+        }
+
+        let Ok(mut overflow_menu) = q_overflow_menu.get_mut(tab_container.overflow_button) else {
+            continue;
+        };
+
+        let hidden: Vec<TabOverflowEntry> = if overflowing {
+            let button_width = q_node
+                .get(tab_container.overflow_button)
+                .map(|(node, _)| node.size().x)
+                .unwrap_or_default();
+            let visible_until =
+                bar_transform.translation().x + (bar_width / 2.) - button_width + 0.5;
+
+            let visible_from = bar_transform.translation().x - (bar_width / 2.) - 0.5;
+
+            tabs.iter()
+                .filter(|(_, _, node, transform)| {
+                    transform.translation().x - (node.size().x / 2.) < visible_from
+                        || transform.translation().x + (node.size().x / 2.) > visible_until
+                })
+                .map(|(entity, tab, _, _)| TabOverflowEntry {
+                    tab: *entity,
+                    title: q_panel
+                        .get(tab.panel)
+                        .map(|panel| panel.title())
+                        .unwrap_or_default(),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        if overflow_menu.hidden != hidden {
+            overflow_menu.hidden = hidden;
+        }
+    }
+}
+
+fn update_tab_bar_scroll(
+    mut q_tab_bars: Query<(Entity, &mut TabBar, &Node)>,
+    q_tab_container: Query<&TabContainer>,
+    q_children: Query<&Children>,
+    q_tab: Query<&Tab>,
+    q_node: Query<(&Node, &Style), Without<TabBar>>,
+    mut commands: Commands,
+) {
+    for (entity, mut tab_bar, bar_node) in &mut q_tab_bars {
+        let Ok(children) = q_children.get(entity) else {
+            continue;
+        };
+
+        let is_dragged =
+            |child: &Entity| q_tab.get(*child).is_ok_and(|tab| tab.placeholder.is_some());
+        let dragging = children.iter().any(is_dragged);
+
+        // The range is only known while all tabs are in the flow
+        if !dragging {
+            let content_width: f32 = children
+                .iter()
+                .filter_map(|child| q_node.get(*child).ok())
+                .map(|(node, _)| node.size().x)
+                .sum();
+            let bar_width = bar_node.size().x;
+            let max_offset = if content_width > bar_width + 0.5 {
+                let button_width = q_tab_container
+                    .get(tab_bar.container)
+                    .ok()
+                    .and_then(|container| q_node.get(container.overflow_button).ok())
+                    .map(|(node, _)| node.size().x)
+                    .unwrap_or_default();
+                (content_width - bar_width + button_width).max(0.)
+            } else {
+                0.
+            };
+
+            let scroll_offset = tab_bar.scroll_offset.clamp(0., max_offset);
+            if scroll_offset != tab_bar.scroll_offset {
+                tab_bar.scroll_offset = scroll_offset;
+            }
+        }
+
+        let left = match tab_bar.scroll_offset > 0. {
+            true => Val::Px(-tab_bar.scroll_offset),
+            false => Val::Auto,
+        };
+
+        for child in children.iter().filter(|child| !is_dragged(child)) {
+            let Ok((_, style)) = q_node.get(*child) else {
+                continue;
+            };
+
+            if style.left != left {
+                commands.style_unchecked(*child).left(left);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "observable")]
+fn trigger_tab_activated(
+    q_tabs: Query<(Entity, &Tab, &PseudoStates), Changed<PseudoStates>>,
+    q_tab: Query<(), With<Tab>>,
+    q_panel_key: Query<&PanelKey>,
+    mut active_tabs: Local<bevy::utils::HashMap<Entity, Entity>>,
+    mut commands: Commands,
+) {
+    for (entity, tab, pseudo_states) in &q_tabs {
+        if !pseudo_states.has(&PseudoState::Selected) {
+            continue;
+        }
+
+        let previous = active_tabs.insert(tab.container, entity);
+        if previous == Some(entity) {
+            continue;
+        }
+
+        commands.trigger_targets(
+            TabActivated {
+                tab: entity,
+                panel: tab.panel,
+                container: tab.container,
+                previous: previous.filter(|previous| q_tab.contains(*previous)),
+                key: q_panel_key.get(tab.panel).ok().map(|key| key.0.clone()),
+            },
+            entity,
+        );
+    }
+
+    active_tabs.retain(|_, tab| q_tab.contains(*tab));
+}
+
 // TODO: Replace this when focus management is implemented
 fn update_sized_zone_resize_handles_on_tab_drag(
     q_accepted_types: Query<&Draggable, (With<Tab>, Changed<Draggable>)>,
@@ -460,7 +866,7 @@ fn handle_tab_dragging(
                         placeholder_index = i;
                         continue;
                     }
-                    let Ok(_) = q_tab.get(entity) else {
+                    let Ok(_) = q_tab.get(*child) else {
                         continue;
                     };
                     let Ok((transform, interaction)) = q_transform.get(*child) else {
@@ -525,17 +931,20 @@ fn handle_tab_dragging(
                     continue;
                 };
 
+                let index = children
+                    .iter()
+                    .take(placeholder_index)
+                    .filter(|child| **child != entity && q_tab.contains(**child))
+                    .count();
+
                 commands
                     .style_unchecked(entity)
                     .position_type(PositionType::Relative)
                     .left(Val::Auto)
                     .z_index(ZIndex::Local(0));
 
-                commands
-                    .entity(container.bar)
-                    .insert_children(placeholder_index, &[entity]);
-
                 commands.entity(placeholder).despawn_recursive();
+                commands.add(ReorderTab { tab: entity, index });
 
                 let mut tab = q_tab.get_mut(entity).unwrap();
                 tab.placeholder = None;
@@ -608,74 +1017,223 @@ impl Default for PopoutTabContextMenu {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
-#[reflect(Component, ContextMenuGenerator)]
-pub struct Tab {
-    container: Entity,
-    bar: Entity,
-    panel: Entity,
-    label_container: Entity,
-    label: Entity,
-    placeholder: Option<Entity>,
-    original_index: Option<usize>,
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct PopoutTabToWindowContextMenu {
+    tab: Entity,
 }
 
-impl Default for Tab {
+impl Default for PopoutTabToWindowContextMenu {
     fn default() -> Self {
         Self {
-            container: Entity::PLACEHOLDER,
-            bar: Entity::PLACEHOLDER,
-            panel: Entity::PLACEHOLDER,
-            label_container: Entity::PLACEHOLDER,
-            label: Entity::PLACEHOLDER,
-            placeholder: None,
-            original_index: None,
+            tab: Entity::PLACEHOLDER,
         }
     }
 }
 
-impl ContextMenuGenerator for Tab {
-    fn build_context_menu(&self, context: Entity, container: &mut UiBuilder<ContextMenu>) {
-        let icons = ThemeData::default().icons;
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct CloseOtherTabsContextMenu {
+    tab: Entity,
+}
 
-        container
-            .menu_item(MenuItemConfig {
-                name: "Close Tab".into(),
-                leading_icon: icons.close,
-                ..default()
-            })
-            .insert(CloseTabContextMenu { tab: context });
-        container
-            .menu_item(MenuItemConfig {
-                name: "Popout Tab".into(),
-                trailing_icon: icons.open_in_new,
-                ..default()
-            })
-            .insert(PopoutTabContextMenu { tab: context });
+impl Default for CloseOtherTabsContextMenu {
+    fn default() -> Self {
+        Self {
+            tab: Entity::PLACEHOLDER,
+        }
     }
+}
 
-    fn placement_index(&self) -> usize {
-        0
-    }
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct CloseTabsToTheRightContextMenu {
+    tab: Entity,
 }
 
-impl UiContext for Tab {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            Tab::LABEL_CONTAINER => Ok(self.label_container),
-            Tab::LABEL => Ok(self.label),
-            Tab::PANEL => Ok(self.panel),
-            _ => Err(format!(
-                "{} doesn't exist for Tab. Possible contexts: {:?}",
-                target,
-                Vec::from_iter(self.contexts())
-            )),
+impl Default for CloseTabsToTheRightContextMenu {
+    fn default() -> Self {
+        Self {
+            tab: Entity::PLACEHOLDER,
         }
     }
-
-    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
-        [Tab::LABEL_CONTAINER, Tab::LABEL, Tab::PANEL].into_iter()
-    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct PinTabContextMenu {
+    tab: Entity,
+}
+
+impl Default for PinTabContextMenu {
+    fn default() -> Self {
+        Self {
+            tab: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct TabOverflowMenuItem {
+    tab: Entity,
+}
+
+impl Default for TabOverflowMenuItem {
+    fn default() -> Self {
+        Self {
+            tab: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct TabOverflowEntry {
+    tab: Entity,
+    title: String,
+}
+
+/// Lists the tabs that don't fit in the tab bar, opened from the tab container's overflow button
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, ContextMenuGenerator)]
+pub struct TabOverflowMenu {
+    hidden: Vec<TabOverflowEntry>,
+}
+
+impl TabOverflowMenu {
+    pub fn hidden_tabs(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.hidden.iter().map(|entry| entry.tab)
+    }
+}
+
+impl ContextMenuGenerator for TabOverflowMenu {
+    fn build_context_menu(&self, _context: Entity, container: &mut UiBuilder<ContextMenu>) {
+        for entry in &self.hidden {
+            container
+                .menu_item(MenuItemConfig {
+                    name: entry.title.clone(),
+                    ..default()
+                })
+                .insert(TabOverflowMenuItem { tab: entry.tab });
+        }
+    }
+
+    fn placement_index(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, ContextMenuGenerator)]
+pub struct Tab {
+    container: Entity,
+    bar: Entity,
+    panel: Entity,
+    label_container: Entity,
+    label: Entity,
+    modified_marker: Entity,
+    placeholder: Option<Entity>,
+    original_index: Option<usize>,
+    pinned: bool,
+    modified: bool,
+}
+
+impl Default for Tab {
+    fn default() -> Self {
+        Self {
+            container: Entity::PLACEHOLDER,
+            bar: Entity::PLACEHOLDER,
+            panel: Entity::PLACEHOLDER,
+            label_container: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+            modified_marker: Entity::PLACEHOLDER,
+            placeholder: None,
+            original_index: None,
+            pinned: false,
+            modified: false,
+        }
+    }
+}
+
+impl ContextMenuGenerator for Tab {
+    fn build_context_menu(&self, context: Entity, container: &mut UiBuilder<ContextMenu>) {
+        let icons = ThemeData::default().icons;
+
+        if !self.pinned {
+            container
+                .menu_item(MenuItemConfig {
+                    name: "Close Tab".into(),
+                    leading_icon: icons.close,
+                    ..default()
+                })
+                .insert(CloseTabContextMenu { tab: context });
+        }
+        container
+            .menu_item(MenuItemConfig {
+                name: "Close Other Tabs".into(),
+                ..default()
+            })
+            .insert(CloseOtherTabsContextMenu { tab: context });
+        container
+            .menu_item(MenuItemConfig {
+                name: "Close Tabs to the Right".into(),
+                ..default()
+            })
+            .insert(CloseTabsToTheRightContextMenu { tab: context });
+        container
+            .menu_item(MenuItemConfig {
+                name: match self.pinned {
+                    true => "Unpin Tab".into(),
+                    false => "Pin Tab".into(),
+                },
+                ..default()
+            })
+            .insert(PinTabContextMenu { tab: context });
+        container
+            .menu_item(MenuItemConfig {
+                name: "Popout Tab".into(),
+                trailing_icon: icons.open_in_new.clone(),
+                ..default()
+            })
+            .insert(PopoutTabContextMenu { tab: context });
+        container
+            .menu_item(MenuItemConfig {
+                name: "Open in New Window".into(),
+                trailing_icon: icons.new_window,
+                ..default()
+            })
+            .insert(PopoutTabToWindowContextMenu { tab: context });
+    }
+
+    fn placement_index(&self) -> usize {
+        0
+    }
+}
+
+impl UiContext for Tab {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            Tab::LABEL_CONTAINER => Ok(self.label_container),
+            Tab::LABEL => Ok(self.label),
+            Tab::MODIFIED_MARKER => Ok(self.modified_marker),
+            Tab::PANEL => Ok(self.panel),
+            _ => Err(format!(
+                "{} doesn't exist for Tab. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [
+            Tab::LABEL_CONTAINER,
+            Tab::LABEL,
+            Tab::MODIFIED_MARKER,
+            Tab::PANEL,
+        ]
+        .into_iter()
+    }
 }
 
 impl DefaultTheme for Tab {
@@ -687,13 +1245,42 @@ impl DefaultTheme for Tab {
 impl Tab {
     pub const LABEL_CONTAINER: &'static str = "LabelContainer";
     pub const LABEL: &'static str = "Label";
+    pub const MODIFIED_MARKER: &'static str = "ModifiedMarker";
     pub const PANEL: &'static str = "Panel";
 
+    pub fn container_id(&self) -> Entity {
+        self.container
+    }
+
+    pub fn panel_id(&self) -> Entity {
+        self.panel
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    pub fn is_modified(&self) -> bool {
+        self.modified
+    }
+
     pub fn theme() -> Theme<Tab> {
-        let base_theme = PseudoTheme::deferred(None, Tab::primary_style);
+        let base_theme = PseudoTheme::deferred(None, Tab::primary_style).depends_on(
+            ThemeDataSections::COLORS
+                | ThemeDataSections::SPACING
+                | ThemeDataSections::TEXT
+                | ThemeDataSections::ANIMATIONS,
+        );
         let selected_theme =
-            PseudoTheme::deferred(vec![PseudoState::Selected], Tab::selected_style);
-        Theme::new(vec![base_theme, selected_theme])
+            PseudoTheme::deferred(vec![PseudoState::Selected], Tab::selected_style).depends_on(
+                ThemeDataSections::COLORS
+                    | ThemeDataSections::SPACING
+                    | ThemeDataSections::ANIMATIONS,
+            );
+        let modified_theme =
+            PseudoTheme::deferred(vec![PseudoState::Modified], Tab::modified_style)
+                .depends_on(ThemeDataSections::NONE);
+        Theme::new(vec![base_theme, selected_theme, modified_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -704,6 +1291,7 @@ impl Tab {
             .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
 
         style_builder
+            .flex_shrink(0.)
             .padding(UiRect::bottom(Val::Px(theme_spacing.gaps.small)))
             .border(UiRect::right(Val::Px(theme_spacing.borders.small)))
             .border_color(colors.accent(Accent::OutlineVariant))
@@ -734,12 +1322,27 @@ impl Tab {
             .sized_font(font)
             .font_color(colors.on(On::Surface));
 
+        style_builder
+            .switch_target(Tab::MODIFIED_MARKER)
+            .display(Display::None)
+            .align_self(AlignSelf::Center)
+            .size(Val::Px(theme_spacing.gaps.small))
+            .margin(UiRect::left(Val::Px(theme_spacing.gaps.small)))
+            .border_radius(BorderRadius::MAX)
+            .background_color(colors.on(On::SurfaceVariant));
+
         style_builder
             .switch_target(Tab::PANEL)
             .position_type(PositionType::Absolute)
             .visibility(Visibility::Hidden);
     }
 
+    fn modified_style(style_builder: &mut StyleBuilder, _theme_data: &ThemeData) {
+        style_builder
+            .switch_target(Tab::MODIFIED_MARKER)
+            .display(Display::Flex);
+    }
+
     fn selected_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         let theme_spacing = theme_data.spacing;
         let colors = theme_data.colors();
@@ -795,6 +1398,10 @@ impl Tab {
             ]),
         )
     }
+
+    fn modified_marker() -> impl Bundle {
+        (Name::new("Modified Marker"), NodeBundle::default())
+    }
 }
 
 #[derive(Component)]
@@ -822,6 +1429,454 @@ impl Command for IncrementTabCount {
     }
 }
 
+#[cfg(feature = "observable")]
+fn panel_key(world: &World, panel: Entity) -> Option<String> {
+    world.get::<PanelKey>(panel).map(|key| key.0.clone())
+}
+
+fn tab_index(world: &World, bar: Entity, tab: Entity) -> Option<usize> {
+    world
+        .get::<Children>(bar)
+        .and_then(|children| children.iter().position(|child| *child == tab))
+}
+
+/// Removes the tab and despawns its panel
+pub(crate) struct CloseTab {
+    pub tab: Entity,
+}
+
+impl Command for CloseTab {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab).cloned() else {
+            warn!("Failed to close tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        let index = tab_index(world, tab.bar, self.tab);
+        if let Some(mut container) = world.get_mut::<TabContainer>(tab.container) {
+            container.remove_tab(index);
+        } else {
+            warn!(
+                "Failed to remove Tab {}: {} is not a TabContainer!",
+                self.tab, tab.container,
+            );
+        }
+
+        #[cfg(feature = "observable")]
+        world.trigger_targets(
+            TabClosed {
+                tab: self.tab,
+                panel: tab.panel,
+                container: tab.container,
+                key: panel_key(world, tab.panel),
+            },
+            self.tab,
+        );
+
+        world.entity_mut(self.tab).despawn_recursive();
+        if let Some(panel) = world.get_entity_mut(tab.panel) {
+            panel.despawn_recursive();
+        }
+    }
+}
+
+/// Closes the tab the way the tab bar does: pinned tabs are kept, and modified tabs
+/// only receive a `TabCloseRequested` trigger
+pub(crate) struct RequestCloseTab {
+    pub tab: Entity,
+}
+
+impl Command for RequestCloseTab {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab) else {
+            warn!("Failed to close tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        if tab.pinned {
+            return;
+        }
+
+        #[cfg(feature = "observable")]
+        if tab.modified {
+            let panel = tab.panel;
+            world.trigger_targets(
+                TabCloseRequested {
+                    tab: self.tab,
+                    panel,
+                },
+                self.tab,
+            );
+            return;
+        }
+
+        CloseTab { tab: self.tab }.apply(world);
+    }
+}
+
+/// Requests to close all other unpinned tabs of the tab's container, or only the ones after it
+pub(crate) struct CloseOtherTabs {
+    pub tab: Entity,
+    pub to_the_right_only: bool,
+}
+
+impl Command for CloseOtherTabs {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab) else {
+            warn!("Failed to close tabs next to {}: Not a Tab", self.tab);
+            return;
+        };
+
+        let Some(children) = world.get::<Children>(tab.bar) else {
+            error!("Tab {} isn't a child of its tab container bar", self.tab);
+            return;
+        };
+
+        let Some(index) = children.iter().position(|child| *child == self.tab) else {
+            error!("Tab {} isn't a child of its tab container bar", self.tab);
+            return;
+        };
+
+        let to_close: Vec<Entity> = children
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index && (!self.to_the_right_only || *i > index))
+            .map(|(_, child)| *child)
+            .filter(|child| world.get::<Tab>(*child).is_some())
+            .collect();
+
+        for tab in to_close {
+            RequestCloseTab { tab }.apply(world);
+        }
+    }
+}
+
+/// Moves the tab to `index` among the tabs of its bar, keeping pinned tabs in front.
+/// The selection stays on the same tab.
+pub(crate) struct ReorderTab {
+    pub tab: Entity,
+    pub index: usize,
+}
+
+impl Command for ReorderTab {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab).cloned() else {
+            warn!("Failed to reorder tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        if tab.placeholder.is_some() {
+            warn!("Failed to reorder tab {}: Tab is being dragged", self.tab);
+            return;
+        }
+
+        let Some(container) = world.get::<TabContainer>(tab.container).copied() else {
+            warn!(
+                "Failed to reorder tab {}: {} is not a TabContainer!",
+                self.tab, tab.container
+            );
+            return;
+        };
+
+        let Some(children) = world.get::<Children>(tab.bar) else {
+            error!("Tab {} isn't a child of its tab container bar", self.tab);
+            return;
+        };
+
+        let active = children.get(container.active).copied();
+        let mut tabs: Vec<Entity> = children
+            .iter()
+            .filter(|child| **child != self.tab && world.get::<Tab>(**child).is_some())
+            .copied()
+            .collect();
+        let pinned = tabs
+            .iter()
+            .filter(|child| world.get::<Tab>(**child).is_some_and(|tab| tab.pinned))
+            .count();
+
+        let index = match tab.pinned {
+            true => self.index.min(pinned),
+            false => self.index.clamp(pinned, tabs.len()),
+        };
+        tabs.insert(index, self.tab);
+
+        world.entity_mut(tab.bar).insert_children(0, &tabs);
+
+        let Some(active) = active else {
+            return;
+        };
+
+        // Safe unwrap: checked above
+        let Some(active_index) = world
+            .get::<Children>(tab.bar)
+            .unwrap()
+            .iter()
+            .position(|child| *child == active)
+        else {
+            return;
+        };
+
+        let mut container = world.get_mut::<TabContainer>(tab.container).unwrap();
+        if container.active != active_index {
+            container.active = active_index;
+        }
+    }
+}
+
+/// Pins or unpins the tab, moving it to the end of the pinned tabs
+pub(crate) struct SetTabPinned {
+    pub tab: Entity,
+    pub pinned: bool,
+}
+
+impl Command for SetTabPinned {
+    fn apply(self, world: &mut World) {
+        let Some(mut tab) = world.get_mut::<Tab>(self.tab) else {
+            warn!("Failed to pin tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        if tab.pinned == self.pinned {
+            return;
+        }
+
+        tab.pinned = self.pinned;
+        let index = match self.pinned {
+            true => usize::MAX,
+            false => 0,
+        };
+
+        if self.pinned {
+            world
+                .commands()
+                .entity(self.tab)
+                .add_pseudo_state(PseudoState::Pinned);
+        } else {
+            world
+                .commands()
+                .entity(self.tab)
+                .remove_pseudo_state(PseudoState::Pinned);
+        }
+
+        ReorderTab {
+            tab: self.tab,
+            index,
+        }
+        .apply(world);
+    }
+}
+
+/// Marks the tab as having unsaved changes, or clears the mark
+pub(crate) struct SetTabModified {
+    pub tab: Entity,
+    pub modified: bool,
+}
+
+impl Command for SetTabModified {
+    fn apply(self, world: &mut World) {
+        let Some(mut tab) = world.get_mut::<Tab>(self.tab) else {
+            warn!("Failed to mark tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        if tab.modified == self.modified {
+            return;
+        }
+
+        tab.modified = self.modified;
+        if self.modified {
+            world
+                .commands()
+                .entity(self.tab)
+                .add_pseudo_state(PseudoState::Modified);
+        } else {
+            world
+                .commands()
+                .entity(self.tab)
+                .remove_pseudo_state(PseudoState::Modified);
+        }
+    }
+}
+
+/// Pops the tab out into a floating panel, placed over the tab
+pub(crate) struct PopoutTab {
+    pub tab: Entity,
+}
+
+impl Command for PopoutTab {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab) else {
+            warn!("Failed to pop out tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        let Some(container) = world.get::<Node>(tab.container) else {
+            warn!(
+                "Failed to pop out tab {}: Missing tab container {}",
+                self.tab, tab.container
+            );
+            return;
+        };
+
+        let size = container.size() * 0.8;
+        let position = world
+            .get::<GlobalTransform>(self.tab)
+            .map(|transform| transform.translation().truncate())
+            .unwrap_or_default();
+
+        world
+            .entity_mut(self.tab)
+            .insert(PopoutPanelFromTabContainer { size, position });
+    }
+}
+
+/// Moves the tab and its panel to the end of another tab container and selects it
+pub(crate) struct MoveTab {
+    pub tab: Entity,
+    pub container: Entity,
+}
+
+impl Command for MoveTab {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab).cloned() else {
+            warn!("Failed to move tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        if tab.container == self.container {
+            return;
+        }
+
+        if tab.placeholder.is_some() {
+            warn!("Failed to move tab {}: Tab is being dragged", self.tab);
+            return;
+        }
+
+        let Some(target) = world.get::<TabContainer>(self.container).copied() else {
+            warn!(
+                "Failed to move tab {}: {} is not a TabContainer!",
+                self.tab, self.container
+            );
+            return;
+        };
+
+        let source_index = tab_index(world, tab.bar, self.tab);
+        if let Some(mut source) = world.get_mut::<TabContainer>(tab.container) {
+            source.remove_tab(source_index);
+        }
+
+        world.entity_mut(target.bar).add_child(self.tab);
+        world.entity_mut(target.viewport).add_child(tab.panel);
+
+        // Safe unwrap: the tab was just added to the bar
+        let index = world
+            .get::<Children>(target.bar)
+            .unwrap()
+            .iter()
+            .position(|child| *child == self.tab)
+            .unwrap();
+
+        let mut moved_tab = world.get_mut::<Tab>(self.tab).unwrap();
+        moved_tab.container = self.container;
+        moved_tab.bar = target.bar;
+
+        let mut container = world.get_mut::<TabContainer>(self.container).unwrap();
+        container.tab_count += 1;
+        container.active = index;
+
+        // Moved tabs go last, but pinned ones stay in front
+        ReorderTab {
+            tab: self.tab,
+            index: usize::MAX,
+        }
+        .apply(world);
+
+        #[cfg(feature = "observable")]
+        world.trigger_targets(
+            TabMoved {
+                tab: self.tab,
+                panel: tab.panel,
+                from: tab.container,
+                to: self.container,
+                key: panel_key(world, tab.panel),
+            },
+            self.tab,
+        );
+    }
+}
+
+/// Selects the tab in its tab container
+pub(crate) struct FocusTab {
+    pub tab: Entity,
+}
+
+impl Command for FocusTab {
+    fn apply(self, world: &mut World) {
+        let Some(tab) = world.get::<Tab>(self.tab) else {
+            warn!("Failed to focus tab {}: Not a Tab", self.tab);
+            return;
+        };
+
+        let container_id = tab.container;
+        let Some(index) = world
+            .get::<Children>(tab.bar)
+            .and_then(|tabs| tabs.iter().position(|child| *child == self.tab))
+        else {
+            error!("Tab {} isn't a child of its tab container bar", self.tab);
+            return;
+        };
+
+        let Some(mut container) = world.get_mut::<TabContainer>(container_id) else {
+            warn!(
+                "Failed to focus tab {}: {} is not a TabContainer!",
+                self.tab, container_id
+            );
+            return;
+        };
+
+        if container.active != index {
+            container.active = index;
+        }
+    }
+}
+
+pub trait TabCommandsExt {
+    /// Closes the tab as if closed from the tab bar. Pinned tabs are kept open, and modified
+    /// tabs receive a `TabCloseRequested` trigger instead.
+    fn close_tab(&mut self, tab: Entity) -> &mut Self;
+
+    /// Closes the tab and despawns its panel, even if it is pinned or modified
+    fn force_close_tab(&mut self, tab: Entity) -> &mut Self;
+
+    /// Pins or unpins the tab. Pinned tabs are kept in front of the others and cannot be closed.
+    fn pin_tab(&mut self, tab: Entity, pinned: bool) -> &mut Self;
+
+    /// Shows or hides the modified marker of the tab, and guards it from being closed
+    fn mark_tab_modified(&mut self, tab: Entity, modified: bool) -> &mut Self;
+}
+
+impl TabCommandsExt for Commands<'_, '_> {
+    fn close_tab(&mut self, tab: Entity) -> &mut Self {
+        self.add(RequestCloseTab { tab });
+        self
+    }
+
+    fn force_close_tab(&mut self, tab: Entity) -> &mut Self {
+        self.add(CloseTab { tab });
+        self
+    }
+
+    fn pin_tab(&mut self, tab: Entity, pinned: bool) -> &mut Self {
+        self.add(SetTabPinned { tab, pinned });
+        self
+    }
+
+    fn mark_tab_modified(&mut self, tab: Entity, modified: bool) -> &mut Self {
+        self.add(SetTabModified { tab, modified });
+        self
+    }
+}
+
 #[derive(Component)]
 #[component(storage = "SparseSet")]
 struct DockFloatingPanel {
@@ -832,12 +1887,14 @@ struct DockFloatingPanel {
 #[reflect(Component)]
 pub struct TabBar {
     container: Entity,
+    scroll_offset: f32,
 }
 
 impl Default for TabBar {
     fn default() -> Self {
         Self {
             container: Entity::PLACEHOLDER,
+            scroll_offset: 0.,
         }
     }
 }
@@ -846,6 +1903,11 @@ impl TabBar {
     pub fn container_id(&self) -> Entity {
         self.container
     }
+
+    /// How far the tabs are scrolled to the left, in logical pixels
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
 }
 
 #[derive(Component, Debug, Reflect)]
@@ -876,7 +1938,8 @@ impl DefaultTheme for TabPlaceholder {
 
 impl TabPlaceholder {
     pub fn theme() -> Theme<TabPlaceholder> {
-        let base_theme = PseudoTheme::deferred_context(None, TabPlaceholder::primary_style);
+        let base_theme = PseudoTheme::deferred_context(None, TabPlaceholder::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::ANIMATIONS);
         Theme::new(vec![base_theme])
     }
 
@@ -930,6 +1993,7 @@ pub struct TabContainer {
     active: usize,
     bar: Entity,
     viewport: Entity,
+    overflow_button: Entity,
     tab_count: usize,
 }
 
@@ -940,6 +2004,7 @@ impl Default for TabContainer {
             tab_count: 0,
             bar: Entity::PLACEHOLDER,
             viewport: Entity::PLACEHOLDER,
+            overflow_button: Entity::PLACEHOLDER,
         }
     }
 }
@@ -954,6 +2019,7 @@ impl UiContext for TabContainer {
     fn get(&self, target: &str) -> Result<Entity, String> {
         match target {
             TabContainer::TAB_BAR => Ok(self.bar),
+            TabContainer::OVERFLOW_BUTTON => Ok(self.overflow_button),
             _ => Err(format!(
                 "{} doesn't exist for TabContainer. Possible contexts: {:?}",
                 target,
@@ -963,17 +2029,22 @@ impl UiContext for TabContainer {
     }
 
     fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
-        [TabContainer::TAB_BAR].into_iter()
+        [TabContainer::TAB_BAR, TabContainer::OVERFLOW_BUTTON].into_iter()
     }
 }
 
 impl TabContainer {
     pub const TAB_BAR: &'static str = "TabBar";
+    pub const OVERFLOW_BUTTON: &'static str = "OverflowButton";
 
     pub fn bar_id(&self) -> Entity {
         self.bar
     }
 
+    pub fn overflow_button_id(&self) -> Entity {
+        self.overflow_button
+    }
+
     pub fn tab_count(&self) -> usize {
         self.tab_count
     }
@@ -982,9 +2053,28 @@ impl TabContainer {
         self.active = active;
     }
 
+    /// Updates the tab count after the tab at `index` is removed from the bar,
+    /// keeping the same tab active if it was after the removed one
+    fn remove_tab(&mut self, index: Option<usize>) {
+        self.tab_count = self.tab_count.saturating_sub(1);
+        if index.is_some_and(|index| index < self.active) {
+            self.active -= 1;
+        } else if self.active >= self.tab_count {
+            self.active = self.tab_count.saturating_sub(1);
+        }
+    }
+
     pub fn theme() -> Theme<TabContainer> {
-        let base_theme = PseudoTheme::deferred(None, TabContainer::primary_style);
-        Theme::new(vec![base_theme])
+        let base_theme = PseudoTheme::deferred(None, TabContainer::primary_style).depends_on(
+            ThemeDataSections::COLORS
+                | ThemeDataSections::SPACING
+                | ThemeDataSections::ICONS
+                | ThemeDataSections::ANIMATIONS,
+        );
+        let overflow_theme =
+            PseudoTheme::deferred(vec![PseudoState::OverflowX], TabContainer::overflow_style)
+                .depends_on(ThemeDataSections::NONE);
+        Theme::new(vec![base_theme, overflow_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -1004,6 +2094,38 @@ impl TabContainer {
             .border(UiRect::bottom(Val::Px(theme_spacing.borders.extra_small)))
             .border_color(colors.accent(Accent::Shadow))
             .background_color(colors.surface(Surface::Surface));
+
+        style_builder
+            .switch_target(TabContainer::OVERFLOW_BUTTON)
+            .display(Display::None)
+            .position_type(PositionType::Absolute)
+            .top(Val::Px(0.))
+            .right(Val::Px(0.))
+            .height(Val::Px(theme_spacing.areas.medium))
+            .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
+            .align_items(AlignItems::Center)
+            .border(UiRect::bottom(Val::Px(theme_spacing.borders.extra_small)))
+            .border_color(colors.accent(Accent::Shadow))
+            .background_color(colors.surface(Surface::Surface))
+            .icon(
+                theme_data
+                    .icons
+                    .expand_more
+                    .with(colors.on(On::Surface), theme_spacing.icons.small),
+            )
+            .animated()
+            .font_color(AnimatedVals {
+                idle: colors.on(On::SurfaceVariant),
+                hover: colors.on(On::Surface).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+    }
+
+    fn overflow_style(style_builder: &mut StyleBuilder, _theme_data: &ThemeData) {
+        style_builder
+            .switch_target(TabContainer::OVERFLOW_BUTTON)
+            .display(Display::Flex);
     }
 }
 
@@ -1030,6 +2152,17 @@ impl TabContainer {
             LockedStyleAttributes::lock(LockableStyleAttribute::Overflow),
         )
     }
+
+    fn overflow_button() -> impl Bundle {
+        (
+            Name::new("Tab Overflow Button"),
+            ButtonBundle::default(),
+            ContentSize::default(),
+            TrackedInteraction::default(),
+            GenerateContextMenu::default(),
+            TabOverflowMenu::default(),
+        )
+    }
 }
 
 pub trait UiTabContainerExt {
@@ -1055,7 +2188,9 @@ impl UiTabContainerExt for UiBuilder<'_, Entity> {
                     TabContainer::bar(),
                     TabBar {
                         container: container_id,
+                        ..default()
                     },
+                    AccessibleWidget::new(Role::TabList),
                 ))
                 .id();
 
@@ -1066,6 +2201,13 @@ impl UiTabContainerExt for UiBuilder<'_, Entity> {
                     })
                     .id();
             });
+
+            tab_container.overflow_button = container
+                .spawn((
+                    TabContainer::overflow_button(),
+                    AccessibleWidget::new(Role::Button).named("More tabs"),
+                ))
+                .id();
         });
 
         let container_id = container.id();
@@ -1112,6 +2254,7 @@ impl UiTabContainerSubExt for UiBuilder<'_, (Entity, TabContainer)> {
             .commands()
             .ui_builder(viewport_id)
             .panel(title.clone(), spawn_children)
+            .insert(AccessibleWidget::new(Role::TabPanel).named(title.clone()))
             .id();
 
         let mut tab = Tab {
@@ -1130,15 +2273,16 @@ impl UiTabContainerSubExt for UiBuilder<'_, (Entity, TabContainer)> {
                         .container(NodeBundle::default(), |container| {
                             tab.label = container
                                 .label(LabelConfig {
-                                    label: title,
+                                    label: title.clone(),
                                     ..default()
                                 })
                                 .id();
+                            tab.modified_marker = container.spawn(Tab::modified_marker()).id();
                         })
                         .id();
                 },
             )
-            .insert(tab);
+            .insert((tab, AccessibleWidget::new(Role::Tab).named(title)));
 
         self.commands().add(IncrementTabCount {
             container: container_id,
@@ -0,0 +1,1297 @@
+use bevy::{a11y::accesskit::Role, prelude::*, ui::FocusPolicy};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::WidgetLibraryUpdate;
+
+use super::{
+    container::UiContainerExt,
+    label::{LabelConfig, UiLabelExt},
+};
+
+const TOOLBAR_OVERFLOW_Z_INDEX: i32 = 100050;
+
+pub struct ToolbarPlugin;
+
+impl Plugin for ToolbarPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            ToolbarUpdate
+                .after(FluxInteractionUpdate)
+                .before(WidgetLibraryUpdate),
+        )
+        .add_plugins((
+            ComponentThemePlugin::<Toolbar>::default(),
+            ComponentThemePlugin::<ToolbarButton>::default(),
+            ComponentThemePlugin::<ToolbarSeparator>::default(),
+            ComponentThemePlugin::<ToolbarOverflowEntry>::default(),
+        ))
+        .add_event::<ToolbarButtonPressed>()
+        .add_event::<ToolbarGroupChanged>()
+        .add_systems(
+            Update,
+            (
+                handle_toolbar_button_press,
+                handle_overflow_click_or_touch,
+                update_toolbar_button_checked,
+                update_toolbar_overflow,
+                update_toolbar_overflow_panel,
+            )
+                .chain()
+                .in_set(ToolbarUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct ToolbarUpdate;
+
+/// Sent when a toolbar button or toggle is pressed, directly or from the overflow menu
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToolbarButtonPressed {
+    pub button: Entity,
+}
+
+/// Sent when a different toggle of an exclusive toolbar group gets checked
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct ToolbarGroupChanged {
+    pub group: String,
+    pub button: Entity,
+}
+
+fn handle_toolbar_button_press(
+    q_interactions: Query<(Entity, &FluxInteraction), Changed<FluxInteraction>>,
+    q_entries: Query<(&ToolbarOverflowEntry, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_toolbar_buttons: Query<(Entity, &mut ToolbarButton, &ToolbarItem)>,
+    mut button_writer: EventWriter<ToolbarButtonPressed>,
+    mut group_writer: EventWriter<ToolbarGroupChanged>,
+) {
+    // Entities that aren't toolbar buttons are skipped below
+    let pressed: Vec<Entity> = q_interactions
+        .iter()
+        .filter(|(_, interaction)| interaction.is_released())
+        .map(|(entity, _)| entity)
+        .chain(
+            q_entries
+                .iter()
+                .filter(|(_, interaction)| interaction.is_released())
+                .map(|(entry, _)| entry.item),
+        )
+        .collect();
+
+    for entity in pressed {
+        let Ok((_, mut button, item)) = q_toolbar_buttons.get_mut(entity) else {
+            continue;
+        };
+
+        button_writer.send(ToolbarButtonPressed { button: entity });
+        if !button.toggle {
+            continue;
+        }
+
+        let Some(group) = button.group.clone() else {
+            button.checked = !button.checked;
+            continue;
+        };
+
+        // Exclusive groups behave like radio buttons: the checked toggle stays checked
+        if button.checked {
+            continue;
+        }
+
+        button.checked = true;
+        let toolbar = item.toolbar;
+        for (other, mut other_button, other_item) in &mut q_toolbar_buttons {
+            if other != entity
+                && other_item.toolbar == toolbar
+                && other_button.checked
+                && other_button.group.as_ref() == Some(&group)
+            {
+                other_button.checked = false;
+            }
+        }
+
+        group_writer.send(ToolbarGroupChanged {
+            group,
+            button: entity,
+        });
+    }
+}
+
+fn handle_overflow_click_or_touch(
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    q_overflow_buttons: Query<(&ToolbarOverflowButton, &FluxInteraction)>,
+    mut q_toolbars: Query<(Entity, &mut Toolbar)>,
+) {
+    if !r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
+        && !r_touches.any_just_released()
+    {
+        return;
+    }
+
+    let toggled = q_overflow_buttons
+        .iter()
+        .find(|(_, interaction)| **interaction == FluxInteraction::Released)
+        .map(|(button, _)| button.toolbar);
+
+    for (entity, mut toolbar) in &mut q_toolbars {
+        if toggled == Some(entity) {
+            toolbar.overflow_open = !toolbar.overflow_open;
+        } else if toolbar.overflow_open {
+            toolbar.overflow_open = false;
+        }
+    }
+}
+
+fn update_toolbar_button_checked(
+    q_buttons: Query<(Entity, &ToolbarButton), Changed<ToolbarButton>>,
+    mut commands: Commands,
+) {
+    for (entity, button) in &q_buttons {
+        if button.checked {
+            commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::Checked);
+        } else {
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Checked);
+        }
+    }
+}
+
+/// Hides the items that don't fit the toolbar, and shows the overflow button instead
+fn update_toolbar_overflow(
+    mut q_toolbars: Query<&mut Toolbar>,
+    q_children: Query<&Children>,
+    q_node: Query<&Node>,
+    mut q_items: Query<(&mut ToolbarItem, &mut Style)>,
+    q_separators: Query<(), With<ToolbarSeparator>>,
+    mut q_visibility: Query<&mut Visibility, With<ToolbarOverflowButton>>,
+) {
+    for mut toolbar in &mut q_toolbars {
+        let Ok(container) = q_node.get(toolbar.container) else {
+            continue;
+        };
+        let Ok(children) = q_children.get(toolbar.container) else {
+            continue;
+        };
+
+        let vertical = toolbar.orientation == ToolbarOrientation::Vertical;
+        let main_size = |size: Vec2| match vertical {
+            true => size.y,
+            false => size.x,
+        };
+
+        // Hidden items keep the size they had when they were last laid out
+        let mut items: Vec<(Entity, f32)> = Vec::with_capacity(children.len());
+        let mut sizes: Vec<(f32, bool)> = Vec::with_capacity(children.len());
+        for child in children.iter() {
+            let Ok((mut item, _)) = q_items.get_mut(*child) else {
+                continue;
+            };
+
+            if !item.overflowed && !item.spacer {
+                if let Ok(node) = q_node.get(*child) {
+                    let size = main_size(node.size());
+                    if size > 0. && item.size != size {
+                        item.size = size;
+                    }
+                }
+            }
+
+            items.push((*child, item.size));
+            sizes.push((item.size, q_separators.contains(*child)));
+        }
+
+        let overflow_size = q_node
+            .get(toolbar.overflow_button)
+            .map_or(0., |node| main_size(node.size()));
+        let shown = shown_item_count(
+            &sizes,
+            main_size(container.size()),
+            overflow_size,
+            toolbar.gap,
+        );
+        let has_overflow = shown < items.len();
+
+        for (index, (child, _)) in items.iter().enumerate() {
+            let Ok((mut item, mut style)) = q_items.get_mut(*child) else {
+                continue;
+            };
+            set_item_overflowed(&mut item, &mut style, index >= shown);
+        }
+
+        if toolbar.has_overflow != has_overflow {
+            toolbar.has_overflow = has_overflow;
+            if !has_overflow {
+                toolbar.overflow_open = false;
+            }
+
+            if let Ok(mut visibility) = q_visibility.get_mut(toolbar.overflow_button) {
+                *visibility = match has_overflow {
+                    true => Visibility::Inherited,
+                    false => Visibility::Hidden,
+                };
+            }
+        }
+    }
+}
+
+/// The number of leading items shown in the toolbar, given their size along the toolbar and
+/// whether they are separators. The rest of the items are listed in the overflow menu.
+fn shown_item_count(items: &[(f32, bool)], available: f32, overflow_size: f32, gap: f32) -> usize {
+    let total: f32 = items.iter().map(|(size, _)| size).sum::<f32>()
+        + gap * items.len().saturating_sub(1) as f32;
+    if available <= 0. || total <= available {
+        return items.len();
+    }
+
+    // Once an item overflows, all the following items overflow as well
+    let limit = available - overflow_size - gap;
+    let mut used = 0.;
+    let mut shown = items
+        .iter()
+        .take_while(|(size, _)| {
+            let fits = used + size <= limit;
+            used += size + gap;
+            fits
+        })
+        .count();
+
+    // A separator doesn't separate anything at the end of the shown items
+    while shown > 0 && items[shown - 1].1 {
+        shown -= 1;
+    }
+
+    shown
+}
+
+fn set_item_overflowed(item: &mut ToolbarItem, style: &mut Style, overflowed: bool) {
+    if item.overflowed != overflowed {
+        item.overflowed = overflowed;
+    }
+
+    let display = match overflowed {
+        true => Display::None,
+        false => Display::Flex,
+    };
+    if style.display != display {
+        style.display = display;
+    }
+}
+
+fn update_toolbar_overflow_panel(
+    q_toolbars: Query<(Entity, &Toolbar), Changed<Toolbar>>,
+    q_children: Query<&Children>,
+    q_items: Query<(&ToolbarItem, Option<&ToolbarButton>)>,
+    mut commands: Commands,
+) {
+    for (entity, toolbar) in &q_toolbars {
+        if !toolbar.overflow_open {
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Open);
+            continue;
+        }
+
+        commands.entity(entity).add_pseudo_state(PseudoState::Open);
+        commands
+            .entity(toolbar.overflow_panel)
+            .despawn_descendants();
+
+        let Ok(children) = q_children.get(toolbar.container) else {
+            continue;
+        };
+
+        let mut panel = commands.ui_builder(toolbar.overflow_panel);
+        for child in children.iter() {
+            let Ok((item, button)) = q_items.get(*child) else {
+                continue;
+            };
+            let Some(button) = button else {
+                continue;
+            };
+            if !item.overflowed {
+                continue;
+            }
+
+            panel.overflow_entry(*child, button);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum ToolbarOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ToolbarConfig {
+    pub orientation: ToolbarOrientation,
+}
+
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Toolbar {
+    orientation: ToolbarOrientation,
+    has_overflow: bool,
+    overflow_open: bool,
+    gap: f32,
+    container: Entity,
+    overflow_button: Entity,
+    overflow_panel: Entity,
+}
+
+impl Default for Toolbar {
+    fn default() -> Self {
+        Self {
+            orientation: Default::default(),
+            has_overflow: false,
+            overflow_open: false,
+            gap: 0.,
+            container: Entity::PLACEHOLDER,
+            overflow_button: Entity::PLACEHOLDER,
+            overflow_panel: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for Toolbar {
+    fn default_theme() -> Option<Theme<Toolbar>> {
+        Toolbar::theme().into()
+    }
+}
+
+impl UiContext for Toolbar {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            Toolbar::CONTAINER => Ok(self.container),
+            Toolbar::OVERFLOW_BUTTON => Ok(self.overflow_button),
+            Toolbar::OVERFLOW_PANEL => Ok(self.overflow_panel),
+            _ => Err(format!(
+                "{} doesn't exist for Toolbar. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [
+            Toolbar::CONTAINER,
+            Toolbar::OVERFLOW_BUTTON,
+            Toolbar::OVERFLOW_PANEL,
+        ]
+        .into_iter()
+    }
+}
+
+impl Toolbar {
+    pub const CONTAINER: &'static str = "Container";
+    pub const OVERFLOW_BUTTON: &'static str = "OverflowButton";
+    pub const OVERFLOW_PANEL: &'static str = "OverflowPanel";
+
+    pub fn orientation(&self) -> ToolbarOrientation {
+        self.orientation
+    }
+
+    pub fn container(&self) -> Entity {
+        self.container
+    }
+
+    /// True if some items don't fit and are listed in the overflow menu
+    pub fn has_overflow(&self) -> bool {
+        self.has_overflow
+    }
+
+    pub fn theme() -> Theme<Toolbar> {
+        let base_theme = PseudoTheme::deferred_context(None, Toolbar::primary_style).depends_on(
+            ThemeDataSections::COLORS
+                | ThemeDataSections::SPACING
+                | ThemeDataSections::ICONS
+                | ThemeDataSections::ANIMATIONS,
+        );
+        let open_theme = PseudoTheme::deferred(vec![PseudoState::Open], Toolbar::open_style)
+            .depends_on(ThemeDataSections::NONE);
+        Theme::new(vec![base_theme, open_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, toolbar: &Toolbar, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let vertical = toolbar.orientation == ToolbarOrientation::Vertical;
+        let direction = match vertical {
+            true => FlexDirection::Column,
+            false => FlexDirection::Row,
+        };
+        let chevron = match vertical {
+            true => theme_data.icons.expand_more.clone(),
+            false => theme_data.icons.chevron_right.clone(),
+        };
+
+        style_builder
+            .flex_direction(direction)
+            .flex_shrink(0.)
+            .align_items(AlignItems::Center)
+            .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
+            .background_color(colors.container(Container::SurfaceMid));
+
+        match vertical {
+            true => style_builder
+                .height(Val::Percent(100.))
+                .border(UiRect::right(Val::Px(theme_spacing.borders.extra_small))),
+            false => style_builder
+                .width(Val::Percent(100.))
+                .border(UiRect::bottom(Val::Px(theme_spacing.borders.extra_small))),
+        };
+        style_builder.border_color(colors.accent(Accent::Shadow));
+
+        // The container only takes the space left in the toolbar, whatever its items need
+        style_builder
+            .switch_target(Toolbar::CONTAINER)
+            .flex_direction(direction)
+            .flex_grow(1.)
+            .flex_basis(Val::Px(0.))
+            .min_width(Val::Px(0.))
+            .min_height(Val::Px(0.))
+            .align_items(AlignItems::Center)
+            .column_gap(Val::Px(toolbar.gap))
+            .row_gap(Val::Px(toolbar.gap))
+            .overflow(Overflow::clip());
+
+        style_builder
+            .switch_target(Toolbar::OVERFLOW_BUTTON)
+            .position_type(PositionType::Absolute)
+            .size(Val::Px(theme_spacing.areas.small))
+            .justify_content(JustifyContent::Center)
+            .align_items(AlignItems::Center)
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .icon(chevron.with(colors.on(On::Surface), theme_spacing.icons.small))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        let inset = Val::Px(theme_spacing.gaps.small);
+        match vertical {
+            true => style_builder
+                .switch_target(Toolbar::OVERFLOW_BUTTON)
+                .bottom(inset)
+                .left(inset),
+            false => style_builder
+                .switch_target(Toolbar::OVERFLOW_BUTTON)
+                .right(inset)
+                .top(inset),
+        };
+
+        style_builder
+            .switch_target(Toolbar::OVERFLOW_PANEL)
+            .position_type(PositionType::Absolute)
+            .flex_direction(FlexDirection::Column)
+            .min_width(Val::Px(theme_spacing.areas.extra_large))
+            .padding(UiRect::vertical(Val::Px(theme_spacing.gaps.small)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .border_color(colors.accent(Accent::Shadow))
+            .background_color(colors.container(Container::SurfaceHigh))
+            .z_index(ZIndex::Global(TOOLBAR_OVERFLOW_Z_INDEX))
+            .display(Display::None);
+
+        match vertical {
+            true => style_builder
+                .switch_target(Toolbar::OVERFLOW_PANEL)
+                .left(Val::Percent(100.))
+                .bottom(Val::Px(0.)),
+            false => style_builder
+                .switch_target(Toolbar::OVERFLOW_PANEL)
+                .top(Val::Percent(100.))
+                .right(Val::Px(0.)),
+        };
+    }
+
+    fn open_style(style_builder: &mut StyleBuilder, _: &ThemeData) {
+        style_builder
+            .switch_target(Toolbar::OVERFLOW_PANEL)
+            .display(Display::Flex);
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Toolbar"),
+            NodeBundle::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::PositionType),
+        )
+    }
+
+    fn container_bundle() -> impl Bundle {
+        (Name::new("Container"), NodeBundle::default())
+    }
+
+    fn overflow_button(toolbar: Entity) -> impl Bundle {
+        (
+            Name::new("Overflow Button"),
+            ButtonBundle {
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            TrackedInteraction::default(),
+            ToolbarOverflowButton { toolbar },
+            AccessibleWidget::new(Role::Button).named("More"),
+            LockedStyleAttributes::lock(LockableStyleAttribute::Visibility),
+        )
+    }
+
+    fn overflow_panel() -> impl Bundle {
+        (
+            Name::new("Overflow Panel"),
+            NodeBundle {
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+            AccessibleWidget::new(Role::Menu),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ToolbarOverflowButton {
+    toolbar: Entity,
+}
+
+/// Any child of a toolbar: buttons, toggles, separators and spacers
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ToolbarItem {
+    toolbar: Entity,
+    spacer: bool,
+    size: f32,
+    overflowed: bool,
+}
+
+impl ToolbarItem {
+    fn new(toolbar: Entity) -> Self {
+        Self {
+            toolbar,
+            spacer: false,
+            size: 0.,
+            overflowed: false,
+        }
+    }
+
+    pub fn toolbar(&self) -> Entity {
+        self.toolbar
+    }
+
+    /// True if the item doesn't fit the toolbar and is listed in its overflow menu instead
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ToolbarButtonConfig {
+    /// Describes the button. Shown next to the icon with `show_label`, and in the overflow menu.
+    pub label: String,
+    pub icon: IconData,
+    pub show_label: bool,
+}
+
+impl ToolbarButtonConfig {
+    pub fn new(label: impl Into<String>, icon: IconData) -> Self {
+        Self {
+            label: label.into(),
+            icon,
+            ..default()
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ToolbarToggleConfig {
+    pub label: String,
+    pub icon: IconData,
+    pub show_label: bool,
+    pub checked: bool,
+    /// Toggles of the same group in a toolbar are exclusive, like radio buttons
+    pub group: Option<String>,
+}
+
+impl ToolbarToggleConfig {
+    pub fn new(label: impl Into<String>, icon: IconData) -> Self {
+        Self {
+            label: label.into(),
+            icon,
+            ..default()
+        }
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ToolbarButton {
+    /// Only used by toggles
+    pub checked: bool,
+    label_text: String,
+    icon_data: IconData,
+    show_label: bool,
+    toggle: bool,
+    group: Option<String>,
+    icon: Entity,
+    label: Entity,
+}
+
+impl Default for ToolbarButton {
+    fn default() -> Self {
+        Self {
+            checked: false,
+            label_text: Default::default(),
+            icon_data: Default::default(),
+            show_label: false,
+            toggle: false,
+            group: None,
+            icon: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for ToolbarButton {
+    fn default_theme() -> Option<Theme<ToolbarButton>> {
+        ToolbarButton::theme().into()
+    }
+}
+
+impl UiContext for ToolbarButton {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            ToolbarButton::ICON => Ok(self.icon),
+            ToolbarButton::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for ToolbarButton. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [ToolbarButton::ICON, ToolbarButton::LABEL].into_iter()
+    }
+}
+
+impl ToolbarButton {
+    pub const ICON: &'static str = "Icon";
+    pub const LABEL: &'static str = "Label";
+
+    /// The description of the button, e.g. for tooltips
+    pub fn label(&self) -> &str {
+        &self.label_text
+    }
+
+    pub fn icon(&self) -> &IconData {
+        &self.icon_data
+    }
+
+    pub fn is_toggle(&self) -> bool {
+        self.toggle
+    }
+
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    pub fn theme() -> Theme<ToolbarButton> {
+        let base_theme = PseudoTheme::deferred_context(None, ToolbarButton::primary_style);
+        let checked_theme =
+            PseudoTheme::deferred_context(vec![PseudoState::Checked], ToolbarButton::checked_style)
+                .depends_on(
+                    ThemeDataSections::COLORS
+                        | ThemeDataSections::SPACING
+                        | ThemeDataSections::ICONS
+                        | ThemeDataSections::ANIMATIONS,
+                );
+        Theme::new(vec![base_theme, checked_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        button: &ToolbarButton,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let font = theme_data
+            .text
+            .get(FontStyle::Label, FontScale::Medium, FontType::Regular);
+
+        style_builder
+            .flex_shrink(0.)
+            .align_items(AlignItems::Center)
+            .column_gap(Val::Px(theme_spacing.gaps.small))
+            .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
+            .border_radius(BorderRadius::all(Val::Px(
+                theme_spacing.corners.extra_small,
+            )))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(ToolbarButton::ICON)
+            .size(Val::Px(theme_spacing.icons.medium))
+            .icon(
+                button
+                    .icon_data
+                    .with(colors.on(On::SurfaceVariant), theme_spacing.icons.medium),
+            );
+
+        style_builder
+            .switch_target(ToolbarButton::LABEL)
+            .sized_font(font)
+            .font_color(colors.on(On::SurfaceVariant))
+            .display(match button.show_label {
+                true => Display::Flex,
+                false => Display::None,
+            });
+    }
+
+    fn checked_style(
+        style_builder: &mut StyleBuilder,
+        button: &ToolbarButton,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::Primary),
+                hover: colors.accent(Accent::Primary).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder.switch_target(ToolbarButton::ICON).icon(
+            button
+                .icon_data
+                .with(colors.on(On::PrimaryContainer), theme_spacing.icons.medium),
+        );
+
+        style_builder
+            .switch_target(ToolbarButton::LABEL)
+            .font_color(colors.on(On::PrimaryContainer));
+    }
+
+    fn frame(name: String) -> impl Bundle {
+        (
+            Name::new(name),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+        )
+    }
+
+    fn icon_bundle() -> impl Bundle {
+        (
+            Name::new("Icon"),
+            ImageBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            BorderColor::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ToolbarSeparator {
+    orientation: ToolbarOrientation,
+}
+
+impl DefaultTheme for ToolbarSeparator {
+    fn default_theme() -> Option<Theme<ToolbarSeparator>> {
+        ToolbarSeparator::theme().into()
+    }
+}
+
+impl UiContext for ToolbarSeparator {}
+
+impl ToolbarSeparator {
+    pub fn theme() -> Theme<ToolbarSeparator> {
+        let base_theme = PseudoTheme::deferred_context(None, ToolbarSeparator::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        separator: &ToolbarSeparator,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let thickness = Val::Px(theme_spacing.borders.extra_small);
+        let length = Val::Px(theme_spacing.areas.small);
+
+        style_builder
+            .flex_shrink(0.)
+            .background_color(colors.accent(Accent::OutlineVariant));
+
+        // Only the item gap separates it along the toolbar, so the overflow can use its node size
+        match separator.orientation {
+            ToolbarOrientation::Horizontal => style_builder.width(thickness).height(length),
+            ToolbarOrientation::Vertical => style_builder.width(length).height(thickness),
+        };
+    }
+
+    fn frame() -> impl Bundle {
+        (Name::new("Separator"), NodeBundle::default())
+    }
+}
+
+/// An item of the overflow menu, standing in for a toolbar button that doesn't fit
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ToolbarOverflowEntry {
+    item: Entity,
+    checked: bool,
+    icon_data: IconData,
+    icon: Entity,
+    label: Entity,
+}
+
+impl Default for ToolbarOverflowEntry {
+    fn default() -> Self {
+        Self {
+            item: Entity::PLACEHOLDER,
+            checked: false,
+            icon_data: Default::default(),
+            icon: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for ToolbarOverflowEntry {
+    fn default_theme() -> Option<Theme<ToolbarOverflowEntry>> {
+        ToolbarOverflowEntry::theme().into()
+    }
+}
+
+impl UiContext for ToolbarOverflowEntry {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            ToolbarOverflowEntry::ICON => Ok(self.icon),
+            ToolbarOverflowEntry::LABEL => Ok(self.label),
+            _ => Err(format!(
+                "{} doesn't exist for ToolbarOverflowEntry. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [ToolbarOverflowEntry::ICON, ToolbarOverflowEntry::LABEL].into_iter()
+    }
+}
+
+impl ToolbarOverflowEntry {
+    pub const ICON: &'static str = "Icon";
+    pub const LABEL: &'static str = "Label";
+
+    /// The toolbar item the entry stands in for
+    pub fn item(&self) -> Entity {
+        self.item
+    }
+
+    pub fn theme() -> Theme<ToolbarOverflowEntry> {
+        let base_theme = PseudoTheme::deferred_context(None, ToolbarOverflowEntry::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        entry: &ToolbarOverflowEntry,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let font = theme_data
+            .text
+            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+        let (idle, on) = match entry.checked {
+            true => (colors.container(Container::Primary), On::PrimaryContainer),
+            false => (colors.container(Container::SurfaceHigh), On::Surface),
+        };
+
+        style_builder
+            .align_items(AlignItems::Center)
+            .column_gap(Val::Px(theme_spacing.gaps.medium))
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.medium),
+                Val::Px(theme_spacing.gaps.small),
+            ))
+            .animated()
+            .background_color(AnimatedVals {
+                idle,
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(ToolbarOverflowEntry::ICON)
+            .size(Val::Px(theme_spacing.icons.small))
+            .icon(
+                entry
+                    .icon_data
+                    .with(colors.on(on), theme_spacing.icons.small),
+            );
+
+        style_builder
+            .switch_target(ToolbarOverflowEntry::LABEL)
+            .sized_font(font)
+            .font_color(colors.on(on));
+    }
+
+    fn frame(label: String) -> impl Bundle {
+        (
+            Name::new(format!("Overflow Entry [{}]", label)),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+            AccessibleWidget::new(Role::MenuItem).named(label),
+        )
+    }
+}
+
+trait UiToolbarOverflowEntryExt {
+    fn overflow_entry(&mut self, item: Entity, button: &ToolbarButton) -> UiBuilder<Entity>;
+}
+
+impl UiToolbarOverflowEntryExt for UiBuilder<'_, Entity> {
+    fn overflow_entry(&mut self, item: Entity, button: &ToolbarButton) -> UiBuilder<Entity> {
+        let mut entry = ToolbarOverflowEntry {
+            item,
+            checked: button.toggle && button.checked,
+            icon_data: button.icon_data.clone(),
+            ..default()
+        };
+
+        let mut frame = self.container(
+            ToolbarOverflowEntry::frame(button.label_text.clone()),
+            |container| {
+                entry.icon = container.spawn(ToolbarButton::icon_bundle()).id();
+                entry.label = container
+                    .label(LabelConfig {
+                        label: button.label_text.clone(),
+                        ..default()
+                    })
+                    .id();
+            },
+        );
+
+        frame.insert(entry);
+        frame
+    }
+}
+
+pub trait UiToolbarExt {
+    /// A bar of icon buttons and toggles. Items that don't fit are hidden and listed in
+    /// an overflow menu, opened from a chevron button at the end of the toolbar.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Open` is used when the overflow menu is open
+    fn toolbar(
+        &mut self,
+        config: ToolbarConfig,
+        spawn_items: impl FnOnce(&mut UiBuilder<(Entity, Toolbar)>),
+    ) -> UiBuilder<Entity>;
+}
+
+impl UiToolbarExt for UiBuilder<'_, Entity> {
+    fn toolbar(
+        &mut self,
+        config: ToolbarConfig,
+        spawn_items: impl FnOnce(&mut UiBuilder<(Entity, Toolbar)>),
+    ) -> UiBuilder<Entity> {
+        let mut toolbar = Toolbar {
+            orientation: config.orientation,
+            ..default()
+        };
+
+        let mut frame = self.container(Toolbar::frame(), |container| {
+            let toolbar_id = container.id();
+            toolbar.container = container.spawn(Toolbar::container_bundle()).id();
+            toolbar.overflow_button = container
+                .container(Toolbar::overflow_button(toolbar_id), |button| {
+                    toolbar.overflow_panel = button.spawn(Toolbar::overflow_panel()).id();
+                })
+                .id();
+        });
+
+        let id = frame.id();
+        frame.insert(AccessibleWidget::new(Role::Toolbar));
+        frame.commands().add(move |world: &mut World| {
+            // The overflow is computed with the item gap of the theme
+            toolbar.gap = world.resource::<ThemeData>().spacing.gaps.small;
+            world.entity_mut(id).insert(toolbar);
+        });
+
+        let mut toolbar_builder = self.commands().ui_builder((id, toolbar));
+        spawn_items(&mut toolbar_builder);
+
+        self.commands().ui_builder(id)
+    }
+}
+
+pub trait UiToolbarSubExt {
+    fn id(&self) -> Entity;
+
+    fn container(&self) -> Entity;
+
+    /// A button with an icon, sending [`ToolbarButtonPressed`] events
+    fn button(&mut self, config: ToolbarButtonConfig) -> UiBuilder<Entity>;
+
+    /// A button that stays checked until pressed again, or until another toggle of its
+    /// group is pressed
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Checked` is used when the toggle is checked
+    fn toggle_button(&mut self, config: ToolbarToggleConfig) -> UiBuilder<Entity>;
+
+    fn separator(&mut self) -> UiBuilder<Entity>;
+
+    /// Takes the free space of the toolbar, pushing the following items to its end
+    fn spacer(&mut self) -> UiBuilder<Entity>;
+}
+
+impl UiToolbarSubExt for UiBuilder<'_, (Entity, Toolbar)> {
+    fn id(&self) -> Entity {
+        self.context().0
+    }
+
+    fn container(&self) -> Entity {
+        self.context().1.container
+    }
+
+    fn button(&mut self, config: ToolbarButtonConfig) -> UiBuilder<Entity> {
+        let button = ToolbarButton {
+            label_text: config.label,
+            icon_data: config.icon,
+            show_label: config.show_label,
+            ..default()
+        };
+
+        spawn_toolbar_button(self, button)
+    }
+
+    fn toggle_button(&mut self, config: ToolbarToggleConfig) -> UiBuilder<Entity> {
+        let button = ToolbarButton {
+            checked: config.checked,
+            label_text: config.label,
+            icon_data: config.icon,
+            show_label: config.show_label,
+            toggle: true,
+            group: config.group,
+            ..default()
+        };
+
+        spawn_toolbar_button(self, button)
+    }
+
+    fn separator(&mut self) -> UiBuilder<Entity> {
+        let toolbar = self.context().1;
+        let toolbar_id = self.id();
+        let id = self
+            .commands()
+            .ui_builder(toolbar.container)
+            .spawn((
+                ToolbarSeparator::frame(),
+                ToolbarSeparator {
+                    orientation: toolbar.orientation,
+                },
+                ToolbarItem::new(toolbar_id),
+            ))
+            .id();
+
+        self.commands().ui_builder(id)
+    }
+
+    fn spacer(&mut self) -> UiBuilder<Entity> {
+        let container = self.container();
+        let toolbar_id = self.id();
+        let id = self
+            .commands()
+            .ui_builder(container)
+            .spawn((
+                Name::new("Spacer"),
+                NodeBundle {
+                    style: Style {
+                        flex_grow: 1.,
+                        ..default()
+                    },
+                    ..default()
+                },
+                ToolbarItem {
+                    spacer: true,
+                    ..ToolbarItem::new(toolbar_id)
+                },
+            ))
+            .id();
+
+        self.commands().ui_builder(id)
+    }
+}
+
+fn spawn_toolbar_button<'a>(
+    builder: &'a mut UiBuilder<(Entity, Toolbar)>,
+    mut button: ToolbarButton,
+) -> UiBuilder<'a, Entity> {
+    let container = builder.container();
+    let toolbar_id = builder.id();
+    let name = format!("Toolbar Button [{}]", button.label_text);
+    // Grouped toggles are radio buttons. AccessKit has no toggle button role, other toggles
+    // are buttons reporting their `PseudoState::Checked` as toggled.
+    let accessible = match (button.toggle, button.group.is_some()) {
+        (true, true) => AccessibleWidget::new(Role::RadioButton),
+        (true, false) => AccessibleWidget::new(Role::Button).toggleable(),
+        (false, _) => AccessibleWidget::new(Role::Button),
+    }
+    .named(button.label_text.clone());
+
+    let id = builder
+        .commands()
+        .ui_builder(container)
+        .container(ToolbarButton::frame(name), |container| {
+            button.icon = container.spawn(ToolbarButton::icon_bundle()).id();
+            button.label = container
+                .label(LabelConfig {
+                    label: button.label_text.clone(),
+                    ..default()
+                })
+                .id();
+        })
+        .insert((button, ToolbarItem::new(toolbar_id), accessible))
+        .id();
+
+    builder.commands().ui_builder(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    const BUTTON: (f32, bool) = (20., false);
+    const SEPARATOR: (f32, bool) = (4., true);
+
+    #[test]
+    fn shown_item_count_shows_everything_that_fits() {
+        assert_eq!(shown_item_count(&[BUTTON; 4], 100., 20., 0.), 4);
+        assert_eq!(shown_item_count(&[BUTTON; 4], 86., 20., 2.), 4);
+        assert_eq!(shown_item_count(&[], 100., 20., 0.), 0);
+        // Not laid out yet
+        assert_eq!(shown_item_count(&[BUTTON; 4], 0., 20., 0.), 4);
+    }
+
+    #[test]
+    fn shown_item_count_hides_items_in_order() {
+        // 70 pixels next to the overflow button
+        assert_eq!(shown_item_count(&[BUTTON; 5], 90., 20., 0.), 3);
+        // Gaps take up room too
+        assert_eq!(shown_item_count(&[BUTTON; 5], 90., 20., 5.), 2);
+        assert_eq!(shown_item_count(&[BUTTON; 5], 30., 20., 0.), 0);
+    }
+
+    #[test]
+    fn shown_item_count_hides_a_trailing_separator() {
+        let items = [BUTTON, BUTTON, SEPARATOR, BUTTON, BUTTON];
+
+        assert_eq!(shown_item_count(&items, 70., 20., 0.), 2);
+        assert_eq!(shown_item_count(&items, 80., 10., 0.), 4);
+        assert_eq!(shown_item_count(&items, 84., 20., 0.), 5);
+    }
+
+    fn toggle(world: &mut World, toolbar: Entity, group: Option<&str>, checked: bool) -> Entity {
+        world
+            .spawn((
+                ToolbarButton {
+                    checked,
+                    toggle: true,
+                    group: group.map(String::from),
+                    ..default()
+                },
+                ToolbarItem::new(toolbar),
+                FluxInteraction::None,
+            ))
+            .id()
+    }
+
+    fn press(world: &mut World, button: Entity) {
+        *world.get_mut::<FluxInteraction>(button).unwrap() = FluxInteraction::Released;
+        world.run_system_once(handle_toolbar_button_press);
+        *world.get_mut::<FluxInteraction>(button).unwrap() = FluxInteraction::None;
+    }
+
+    fn checked(world: &World, button: Entity) -> bool {
+        world.get::<ToolbarButton>(button).unwrap().checked
+    }
+
+    #[test]
+    fn exclusive_toggle_groups_behave_like_radio_buttons() {
+        let mut world = World::new();
+        world.init_resource::<Events<ToolbarButtonPressed>>();
+        world.init_resource::<Events<ToolbarGroupChanged>>();
+        let toolbar = world.spawn_empty().id();
+        let other_toolbar = world.spawn_empty().id();
+        let select = toggle(&mut world, toolbar, Some("tool"), true);
+        let brush = toggle(&mut world, toolbar, Some("tool"), false);
+        let grid = toggle(&mut world, toolbar, None, false);
+        let other_select = toggle(&mut world, other_toolbar, Some("tool"), true);
+
+        press(&mut world, brush);
+        assert!(!checked(&world, select));
+        assert!(checked(&world, brush));
+        // Groups are scoped to their toolbar
+        assert!(checked(&world, other_select));
+
+        let events = world.resource::<Events<ToolbarGroupChanged>>();
+        let changed = events.iter_current_update_events().last().unwrap();
+        assert_eq!(changed.button, brush);
+        assert_eq!(changed.group, "tool");
+
+        // The checked toggle of a group stays checked
+        press(&mut world, brush);
+        assert!(checked(&world, brush));
+
+        // Toggles without a group flip
+        press(&mut world, grid);
+        assert!(checked(&world, grid));
+        press(&mut world, grid);
+        assert!(!checked(&world, grid));
+        assert!(checked(&world, brush));
+    }
+}
@@ -3,7 +3,11 @@ pub mod extra_menu;
 pub mod menu;
 pub mod menu_bar;
 pub mod menu_item;
+pub mod menu_model;
+pub mod menu_navigation;
+pub mod menu_scroll;
 pub mod menu_separators;
+pub mod radio_menu_item;
 pub mod shortcut;
 pub mod submenu;
 pub mod toggle_menu_item;
@@ -1,23 +1,36 @@
-use bevy::{ecs::world::CommandQueue, prelude::*, ui::FocusPolicy, window::PrimaryWindow};
+use bevy::{
+    a11y::accesskit::Role,
+    ecs::{query::QueryFilter, world::CommandQueue},
+    prelude::*,
+    reflect::TypeRegistry,
+    ui::FocusPolicy,
+};
 
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
-use super::menu_separators::UiMenuItemSeparatorExt;
+use super::{
+    menu_item::KeepMenuOpen, menu_scroll::MenuScroll, menu_separators::UiMenuItemSeparatorExt,
+    submenu::Submenu,
+};
 
 const MENU_CONTAINER_Z_INDEX: i32 = 100002;
 
-// TODO: Implement scroll container and up/down arrows when content larger than screen
 pub struct ContextMenuPlugin;
 
 impl Plugin for ContextMenuPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(Update, ContextMenuUpdate.after(FluxInteractionUpdate))
+            .init_resource::<LongPressConfig>()
+            .init_resource::<ContextMenuLongPress>()
+            .init_resource::<ContextMenuEntries>()
             .add_plugins(ComponentThemePlugin::<ContextMenu>::default())
             .add_systems(
                 Update,
                 (
                     update_context_menu_vertical_position,
+                    open_context_menu_on_long_press,
+                    mark_context_menu_entry_targets,
                     handle_click_or_touch,
                     delete_closed_context_menu,
                     generate_context_menu,
@@ -30,15 +43,113 @@ impl Plugin for ContextMenuPlugin {
     }
 }
 
-// TODO: Handle long press on touch
+/// Configures the touch long press that opens context menus
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct LongPressConfig {
+    /// Seconds a touch must be held to count as a long press
+    pub duration: f32,
+    /// Distance in pixels a touch can move before it no longer counts as a long press
+    pub tolerance: f32,
+}
+
+impl Default for LongPressConfig {
+    fn default() -> Self {
+        Self {
+            duration: 0.5,
+            tolerance: 10.,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+struct ContextMenuLongPress {
+    touch: Option<u64>,
+    elapsed: f32,
+    opened: bool,
+}
+
+fn open_context_menu_on_long_press(
+    r_time: Res<Time<Real>>,
+    r_config: Res<LongPressConfig>,
+    r_touches: Res<Touches>,
+    mut r_long_press: ResMut<ContextMenuLongPress>,
+    mut q_interacted: Query<(Entity, &Interaction, &mut GenerateContextMenu)>,
+    mut commands: Commands,
+) {
+    if let Some(touch) = r_touches.iter_just_pressed().next() {
+        *r_long_press = ContextMenuLongPress {
+            touch: touch.id().into(),
+            ..default()
+        };
+    }
+
+    let Some(touch) = r_long_press.touch else {
+        return;
+    };
+    if r_long_press.opened {
+        return;
+    }
+    let Some(touch) = r_touches.get_pressed(touch) else {
+        return;
+    };
+    if touch.distance().length() > r_config.tolerance {
+        r_long_press.touch = None;
+        return;
+    }
+
+    r_long_press.elapsed += r_time.delta_seconds();
+    if r_long_press.elapsed < r_config.duration {
+        return;
+    }
+    r_long_press.opened = true;
+
+    // The touch presses every node under it, the first one with a context menu opens it
+    let Some(open) = q_interacted
+        .iter()
+        .find(|(_, interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(entity, _, _)| entity)
+    else {
+        return;
+    };
+
+    for (entity, _, mut gen_menu) in &mut q_interacted {
+        if entity == open {
+            if let Some(container) = gen_menu.container {
+                commands.entity(container).despawn_recursive();
+                gen_menu.container = None;
+            }
+            gen_menu.is_open = true;
+            gen_menu.position = touch.position().into();
+        } else if gen_menu.is_open {
+            gen_menu.is_open = false;
+        }
+    }
+}
+
+type SubmenuOrKeepOpen = Or<(With<Submenu>, With<KeepMenuOpen>)>;
+
 fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
+    r_touches: Res<Touches>,
+    r_long_press: Res<ContextMenuLongPress>,
     q_context_menu: Query<&Interaction, (With<ContextMenu>, Changed<Interaction>)>,
+    q_keep_open: Query<&FluxInteraction, (SubmenuOrKeepOpen, Changed<FluxInteraction>)>,
     mut q_interacted: Query<(Entity, &Interaction, &mut GenerateContextMenu)>,
     mut commands: Commands,
 ) {
     let mut close_all = false;
 
+    // Releasing the touch that opened a menu, opening a submenu or clicking an item
+    // that keeps the menu open doesn't close it
+    let long_press_released = r_long_press.opened
+        && r_touches
+            .iter_just_released()
+            .any(|touch| Some(touch.id()) == r_long_press.touch);
+    let keep_open_released = q_keep_open
+        .iter()
+        .any(|interaction| *interaction == FluxInteraction::Released);
+
     if r_mouse.just_pressed(MouseButton::Right) {
         let mut open: Option<Entity> = None;
         for (entity, interaction, _) in &q_interacted {
@@ -51,6 +162,7 @@ fn handle_click_or_touch(
         if let Some(open) = open {
             for (entity, _, mut gen_menu) in &mut q_interacted {
                 if entity == open {
+                    gen_menu.position = None;
                     if !gen_menu.is_open {
                         gen_menu.is_open = true;
                     } else if let Some(container) = gen_menu.container {
@@ -64,7 +176,9 @@ fn handle_click_or_touch(
         } else {
             close_all = true;
         }
-    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle]) {
+    } else if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle])
+        || r_touches.any_just_pressed()
+    {
         let mut on_context_menu = false;
         for interaction in &q_context_menu {
             if *interaction == Interaction::Pressed {
@@ -75,7 +189,11 @@ fn handle_click_or_touch(
         if !on_context_menu {
             close_all = true;
         }
-    } else if r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle]) {
+    } else if (r_mouse.any_just_released([MouseButton::Left, MouseButton::Middle])
+        || r_touches.any_just_released())
+        && !long_press_released
+        && !keep_open_released
+    {
         close_all = true;
     }
 
@@ -104,6 +222,24 @@ fn delete_closed_context_menu(
     }
 }
 
+fn mark_context_menu_entry_targets(world: &mut World) {
+    let targets = world.resource_scope(|world, mut registry: Mut<ContextMenuEntries>| {
+        registry
+            .entries
+            .iter_mut()
+            .flat_map(|entry| entry.filter.unmarked(world))
+            .collect::<Vec<Entity>>()
+    });
+
+    for entity in targets {
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            if !entity.contains::<GenerateContextMenu>() {
+                entity.insert(GenerateContextMenu::default());
+            }
+        }
+    }
+}
+
 fn generate_context_menu(world: &mut World) {
     let mut q_gen_menus =
         world.query_filtered::<(Entity, &mut GenerateContextMenu), Changed<GenerateContextMenu>>();
@@ -120,135 +256,178 @@ fn generate_context_menu(world: &mut World) {
         return;
     };
 
+    // The entity and its ancestors up to the context root all contribute
+    let mut sources = vec![entity];
     let mut root_node = entity;
     while let Some(parent) = world.get::<Parent>(root_node) {
         root_node = parent.get();
-        if let Some(_) = world.get::<UiContextRoot>(root_node) {
+        sources.push(root_node);
+        if world.get::<UiContextRoot>(root_node).is_some() {
             break;
         }
     }
 
-    let entity_ref = world.entity(entity);
-    let type_registry = world.resource::<AppTypeRegistry>().read();
-    let mut generators: Vec<&dyn ContextMenuGenerator> = world
-        .entity(entity)
-        .archetype()
-        .components()
-        .filter(|component_id| {
-            let Some(component_info) = world.components().get_info(*component_id) else {
-                return false;
-            };
+    world.resource_scope(|world, mut registry: Mut<ContextMenuEntries>| {
+        let mut matched_entries: Vec<(usize, Entity)> = Vec::new();
+        for source in sources.iter() {
+            for (index, entry) in registry.entries.iter_mut().enumerate() {
+                if entry.filter.matches(world, *source) {
+                    matched_entries.push((index, *source));
+                }
+            }
+        }
 
-            let Some(type_id) = component_info.type_id() else {
-                return false;
-            };
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let mut contributions: Vec<ContextMenuContribution> = sources
+            .iter()
+            .flat_map(|source| {
+                let generators = context_menu_generators_of(world, &type_registry, *source)
+                    .into_iter()
+                    .map(|generator| ContextMenuContribution::Generator(generator, *source));
+                let entries = matched_entries
+                    .iter()
+                    .filter(|(_, entry_source)| entry_source == source)
+                    .map(|(index, _)| {
+                        ContextMenuContribution::Entry(&registry.entries[*index], *source)
+                    });
+
+                generators
+                    .chain(entries)
+                    .collect::<Vec<ContextMenuContribution>>()
+            })
+            .collect();
+
+        drop(type_registry);
+
+        if contributions.is_empty() {
+            for (orig_entity, mut gen_menu) in q_gen_menus.iter_mut(world) {
+                if orig_entity == entity {
+                    gen_menu.is_open = false;
+                    break;
+                }
+            }
 
-            type_registry
-                .get_type_data::<ReflectContextMenuGenerator>(type_id)
-                .is_some()
-        })
-        .map(|generator_id| {
-            let type_id = world
-                .components()
-                .get_info(generator_id)
-                .unwrap()
-                .type_id()
-                .unwrap();
-            let reflect_generator = type_registry
-                .get_type_data::<ReflectContextMenuGenerator>(type_id)
-                .unwrap();
+            warn!(
+                "Cannot create context menu for entity {}. No generators implemented!",
+                entity
+            );
+            return;
+        }
 
-            let component = type_registry
-                .get(type_id)
-                .unwrap()
-                .data::<ReflectComponent>()
-                .unwrap()
-                .reflect(entity_ref)
-                .unwrap();
-
-            let actual_generator: &dyn ContextMenuGenerator =
-                reflect_generator.get(&*component).unwrap();
-            actual_generator
-        })
-        .collect();
+        // Stable, so contributions of the entity come before those of its ancestors
+        contributions.sort_by_key(ContextMenuContribution::placement_index);
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        let name = format!("Context Menu of [{}]", entity);
 
-    drop(type_registry);
+        let container_id = commands
+            .ui_builder(root_node)
+            .spawn((ContextMenu::frame(name), AccessibleWidget::new(Role::Menu)))
+            .id();
+
+        let context_menu = ContextMenu {
+            context: entity,
+            container: container_id,
+        };
+
+        commands.entity(container_id).insert(context_menu);
+
+        let mut builder = commands.ui_builder(context_menu);
+        let mut last_index: Option<usize> = None;
+        for contribution in contributions {
+            let placement_index = contribution.placement_index();
+            if last_index.is_some_and(|last_index| last_index != placement_index) {
+                builder.separator();
+            }
+            last_index = placement_index.into();
+
+            contribution.build(&mut builder);
+        }
+
+        queue.apply(world);
 
-    if generators.len() == 0 {
         for (orig_entity, mut gen_menu) in q_gen_menus.iter_mut(world) {
             if orig_entity == entity {
-                gen_menu.is_open = false;
+                gen_menu.container = Some(container_id);
                 break;
             }
         }
+    });
+}
 
-        warn!(
-            "Cannot create context menu for entity {}. No generators implemented!",
-            entity
-        );
-        return;
-    }
-
-    generators.sort_by_key(|g| g.placement_index());
-
-    let mut queue = CommandQueue::default();
-    let mut commands = Commands::new(&mut queue, world);
-    let name = format!("Context Menu of [{}]", entity);
+fn context_menu_generators_of<'w>(
+    world: &'w World,
+    type_registry: &TypeRegistry,
+    entity: Entity,
+) -> Vec<&'w dyn ContextMenuGenerator> {
+    let entity_ref = world.entity(entity);
 
-    let container_id = commands
-        .ui_builder(root_node)
-        .spawn(ContextMenu::frame(name))
-        .id();
+    entity_ref
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            let type_id = world.components().get_info(component_id)?.type_id()?;
+            let reflect_generator =
+                type_registry.get_type_data::<ReflectContextMenuGenerator>(type_id)?;
+            let component = type_registry
+                .get_type_data::<ReflectComponent>(type_id)?
+                .reflect(entity_ref)?;
 
-    let context_menu = ContextMenu {
-        context: entity,
-        container: container_id,
-    };
+            reflect_generator.get(component)
+        })
+        .collect()
+}
 
-    commands.entity(container_id).insert(context_menu);
+enum ContextMenuContribution<'a> {
+    Generator(&'a dyn ContextMenuGenerator, Entity),
+    Entry(&'a ContextMenuEntry, Entity),
+}
 
-    let mut builder = commands.ui_builder(context_menu);
-    let mut last_index = 0;
-    for generator in generators {
-        if generator.placement_index() > last_index + 1 {
-            builder.separator();
+impl ContextMenuContribution<'_> {
+    fn placement_index(&self) -> usize {
+        match self {
+            ContextMenuContribution::Generator(generator, _) => generator.placement_index(),
+            ContextMenuContribution::Entry(entry, _) => entry.placement_index,
         }
-        last_index = generator.placement_index();
-
-        generator.build_context_menu(entity, &mut builder);
     }
 
-    queue.apply(world);
-
-    for (orig_entity, mut gen_menu) in q_gen_menus.iter_mut(world) {
-        if orig_entity == entity {
-            gen_menu.container = Some(container_id);
-            break;
+    fn build(&self, builder: &mut UiBuilder<ContextMenu>) {
+        match self {
+            ContextMenuContribution::Generator(generator, source) => {
+                generator.build_context_menu(*source, builder)
+            }
+            ContextMenuContribution::Entry(entry, source) => (entry.build)(*source, builder),
         }
     }
 }
 
-// TODO: Handle touch position
 fn position_added_context_menu(
-    q_context_menus: Query<Entity, Added<ContextMenu>>,
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    //r_touches: Res<Touches>,
+    q_context_menus: Query<(Entity, &ContextMenu), Added<ContextMenu>>,
+    q_gen_menus: Query<&GenerateContextMenu>,
+    ui_windows: UiWindows,
+    q_window: Query<&Window>,
     mut commands: Commands,
 ) {
-    let Ok(window) = q_window.get_single() else {
-        return;
-    };
-
-    let position = window.cursor_position();
-    // if position.is_none() {
-    //     position = r_touches.first_pressed_position();
-    // }
-
-    let Some(position) = position else {
-        return;
-    };
+    for (entity, context_menu) in &q_context_menus {
+        let Some(window) = ui_windows.window_of(entity) else {
+            continue;
+        };
+        let Ok(window) = q_window.get(window) else {
+            continue;
+        };
+
+        // Menus opened by touch are placed at the touch, others at the cursor
+        let position = q_gen_menus
+            .get(context_menu.context)
+            .ok()
+            .and_then(|gen_menu| gen_menu.position)
+            .or(window.cursor_position());
+
+        let Some(position) = position else {
+            continue;
+        };
 
-    for entity in &q_context_menus {
         commands
             .style(entity)
             .position_type(PositionType::Absolute)
@@ -256,19 +435,25 @@ fn position_added_context_menu(
     }
 }
 
+type ChangedContextMenuNode = (With<ContextMenu>, Changed<Node>);
+
 fn update_context_menu_vertical_position(
     mut q_node_style: Query<
-        (&Node, &Transform, &mut Style, &mut Visibility),
-        (With<ContextMenu>, Changed<Node>),
+        (Entity, &Node, &Transform, &mut Style, &mut Visibility),
+        ChangedContextMenuNode,
     >,
-    q_window: Query<&Window, With<PrimaryWindow>>,
+    ui_windows: UiWindows,
+    q_window: Query<&Window>,
 ) {
-    let Ok(window) = q_window.get_single() else {
-        return;
-    };
-
-    let resolution = Vec2::new(window.resolution.width(), window.resolution.height());
-    for (node, transform, mut style, mut visibility) in &mut q_node_style {
+    for (entity, node, transform, mut style, mut visibility) in &mut q_node_style {
+        let Some(window) = ui_windows.window_of(entity) else {
+            continue;
+        };
+        let Ok(window) = q_window.get(window) else {
+            continue;
+        };
+
+        let resolution = Vec2::new(window.resolution.width(), window.resolution.height());
         let size = node.size();
 
         let position = transform.translation.truncate() - (size / 2.);
@@ -298,23 +483,102 @@ fn delete_orphaned_context_menus(
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct ContextMenuUpdate;
 
+/// Contributes items to the context menu of the entity it is on and of its descendants.
+///
+/// All generators on the entity and its ancestors are collected, grouped by placement index,
+/// with a separator between groups.
 #[reflect_trait]
 pub trait ContextMenuGenerator {
     fn build_context_menu(&self, context: Entity, container: &mut UiBuilder<ContextMenu>);
     fn placement_index(&self) -> usize;
 }
 
+type BuildContextMenuEntry = Box<dyn Fn(Entity, &mut UiBuilder<ContextMenu>) + Send + Sync>;
+
+trait ContextMenuEntryFilter: Send + Sync {
+    fn matches(&mut self, world: &mut World, entity: Entity) -> bool;
+    fn unmarked(&mut self, world: &mut World) -> Vec<Entity>;
+}
+
+type UnmarkedEntryTarget<F> = (F, With<Interaction>, Without<GenerateContextMenu>);
+
+struct QueryEntryFilter<F: QueryFilter> {
+    matches: Option<QueryState<(), F>>,
+    unmarked: Option<QueryState<Entity, UnmarkedEntryTarget<F>>>,
+}
+
+impl<F: QueryFilter + Send + Sync + 'static> ContextMenuEntryFilter for QueryEntryFilter<F> {
+    fn matches(&mut self, world: &mut World, entity: Entity) -> bool {
+        self.matches
+            .get_or_insert_with(|| QueryState::new(world))
+            .get(world, entity)
+            .is_ok()
+    }
+
+    fn unmarked(&mut self, world: &mut World) -> Vec<Entity> {
+        self.unmarked
+            .get_or_insert_with(|| QueryState::new(world))
+            .iter(world)
+            .collect()
+    }
+}
+
+struct ContextMenuEntry {
+    placement_index: usize,
+    filter: Box<dyn ContextMenuEntryFilter>,
+    build: BuildContextMenuEntry,
+}
+
+/// Context menu entries contributed for entities matching a query filter, i.e. by plugins
+/// that don't own the widgets they extend.
+///
+/// Entries are merged with the `ContextMenuGenerator`s of the entity and its ancestors.
+/// Interactive entities matching a filter are made to open a context menu.
+#[derive(Resource, Default)]
+pub struct ContextMenuEntries {
+    entries: Vec<ContextMenuEntry>,
+}
+
+impl ContextMenuEntries {
+    /// Adds entries to the context menu of entities matching the filter `F`
+    pub fn add<F: QueryFilter + Send + Sync + 'static>(
+        &mut self,
+        placement_index: usize,
+        build: impl Fn(Entity, &mut UiBuilder<ContextMenu>) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.entries.push(ContextMenuEntry {
+            placement_index,
+            filter: Box::new(QueryEntryFilter::<F> {
+                matches: None,
+                unmarked: None,
+            }),
+            build: Box::new(build),
+        });
+        self
+    }
+}
+
 #[derive(Component, Debug, Default, Reflect)]
 #[reflect(Component)]
 pub struct GenerateContextMenu {
     is_open: bool,
     container: Option<Entity>,
+    position: Option<Vec2>,
 }
 
 impl GenerateContextMenu {
     pub fn is_open(&self) -> bool {
         self.is_open
     }
+
+    /// Opens the menu on the next update, for widgets that open their menu on a regular press
+    pub(crate) fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.is_open = false;
+    }
 }
 
 #[derive(Component, Clone, Copy, Debug, Reflect, UiContext)]
@@ -345,7 +609,8 @@ impl ContextMenu {
     }
 
     pub fn theme() -> Theme<ContextMenu> {
-        let base_theme = PseudoTheme::deferred(None, ContextMenu::container);
+        let base_theme = PseudoTheme::deferred(None, ContextMenu::container)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
         Theme::new(vec![base_theme])
     }
 
@@ -384,6 +649,8 @@ impl ContextMenu {
                 LockableStyleAttribute::Overflow,
             ]),
             Interaction::default(),
+            Scrollable::default(),
+            MenuScroll::default(),
         )
     }
 }
@@ -397,3 +664,172 @@ impl UiContextMenuExt for UiBuilder<'_, ContextMenu> {
         self.context().container
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{
+        input::touch::{TouchInput, TouchPhase},
+        log::LogPlugin,
+        render::{settings::WgpuSettings, RenderPlugin},
+        time::TimeUpdateStrategy,
+        window::{ExitCondition, PrimaryWindow, WindowResolution},
+        winit::WinitPlugin,
+    };
+
+    use crate::{prelude::*, SickleUiPlugin};
+
+    use super::*;
+
+    #[derive(Component)]
+    struct LongPressTarget;
+
+    /// A windowed app without a renderer or winit, with a context menu target
+    /// covering the top left 200 by 200 pixels, advancing 100ms per update
+    fn touch_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        resolution: WindowResolution::new(800., 600.),
+                        ..default()
+                    }),
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    ..default()
+                })
+                .disable::<WinitPlugin>()
+                .disable::<LogPlugin>(),
+        )
+        .add_plugins(SickleUiPlugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.finish();
+        app.cleanup();
+
+        app.world_mut()
+            .resource_mut::<ContextMenuEntries>()
+            .add::<With<LongPressTarget>>(0, |_, container| {
+                container.menu_item(MenuItemConfig {
+                    name: "Entry".into(),
+                    ..default()
+                });
+            });
+        app.world_mut().spawn(Camera2dBundle::default());
+        let target = app
+            .world_mut()
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(200.),
+                        height: Val::Px(200.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Interaction::default(),
+                LongPressTarget,
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        (app, target)
+    }
+
+    fn touch(app: &mut App, phase: TouchPhase, position: Vec2) {
+        let window = app
+            .world_mut()
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(app.world());
+        app.world_mut().send_event(TouchInput {
+            phase,
+            position,
+            window,
+            force: None,
+            id: 0,
+        });
+        app.update();
+    }
+
+    fn menu_open(app: &App, target: Entity) -> bool {
+        app.world()
+            .get::<GenerateContextMenu>(target)
+            .is_some_and(GenerateContextMenu::is_open)
+    }
+
+    fn menu_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query::<&ContextMenu>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn long_press_opens_and_tap_outside_closes() {
+        let (mut app, target) = touch_app();
+
+        touch(&mut app, TouchPhase::Started, Vec2::new(50., 50.));
+        for _ in 0..7 {
+            app.update();
+        }
+        assert!(menu_open(&app, target));
+        assert_eq!(menu_count(&mut app), 1);
+
+        // Lifting the finger that opened the menu keeps it open
+        touch(&mut app, TouchPhase::Ended, Vec2::new(50., 50.));
+        app.update();
+        assert!(menu_open(&app, target));
+
+        touch(&mut app, TouchPhase::Started, Vec2::new(600., 500.));
+        touch(&mut app, TouchPhase::Ended, Vec2::new(600., 500.));
+        app.update();
+        assert!(!menu_open(&app, target));
+        assert_eq!(menu_count(&mut app), 0);
+    }
+
+    #[test]
+    fn short_or_moving_touches_do_not_open() {
+        let (mut app, target) = touch_app();
+
+        touch(&mut app, TouchPhase::Started, Vec2::new(50., 50.));
+        app.update();
+        touch(&mut app, TouchPhase::Ended, Vec2::new(50., 50.));
+        for _ in 0..7 {
+            app.update();
+        }
+        assert!(!menu_open(&app, target));
+
+        touch(&mut app, TouchPhase::Started, Vec2::new(50., 50.));
+        touch(&mut app, TouchPhase::Moved, Vec2::new(100., 50.));
+        for _ in 0..7 {
+            app.update();
+        }
+        assert!(!menu_open(&app, target));
+        assert_eq!(menu_count(&mut app), 0);
+    }
+
+    #[test]
+    fn long_press_ignores_virtual_time() {
+        let (mut app, target) = touch_app();
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+
+        touch(&mut app, TouchPhase::Started, Vec2::new(50., 50.));
+        for _ in 0..7 {
+            app.update();
+        }
+        assert!(menu_open(&app, target));
+    }
+}
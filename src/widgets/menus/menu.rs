@@ -1,4 +1,4 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{a11y::accesskit::Role, ecs::entity::EntityHashSet, prelude::*, ui::FocusPolicy};
 
 use sickle_ui_scaffold::prelude::*;
 
@@ -8,20 +8,24 @@ use crate::widgets::layout::{
 };
 
 use super::{
+    context_menu::{ContextMenuUpdate, GenerateContextMenu},
     menu_bar::{MenuBar, UiMenuBarSubExt},
-    menu_item::MenuItem,
+    menu_item::{KeepMenuOpen, MenuItem},
+    menu_navigation::MenuMnemonic,
+    menu_scroll::MenuScroll,
+    submenu::Submenu,
 };
 
 // TODO: Move all z-index constants to a resource
 const MENU_CONTAINER_Z_INDEX: i32 = 100000;
 
-// TODO: Implement scrolling and up/down arrows when menu too large (>70%?)
 pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(Update, MenuUpdate.after(FluxInteractionUpdate))
             .add_plugins(ComponentThemePlugin::<Menu>::default())
+            .add_event::<MenuOpenChanged>()
             .add_systems(
                 Update,
                 (
@@ -31,6 +35,12 @@ impl Plugin for MenuPlugin {
                 )
                     .chain()
                     .in_set(MenuUpdate),
+            )
+            .add_systems(
+                Update,
+                send_menu_open_events
+                    .after(MenuUpdate)
+                    .after(ContextMenuUpdate),
             );
     }
 }
@@ -38,10 +48,22 @@ impl Plugin for MenuPlugin {
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct MenuUpdate;
 
+/// Sent when a menu of a `MenuBar` or a context menu opens or closes
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MenuOpenChanged {
+    /// The `Menu` entity, or the entity the context menu was opened on
+    pub menu: Entity,
+    pub open: bool,
+}
+
+type MenuItemOrSubmenu = Or<(With<MenuItem>, With<Submenu>, With<KeepMenuOpen>)>;
+type SubmenuOrKeepOpen = Or<(With<Submenu>, With<KeepMenuOpen>)>;
+
 fn handle_click_or_touch(
     r_mouse: Res<ButtonInput<MouseButton>>,
     r_touches: Res<Touches>,
-    q_menu_items: Query<(&MenuItem, Ref<FluxInteraction>)>,
+    q_menu_items: Query<Ref<FluxInteraction>, MenuItemOrSubmenu>,
+    q_keep_open: Query<Ref<FluxInteraction>, SubmenuOrKeepOpen>,
     mut q_menus: Query<(Entity, &mut Menu, Ref<FluxInteraction>)>,
 ) {
     if r_mouse.any_just_pressed([MouseButton::Left, MouseButton::Middle, MouseButton::Right])
@@ -51,7 +73,7 @@ fn handle_click_or_touch(
             .iter()
             .any(|(_, _, f)| *f == FluxInteraction::Pressed);
         if !any_pressed {
-            for (_, interaction) in &q_menu_items {
+            for interaction in &q_menu_items {
                 if interaction.is_changed() && *interaction == FluxInteraction::Pressed {
                     return;
                 }
@@ -70,7 +92,10 @@ fn handle_click_or_touch(
         let any_pressed = q_menus
             .iter()
             .any(|(_, _, f)| *f == FluxInteraction::Released);
-        if !any_pressed {
+        let keep_open_released = q_keep_open
+            .iter()
+            .any(|f| f.is_changed() && *f == FluxInteraction::Released);
+        if !any_pressed && !keep_open_released {
             for (_, mut menu, _) in &mut q_menus {
                 menu.is_open = false;
             }
@@ -127,7 +152,7 @@ fn handle_click_or_touch(
 }
 
 fn handle_item_interaction(
-    q_menu_items: Query<&MenuItem, Changed<MenuItem>>,
+    q_menu_items: Query<&MenuItem, (Changed<MenuItem>, Without<KeepMenuOpen>)>,
     mut q_menus: Query<&mut Menu>,
 ) {
     let any_interacted = q_menu_items.iter().any(|item| item.interacted());
@@ -138,6 +163,41 @@ fn handle_item_interaction(
     }
 }
 
+fn send_menu_open_events(
+    q_menus: Query<(Entity, &Menu), Changed<Menu>>,
+    q_context_menus: Query<(Entity, &GenerateContextMenu), Changed<GenerateContextMenu>>,
+    mut removed_menus: RemovedComponents<Menu>,
+    mut removed_context_menus: RemovedComponents<GenerateContextMenu>,
+    mut open_menus: Local<EntityHashSet>,
+    mut writer: EventWriter<MenuOpenChanged>,
+) {
+    let changed = q_menus
+        .iter()
+        .map(|(entity, menu)| (entity, menu.is_open))
+        .chain(
+            q_context_menus
+                .iter()
+                .map(|(entity, gen_menu)| (entity, gen_menu.is_open())),
+        );
+
+    for (menu, open) in changed {
+        let reported = match open {
+            true => open_menus.insert(menu),
+            false => open_menus.remove(&menu),
+        };
+
+        if reported {
+            writer.send(MenuOpenChanged { menu, open });
+        }
+    }
+
+    for menu in removed_menus.read().chain(removed_context_menus.read()) {
+        if open_menus.remove(&menu) {
+            writer.send(MenuOpenChanged { menu, open: false });
+        }
+    }
+}
+
 fn update_menu_container_visibility(
     q_menus: Query<(Entity, &Menu), Changed<Menu>>,
     mut commands: Commands,
@@ -206,10 +266,31 @@ impl Menu {
     pub const CONTAINER: &'static str = "Container";
     pub const LABEL: &'static str = "Label";
 
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn container(&self) -> Entity {
+        self.container
+    }
+
+    pub(crate) fn set_open(&mut self, open: bool) {
+        self.is_open = open;
+    }
+
     pub fn theme() -> Theme<Menu> {
-        let base_theme = PseudoTheme::deferred(None, Menu::primary_style);
-        let open_theme = PseudoTheme::deferred(vec![PseudoState::Open], Menu::open_style);
-        Theme::new(vec![base_theme, open_theme])
+        let base_theme = PseudoTheme::deferred(None, Menu::primary_style).depends_on(
+            ThemeDataSections::COLORS
+                | ThemeDataSections::SPACING
+                | ThemeDataSections::TEXT
+                | ThemeDataSections::ANIMATIONS,
+        );
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style)
+                .depends_on(ThemeDataSections::COLORS);
+        let open_theme = PseudoTheme::deferred(vec![PseudoState::Open], Menu::open_style)
+            .depends_on(ThemeDataSections::COLORS);
+        Theme::new(vec![base_theme, focused_theme, open_theme])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -286,7 +367,7 @@ impl Menu {
         )
     }
 
-    fn container() -> impl Bundle {
+    fn container_bundle() -> impl Bundle {
         (
             Name::new("Container"),
             NodeBundle {
@@ -302,6 +383,8 @@ impl Menu {
                 LockableStyleAttribute::Overflow,
             ]),
             Interaction::default(),
+            Scrollable::default(),
+            MenuScroll::default(),
         )
     }
 }
@@ -311,6 +394,7 @@ pub trait UiMenuExt {
     ///
     /// ### PseudoState usage
     /// - `PseudoState::Open` is used when the menu panel is visible
+    /// - `PseudoState::Focused` is used when the menu is highlighted by keyboard navigation
     fn menu(
         &mut self,
         config: MenuConfig,
@@ -326,10 +410,13 @@ impl UiMenuExt for UiBuilder<'_, Entity> {
     ) -> UiBuilder<Entity> {
         let mut menu = Menu::default();
         let name = format!("Menu [{}]", config.name.clone());
+        let alt_code = config.alt_code;
 
         let button_id = self
             .container(Menu::button(name), |menu_button| {
-                menu.container = menu_button.spawn(Menu::container()).id();
+                menu.container = menu_button
+                    .spawn((Menu::container_bundle(), AccessibleWidget::new(Role::Menu)))
+                    .id();
                 menu.label = menu_button
                     .label(LabelConfig {
                         label: config.name.clone(),
@@ -337,9 +424,19 @@ impl UiMenuExt for UiBuilder<'_, Entity> {
                     })
                     .id();
             })
-            .insert((menu, config))
+            .insert((
+                menu,
+                AccessibleWidget::new(Role::MenuItem).named(config.name.clone()),
+                config,
+            ))
             .id();
 
+        if let Some(key) = alt_code {
+            self.commands()
+                .entity(button_id)
+                .insert(MenuMnemonic::new(key, menu.label));
+        }
+
         let mut menu_builder = self.commands().ui_builder(menu);
         spawn_items(&mut menu_builder);
 
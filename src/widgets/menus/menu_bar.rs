@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{a11y::accesskit::Role, prelude::*};
 
 use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
@@ -23,7 +23,8 @@ impl DefaultTheme for MenuBar {
 
 impl MenuBar {
     pub fn theme() -> Theme<MenuBar> {
-        let base_theme = PseudoTheme::deferred(None, MenuBar::primary_style);
+        let base_theme = PseudoTheme::deferred(None, MenuBar::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
         Theme::new(vec![base_theme])
     }
 
@@ -67,7 +68,13 @@ impl UiMenuBarExt for UiBuilder<'_, Entity> {
         &mut self,
         spawn_children: impl FnOnce(&mut UiBuilder<(Entity, MenuBar)>),
     ) -> UiBuilder<Entity> {
-        let id = self.spawn((MenuBar::frame(), MenuBar)).id();
+        let id = self
+            .spawn((
+                MenuBar::frame(),
+                MenuBar,
+                AccessibleWidget::new(Role::MenuBar),
+            ))
+            .id();
 
         let mut builder = self.commands().ui_builder((id, MenuBar));
         spawn_children(&mut builder);
@@ -1,4 +1,4 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{a11y::accesskit::Role, prelude::*, ui::FocusPolicy};
 
 use sickle_ui_scaffold::prelude::*;
 
@@ -13,6 +13,7 @@ use crate::{
 use super::{
     context_menu::{ContextMenu, ContextMenuUpdate, UiContextMenuExt},
     menu::{Menu, MenuUpdate, UiMenuSubExt},
+    menu_navigation::MenuMnemonic,
     shortcut::Shortcut,
     submenu::{Submenu, SubmenuUpdate, UiSubmenuSubExt},
 };
@@ -65,10 +66,10 @@ fn update_menu_item_on_pressed(
 }
 
 fn update_menu_item_on_shortcut_press(
-    mut q_menu_items: Query<(&mut MenuItem, &Shortcut), Changed<Shortcut>>,
+    mut q_menu_items: Query<(&mut MenuItem, &Shortcut, &FluxInteraction), Changed<Shortcut>>,
 ) {
-    for (mut item, shortcut) in &mut q_menu_items {
-        if shortcut.pressed() && !item.interacted {
+    for (mut item, shortcut, interaction) in &mut q_menu_items {
+        if shortcut.pressed() && !item.interacted && *interaction != FluxInteraction::Disabled {
             item.interacted = true;
         }
     }
@@ -81,8 +82,15 @@ pub struct MenuItemConfig {
     pub trailing_icon: IconData,
     pub alt_code: Option<KeyCode>,
     pub shortcut: Option<Vec<KeyCode>>,
+    /// Keeps the menu open when the item is clicked or activated
+    pub keep_open: bool,
 }
 
+/// Marks menu items that don't close their menu when clicked or activated
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct KeepMenuOpen;
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct MenuItem {
@@ -158,6 +166,11 @@ impl MenuItem {
         self.interacted
     }
 
+    /// Activates the item as if it was clicked
+    pub(crate) fn activate(&mut self) {
+        self.interacted = true;
+    }
+
     pub fn alt_code(&self) -> Option<KeyCode> {
         self.alt_code
     }
@@ -192,7 +205,13 @@ impl MenuItem {
 
     pub fn theme() -> Theme<MenuItem> {
         let base_theme = PseudoTheme::deferred_context(None, MenuItem::primary_style);
-        Theme::new(vec![base_theme])
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style)
+                .depends_on(ThemeDataSections::COLORS);
+        let disabled_theme =
+            PseudoTheme::deferred(vec![PseudoState::Disabled], MenuItem::disabled_style)
+                .depends_on(ThemeDataSections::COLORS);
+        Theme::new(vec![base_theme, focused_theme, disabled_theme])
     }
 
     fn primary_style(
@@ -272,6 +291,24 @@ impl MenuItem {
             .icon(trailing_icon);
     }
 
+    pub(crate) fn focused_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.background_color(colors.container(Container::SurfaceHighest));
+    }
+
+    pub(crate) fn disabled_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(MenuItem::LABEL)
+            .font_color(colors.on(On::Surface).with_alpha(0.38));
+
+        style_builder
+            .switch_target(MenuItem::SHORTCUT)
+            .font_color(colors.on(On::SurfaceVariant).with_alpha(0.38));
+    }
+
     fn button(name: String) -> impl Bundle {
         (
             Name::new(name),
@@ -331,6 +368,7 @@ impl MenuItem {
         };
 
         let name = format!("Menu Item [{}]", config.name.clone());
+        let accessible = AccessibleWidget::new(Role::MenuItem).named(config.name.clone());
         let shortcut_text: String = match &config.shortcut {
             Some(vec) => vec.shortcut_text().into(),
             None => "".into(),
@@ -361,15 +399,27 @@ impl MenuItem {
             menu_item.trailing = container.spawn(MenuItem::trailing_icon_bundle()).id();
         });
 
+        item.insert(accessible);
+        if let Some(key) = config.alt_code {
+            item.insert(MenuMnemonic::new(key, menu_item.label));
+        }
         if let Some(shortcut) = config.shortcut {
             item.insert(Shortcut::new(shortcut));
         }
+        if config.keep_open {
+            item.insert(KeepMenuOpen);
+        }
 
         (item.id(), menu_item)
     }
 }
 
 pub trait UiMenuItemExt {
+    /// A menu item in a menu, context menu, or submenu
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Focused` is used when the item is highlighted by keyboard navigation
+    /// - `PseudoState::Disabled` is used when the item's action is disabled
     fn menu_item(&mut self, config: MenuItemConfig) -> UiBuilder<Entity>;
 }
 
@@ -0,0 +1,682 @@
+use bevy::{
+    ecs::world::CommandQueue,
+    prelude::*,
+    reflect::{DynamicEnum, DynamicVariant, TypeInfo, Typed, VariantInfo},
+    utils::{HashMap, HashSet},
+};
+use serde::{Deserialize, Serialize};
+
+use sickle_ui_scaffold::prelude::*;
+
+use super::{
+    menu::{MenuConfig, UiMenuExt},
+    menu_bar::UiMenuBarExt,
+    menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
+    menu_separators::UiMenuItemSeparatorExt,
+    radio_menu_item::{
+        RadioMenuItem, RadioMenuItemConfig, RadioMenuItemUpdate, UiRadioMenuItemExt,
+    },
+    submenu::{SubmenuConfig, UiSubmenuExt},
+    toggle_menu_item::{
+        ToggleMenuItem, ToggleMenuItemConfig, ToggleMenuItemUpdate, UiToggleMenuItemExt,
+    },
+};
+
+pub struct MenuModelPlugin;
+
+impl Plugin for MenuModelPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            MenuModelUpdate
+                .after(MenuItemUpdate)
+                .after(ToggleMenuItemUpdate)
+                .after(RadioMenuItemUpdate),
+        )
+        .register_type::<MenuAction>()
+        .init_resource::<MenuActions>()
+        .add_event::<ActionTriggered>()
+        .add_systems(
+            Update,
+            (
+                send_menu_action_events,
+                rebuild_menu_models,
+                update_menu_action_states,
+            )
+                .chain()
+                .in_set(MenuModelUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct MenuModelUpdate;
+
+/// Sent when a menu item bound to an action is clicked, activated by keyboard navigation
+/// or by its shortcut. Toggles send it when their value is changed by the user.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct ActionTriggered(pub String);
+
+fn rebuild_menu_models(world: &mut World) {
+    world.resource_scope(|world, actions: Mut<MenuActions>| {
+        let mut q_models =
+            world.query::<(Entity, Ref<MenuModel>, Option<&MenuModelHiddenActions>)>();
+        let rebuilds: Vec<(Entity, MenuModel, MenuModelHiddenActions)> = q_models
+            .iter(world)
+            .filter_map(|(entity, model, hidden)| {
+                let current = MenuModelHiddenActions::of(&model, &actions, world);
+                (model.is_changed() || hidden != Some(&current))
+                    .then(|| (entity, model.clone(), current))
+            })
+            .collect();
+
+        if rebuilds.is_empty() {
+            return;
+        }
+
+        // Toggles and radio options keep their value when the bar is rebuilt
+        let checked: Vec<HashMap<String, bool>> = rebuilds
+            .iter()
+            .map(|(entity, _, _)| checked_actions(*entity, world))
+            .collect();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        for ((entity, model, hidden), checked) in rebuilds.into_iter().zip(checked) {
+            commands.entity(entity).despawn_descendants();
+
+            let mut builder = commands.ui_builder(entity);
+            for menu in model.menus.iter() {
+                if !has_visible_entries(&menu.entries, &hidden) {
+                    continue;
+                }
+
+                builder.menu(
+                    MenuConfig {
+                        name: menu.name.clone(),
+                        alt_code: menu.alt_code,
+                    },
+                    |menu_builder| {
+                        let container = menu_builder.context().container();
+                        let mut container_builder = menu_builder.commands().ui_builder(container);
+                        build_entries(&mut container_builder, &menu.entries, &hidden, &checked);
+                    },
+                );
+            }
+
+            commands.entity(entity).insert(hidden);
+        }
+
+        queue.apply(world);
+    });
+}
+
+/// The checked state of the toggles and radio options built for the model, by action
+fn checked_actions(entity: Entity, world: &mut World) -> HashMap<String, bool> {
+    let mut q_descendants = world.query::<&Children>();
+    let mut q_actions = world.query_filtered::<&MenuAction, CheckableMenuAction>();
+
+    let mut checked = HashMap::new();
+    let mut stack = vec![entity];
+    while let Some(parent) = stack.pop() {
+        let Ok(children) = q_descendants.get(world, parent) else {
+            continue;
+        };
+
+        for child in children.iter() {
+            if let Ok(action) = q_actions.get(world, *child) {
+                checked.insert(action.id.clone(), action.checked);
+            }
+            stack.push(*child);
+        }
+    }
+
+    checked
+}
+
+fn build_entries(
+    builder: &mut UiBuilder<Entity>,
+    entries: &[MenuModelEntry],
+    hidden: &MenuModelHiddenActions,
+    checked: &HashMap<String, bool>,
+) {
+    let mut has_items = false;
+    let mut separator = false;
+    for entry in entries.iter() {
+        if let MenuModelEntry::Separator = entry {
+            separator = has_items;
+            continue;
+        }
+
+        if !entry.is_visible(hidden) {
+            continue;
+        }
+
+        if separator {
+            builder.separator();
+            separator = false;
+        }
+        has_items = true;
+
+        match entry {
+            MenuModelEntry::Item(action) => {
+                builder
+                    .menu_item(MenuItemConfig {
+                        name: action.name.clone(),
+                        leading_icon: action.leading_icon.clone(),
+                        alt_code: action.alt_code,
+                        shortcut: action.shortcut.clone(),
+                        keep_open: action.keep_open,
+                        ..default()
+                    })
+                    .insert(MenuAction::new(action.action.clone()));
+            }
+            MenuModelEntry::Toggle(action) => {
+                let initially_checked = action.checked_in(checked);
+                builder
+                    .toggle_menu_item(ToggleMenuItemConfig {
+                        initially_checked,
+                        ..action.toggle_config()
+                    })
+                    .insert(MenuAction::with_checked(
+                        action.action.clone(),
+                        initially_checked,
+                    ));
+            }
+            MenuModelEntry::RadioGroup { group, options } => {
+                for option in options.iter().filter(|o| !hidden.contains(&o.action)) {
+                    let initially_selected = option.checked_in(checked);
+                    builder
+                        .radio_menu_item(RadioMenuItemConfig {
+                            name: option.name.clone(),
+                            group: group.clone(),
+                            alt_code: option.alt_code,
+                            shortcut: option.shortcut.clone(),
+                            keep_open: option.keep_open,
+                            initially_selected,
+                            ..default()
+                        })
+                        .insert(MenuAction::with_checked(
+                            option.action.clone(),
+                            initially_selected,
+                        ));
+                }
+            }
+            MenuModelEntry::Submenu(submenu) => {
+                builder.submenu(
+                    SubmenuConfig {
+                        name: submenu.name.clone(),
+                        alt_code: submenu.alt_code,
+                        leading_icon: submenu.leading_icon.clone(),
+                    },
+                    |submenu_builder| {
+                        let container = submenu_builder.context().container();
+                        let mut container_builder =
+                            submenu_builder.commands().ui_builder(container);
+                        build_entries(&mut container_builder, &submenu.entries, hidden, checked);
+                    },
+                );
+            }
+            MenuModelEntry::Separator => (),
+        }
+    }
+}
+
+fn has_visible_entries(entries: &[MenuModelEntry], hidden: &MenuModelHiddenActions) -> bool {
+    entries.iter().any(|entry| entry.is_visible(hidden))
+}
+
+type ChangedActionMenuItem = (
+    Changed<MenuItem>,
+    Without<ToggleMenuItem>,
+    Without<RadioMenuItem>,
+);
+type ChangedActionRadioItem = (Changed<RadioMenuItem>, Without<ToggleMenuItem>);
+type CheckableMenuAction = Or<(With<ToggleMenuItem>, With<RadioMenuItem>)>;
+
+fn send_menu_action_events(
+    q_menu_items: Query<(&MenuItem, &MenuAction), ChangedActionMenuItem>,
+    mut q_toggle_items: Query<(&ToggleMenuItem, &mut MenuAction), Changed<ToggleMenuItem>>,
+    mut q_radio_items: Query<(&RadioMenuItem, &mut MenuAction), ChangedActionRadioItem>,
+    mut writer: EventWriter<ActionTriggered>,
+) {
+    for (item, action) in &q_menu_items {
+        if item.interacted() && action.enabled {
+            writer.send(ActionTriggered(action.id.clone()));
+        }
+    }
+
+    for (toggle, mut action) in &mut q_toggle_items {
+        if toggle.checked == action.checked {
+            continue;
+        }
+
+        action.checked = toggle.checked;
+        if action.enabled {
+            writer.send(ActionTriggered(action.id.clone()));
+        }
+    }
+
+    for (radio, mut action) in &mut q_radio_items {
+        if radio.selected == action.checked {
+            continue;
+        }
+
+        action.checked = radio.selected;
+        if radio.selected && action.enabled {
+            writer.send(ActionTriggered(action.id.clone()));
+        }
+    }
+}
+
+fn update_menu_action_states(world: &mut World) {
+    world.resource_scope(|world, actions: Mut<MenuActions>| {
+        let mut q_actions = world.query::<(Entity, &MenuAction)>();
+        let updates: Vec<(Entity, Option<bool>, Option<bool>)> = q_actions
+            .iter(world)
+            .filter_map(|(entity, action)| {
+                let enabled = Some(actions.is_enabled(&action.id, world))
+                    .filter(|enabled| *enabled != action.enabled);
+                let checked = actions
+                    .is_checked(&action.id, world)
+                    .filter(|checked| *checked != action.checked);
+
+                (enabled.is_some() || checked.is_some()).then_some((entity, enabled, checked))
+            })
+            .collect();
+
+        let mut pseudo_states: Vec<(Entity, bool)> = Vec::with_capacity(updates.len());
+        for (entity, enabled, checked) in updates {
+            if let Some(mut action) = world.get_mut::<MenuAction>(entity) {
+                if let Some(enabled) = enabled {
+                    action.enabled = enabled;
+                }
+                if let Some(checked) = checked {
+                    action.checked = checked;
+                }
+            }
+
+            if let Some(enabled) = enabled {
+                if let Some(mut interaction) = world.get_mut::<FluxInteraction>(entity) {
+                    *interaction = match enabled {
+                        true => FluxInteraction::None,
+                        false => FluxInteraction::Disabled,
+                    };
+                }
+                pseudo_states.push((entity, enabled));
+            }
+
+            if let Some(checked) = checked {
+                if let Some(mut toggle) = world.get_mut::<ToggleMenuItem>(entity) {
+                    if toggle.checked != checked {
+                        toggle.checked = checked;
+                    }
+                }
+                if let Some(mut radio) = world.get_mut::<RadioMenuItem>(entity) {
+                    if radio.selected != checked {
+                        radio.selected = checked;
+                    }
+                }
+            }
+        }
+
+        if pseudo_states.is_empty() {
+            return;
+        }
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        for (entity, enabled) in pseudo_states {
+            match enabled {
+                true => commands
+                    .entity(entity)
+                    .remove_pseudo_state(PseudoState::Disabled),
+                false => commands
+                    .entity(entity)
+                    .add_pseudo_state(PseudoState::Disabled),
+            };
+        }
+        queue.apply(world);
+    });
+}
+
+type MenuActionPredicate = Box<dyn Fn(&World) -> bool + Send + Sync>;
+
+/// Predicates evaluated each frame against the World for menu items bound to an action id.
+///
+/// Actions without a predicate are enabled and visible. Toggles keep their own value unless
+/// a `checked_if` predicate is registered for their action.
+#[derive(Resource, Default)]
+pub struct MenuActions {
+    enabled: HashMap<String, MenuActionPredicate>,
+    checked: HashMap<String, MenuActionPredicate>,
+    visible: HashMap<String, MenuActionPredicate>,
+}
+
+impl MenuActions {
+    pub fn enabled_if(
+        &mut self,
+        action: impl Into<String>,
+        predicate: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.enabled.insert(action.into(), Box::new(predicate));
+        self
+    }
+
+    pub fn checked_if(
+        &mut self,
+        action: impl Into<String>,
+        predicate: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.checked.insert(action.into(), Box::new(predicate));
+        self
+    }
+
+    pub fn visible_if(
+        &mut self,
+        action: impl Into<String>,
+        predicate: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.visible.insert(action.into(), Box::new(predicate));
+        self
+    }
+
+    /// Removes all predicates of the action
+    pub fn remove(&mut self, action: &str) -> &mut Self {
+        self.enabled.remove(action);
+        self.checked.remove(action);
+        self.visible.remove(action);
+        self
+    }
+
+    fn is_enabled(&self, action: &str, world: &World) -> bool {
+        self.enabled
+            .get(action)
+            .map_or(true, |predicate| predicate(world))
+    }
+
+    fn is_checked(&self, action: &str, world: &World) -> Option<bool> {
+        self.checked.get(action).map(|predicate| predicate(world))
+    }
+
+    fn is_visible(&self, action: &str, world: &World) -> bool {
+        self.visible
+            .get(action)
+            .map_or(true, |predicate| predicate(world))
+    }
+}
+
+/// Binds a menu item to an action id. Added to items built from a `MenuModel`,
+/// but can be inserted on manually built menu items as well.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct MenuAction {
+    id: String,
+    enabled: bool,
+    checked: bool,
+}
+
+impl MenuAction {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            enabled: true,
+            checked: false,
+        }
+    }
+
+    fn with_checked(id: impl Into<String>, checked: bool) -> Self {
+        Self {
+            checked,
+            ..MenuAction::new(id)
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+struct MenuModelHiddenActions(HashSet<String>);
+
+impl MenuModelHiddenActions {
+    fn of(model: &MenuModel, actions: &MenuActions, world: &World) -> Self {
+        let mut hidden = HashSet::new();
+        for menu in model.menus.iter() {
+            MenuModelHiddenActions::collect(&menu.entries, actions, world, &mut hidden);
+        }
+
+        Self(hidden)
+    }
+
+    fn collect(
+        entries: &[MenuModelEntry],
+        actions: &MenuActions,
+        world: &World,
+        hidden: &mut HashSet<String>,
+    ) {
+        for entry in entries.iter() {
+            match entry {
+                MenuModelEntry::Item(action) | MenuModelEntry::Toggle(action) => {
+                    if !actions.is_visible(&action.action, world) {
+                        hidden.insert(action.action.clone());
+                    }
+                }
+                MenuModelEntry::RadioGroup { options, .. } => {
+                    for option in options.iter() {
+                        if !actions.is_visible(&option.action, world) {
+                            hidden.insert(option.action.clone());
+                        }
+                    }
+                }
+                MenuModelEntry::Submenu(submenu) => {
+                    MenuModelHiddenActions::collect(&submenu.entries, actions, world, hidden);
+                }
+                MenuModelEntry::Separator => (),
+            }
+        }
+    }
+
+    fn contains(&self, action: &str) -> bool {
+        self.0.contains(action)
+    }
+}
+
+/// A declarative description of the menus of a `MenuBar`.
+///
+/// The bar is rebuilt whenever the component changes or the visibility of an action changes.
+/// Toggles and radio options start with their `checked` value and keep their current value
+/// through rebuilds.
+#[derive(Component, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MenuModel {
+    pub menus: Vec<MenuModelMenu>,
+}
+
+impl MenuModel {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MenuModelMenu {
+    pub name: String,
+    #[serde(default, with = "serialize_key_code")]
+    pub alt_code: Option<KeyCode>,
+    #[serde(default)]
+    pub entries: Vec<MenuModelEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MenuModelEntry {
+    Item(MenuModelAction),
+    Toggle(MenuModelAction),
+    RadioGroup {
+        group: String,
+        options: Vec<MenuModelAction>,
+    },
+    Separator,
+    Submenu(MenuModelSubmenu),
+}
+
+impl MenuModelEntry {
+    fn is_visible(&self, hidden: &MenuModelHiddenActions) -> bool {
+        match self {
+            MenuModelEntry::Item(action) | MenuModelEntry::Toggle(action) => {
+                !hidden.contains(&action.action)
+            }
+            MenuModelEntry::RadioGroup { options, .. } => options
+                .iter()
+                .any(|option| !hidden.contains(&option.action)),
+            MenuModelEntry::Separator => false,
+            MenuModelEntry::Submenu(submenu) => has_visible_entries(&submenu.entries, hidden),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MenuModelAction {
+    pub action: String,
+    pub name: String,
+    #[serde(default, with = "serialize_key_code")]
+    pub alt_code: Option<KeyCode>,
+    #[serde(default, with = "serialize_shortcut")]
+    pub shortcut: Option<Vec<KeyCode>>,
+    #[serde(default)]
+    pub leading_icon: IconData,
+    #[serde(default)]
+    pub keep_open: bool,
+    /// Initial value of toggles and radio options, ignored for plain items
+    #[serde(default)]
+    pub checked: bool,
+}
+
+impl MenuModelAction {
+    fn checked_in(&self, checked: &HashMap<String, bool>) -> bool {
+        checked.get(&self.action).copied().unwrap_or(self.checked)
+    }
+
+    fn toggle_config(&self) -> ToggleMenuItemConfig {
+        ToggleMenuItemConfig {
+            name: self.name.clone(),
+            alt_code: self.alt_code,
+            shortcut: self.shortcut.clone(),
+            keep_open: self.keep_open,
+            ..default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MenuModelSubmenu {
+    pub name: String,
+    #[serde(default, with = "serialize_key_code")]
+    pub alt_code: Option<KeyCode>,
+    #[serde(default)]
+    pub leading_icon: IconData,
+    #[serde(default)]
+    pub entries: Vec<MenuModelEntry>,
+}
+
+fn key_code_from_name(name: &str) -> Option<KeyCode> {
+    let TypeInfo::Enum(info) = KeyCode::type_info() else {
+        return None;
+    };
+    let Some(VariantInfo::Unit(_)) = info.variant(name) else {
+        return None;
+    };
+
+    KeyCode::from_reflect(&DynamicEnum::new(name, DynamicVariant::Unit))
+}
+
+/// Key codes are (de)serialized by their variant name, i.e. `"KeyF"`
+mod serialize_key_code {
+    use bevy::{prelude::*, reflect::Enum};
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::key_code_from_name;
+
+    pub fn serialize<S>(key: &Option<KeyCode>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        key.as_ref()
+            .map(|key| key.variant_name())
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<KeyCode>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|name| {
+                key_code_from_name(&name)
+                    .ok_or_else(|| D::Error::custom(format!("Unknown key code: {}", name)))
+            })
+            .transpose()
+    }
+}
+
+mod serialize_shortcut {
+    use bevy::{prelude::*, reflect::Enum};
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::key_code_from_name;
+
+    pub fn serialize<S>(keys: &Option<Vec<KeyCode>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        keys.as_ref()
+            .map(|keys| {
+                keys.iter()
+                    .map(|key| key.variant_name())
+                    .collect::<Vec<&str>>()
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<KeyCode>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Vec<String>>::deserialize(deserializer)?
+            .map(|names| {
+                names
+                    .iter()
+                    .map(|name| {
+                        key_code_from_name(name)
+                            .ok_or_else(|| D::Error::custom(format!("Unknown key code: {}", name)))
+                    })
+                    .collect()
+            })
+            .transpose()
+    }
+}
+
+pub trait UiMenuModelExt {
+    /// A MenuBar built from a `MenuModel`, rebuilt whenever the model changes
+    fn menu_bar_from_model(&mut self, model: MenuModel) -> UiBuilder<Entity>;
+}
+
+impl UiMenuModelExt for UiBuilder<'_, Entity> {
+    fn menu_bar_from_model(&mut self, model: MenuModel) -> UiBuilder<Entity> {
+        let id = self.menu_bar(|_| {}).insert(model).id();
+
+        self.commands().ui_builder(id)
+    }
+}
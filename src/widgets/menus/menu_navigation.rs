@@ -0,0 +1,737 @@
+use bevy::{
+    a11y::Focus,
+    ecs::system::SystemParam,
+    prelude::*,
+    text::TextLayoutInfo,
+    ui::{widget::text_system, UiSystem},
+};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::layout::scroll_view::{ScrollAlignment, ScrollIntoViewExt};
+
+use super::{
+    context_menu::{ContextMenu, ContextMenuUpdate, GenerateContextMenu},
+    menu::{Menu, MenuConfig, MenuUpdate},
+    menu_bar::MenuBar,
+    menu_item::{KeepMenuOpen, MenuItem, MenuItemUpdate},
+    menu_scroll::MenuScroll,
+    radio_menu_item::RadioMenuItem,
+    submenu::{set_submenu_open, Submenu, SubmenuContainer, SubmenuContainerState, SubmenuUpdate},
+    toggle_menu_item::ToggleMenuItem,
+};
+
+const ALT_KEYS: [KeyCode; 2] = [KeyCode::AltLeft, KeyCode::AltRight];
+const MNEMONIC_UNDERLINE_THICKNESS: f32 = 1.;
+
+/// Keyboard handling of menus: `Alt` reveals mnemonics and `Alt` + mnemonic opens menus,
+/// arrow keys move between menus and items, `Enter` activates, `Space` toggles and `Escape`
+/// closes one level at a time.
+pub struct MenuNavigationPlugin;
+
+impl Plugin for MenuNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            MenuNavigationUpdate
+                .after(MenuItemUpdate)
+                .before(MenuUpdate)
+                .before(SubmenuUpdate)
+                .before(ContextMenuUpdate),
+        )
+        .init_resource::<MenuNavigation>()
+        .add_systems(
+            Update,
+            (
+                handle_menu_keyboard_input,
+                update_menu_navigation_highlight,
+                spawn_mnemonic_underlines,
+            )
+                .chain()
+                .in_set(MenuNavigationUpdate),
+        )
+        .add_systems(
+            PostUpdate,
+            update_mnemonic_underlines
+                .after(UiSystem::Layout)
+                .after(text_system)
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct MenuNavigationUpdate;
+
+fn handle_menu_keyboard_input(
+    r_keys: Res<ButtonInput<KeyCode>>,
+    r_mouse: Res<ButtonInput<MouseButton>>,
+    mut r_navigation: ResMut<MenuNavigation>,
+    mut alt_alone: Local<bool>,
+    mut menu_tree: MenuTree,
+) {
+    let alt_pressed = r_keys.any_pressed(ALT_KEYS);
+    if r_keys.any_just_pressed(ALT_KEYS) {
+        *alt_alone = true;
+    } else if r_keys.get_just_pressed().next().is_some()
+        || r_mouse.get_just_pressed().next().is_some()
+    {
+        *alt_alone = false;
+    }
+
+    let levels = menu_tree.levels();
+    let mut highlighted = r_navigation.highlighted;
+
+    // Pointer interaction ends keyboard navigation
+    if r_mouse.get_just_pressed().next().is_some() {
+        highlighted = None;
+    }
+
+    // Drop the highlight once its menu is closed by other means
+    highlighted = highlighted.filter(|entity| match levels.last() {
+        Some(_) => levels
+            .iter()
+            .any(|level| menu_tree.items_of(level.container).contains(entity)),
+        None => menu_tree.menus.contains(*entity),
+    });
+
+    if r_keys.any_just_released(ALT_KEYS) && *alt_alone {
+        *alt_alone = false;
+        highlighted = match levels.is_empty() && highlighted.is_none() {
+            true => menu_tree.first_bar_menu(),
+            false => {
+                menu_tree.close_all();
+                None
+            }
+        };
+    } else if let Some(key) = r_keys
+        .get_just_pressed()
+        .find(|key| !ALT_KEYS.contains(key))
+    {
+        highlighted = menu_tree.handle_key(*key, alt_pressed, &levels, highlighted);
+    }
+
+    let mnemonics_visible = alt_pressed || highlighted.is_some();
+    if r_navigation.highlighted != highlighted {
+        r_navigation.highlighted = highlighted;
+    }
+    if r_navigation.mnemonics_visible != mnemonics_visible {
+        r_navigation.mnemonics_visible = mnemonics_visible;
+    }
+}
+
+fn update_menu_navigation_highlight(
+    r_navigation: Res<MenuNavigation>,
+    r_focus: Option<ResMut<Focus>>,
+    mut previous: Local<Option<Entity>>,
+    q_parent: Query<&Parent>,
+    q_menu_scroll: Query<(), With<MenuScroll>>,
+    mut commands: Commands,
+) {
+    if !r_navigation.is_changed() || *previous == r_navigation.highlighted {
+        return;
+    }
+
+    if let Some(entity) = *previous {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.remove_pseudo_state(PseudoState::Focused);
+        }
+    }
+
+    if let Some(entity) = r_navigation.highlighted {
+        commands
+            .entity(entity)
+            .add_pseudo_state(PseudoState::Focused);
+
+        // Items of menu, submenu and context menu panels are scrolled to as focus moves
+        if let Ok(panel) = q_parent.get(entity) {
+            if q_menu_scroll.contains(panel.get()) {
+                commands
+                    .entity(panel.get())
+                    .scroll_into_view(entity, ScrollAlignment::Nearest);
+            }
+        }
+    }
+
+    if let Some(mut focus) = r_focus {
+        if r_navigation.highlighted.is_some() || focus.0 == *previous {
+            focus.0 = r_navigation.highlighted;
+        }
+    }
+
+    *previous = r_navigation.highlighted;
+}
+
+/// Spawns the underline of new mnemonics next to their label, and despawns the underlines
+/// of removed ones. Text nodes can't have children, so the label text is left as is and the
+/// underline is placed under it after layout.
+fn spawn_mnemonic_underlines(
+    mut q_mnemonics: Query<(Entity, &mut MenuMnemonic), Added<MenuMnemonic>>,
+    q_has_mnemonic: Query<(), With<MenuMnemonic>>,
+    q_underlines: Query<(Entity, &MnemonicUnderline)>,
+    q_parent: Query<&Parent>,
+    mut commands: Commands,
+) {
+    for (entity, underline) in &q_underlines {
+        if q_has_mnemonic.get(underline.mnemonic).is_err() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for (entity, mut mnemonic) in &mut q_mnemonics {
+        let Ok(label_parent) = q_parent.get(mnemonic.label) else {
+            continue;
+        };
+
+        let underline = commands
+            .spawn((
+                Name::new("Mnemonic Underline"),
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        height: Val::Px(MNEMONIC_UNDERLINE_THICKNESS),
+                        ..default()
+                    },
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                MnemonicUnderline { mnemonic: entity },
+            ))
+            .set_parent(label_parent.get())
+            .id();
+        mnemonic.underline = underline.into();
+    }
+}
+
+type MnemonicLabel<'a> = (&'a Node, &'a Transform, &'a Text, &'a TextLayoutInfo);
+
+type MnemonicUnderlineNode<'a> = (
+    &'a mut Transform,
+    &'a mut Style,
+    &'a mut BackgroundColor,
+    &'a mut Visibility,
+);
+
+/// Places the underlines under the mnemonic character of their label while mnemonics are
+/// visible, using the laid out glyphs of the label
+fn update_mnemonic_underlines(
+    r_navigation: Res<MenuNavigation>,
+    r_ui_scale: Res<UiScale>,
+    q_mnemonics: Query<&MenuMnemonic>,
+    q_label: Query<MnemonicLabel>,
+    mut q_underline: Query<MnemonicUnderlineNode, (With<MnemonicUnderline>, Without<Text>)>,
+    q_window: Query<&Window>,
+    ui_windows: UiWindows,
+) {
+    for mnemonic in &q_mnemonics {
+        let Some(Ok((mut transform, mut style, mut background_color, mut visibility))) = mnemonic
+            .underline
+            .map(|underline| q_underline.get_mut(underline))
+        else {
+            continue;
+        };
+
+        let placement = r_navigation
+            .mnemonics_visible
+            .then(|| mnemonic.character())
+            .flatten()
+            .and_then(|character| {
+                let (node, label_transform, text, layout) = q_label.get(mnemonic.label).ok()?;
+                let scale_factor = ui_windows
+                    .window_of(mnemonic.label)
+                    .and_then(|window| q_window.get(window).ok())
+                    .map_or(1., |window| window.scale_factor())
+                    * r_ui_scale.0;
+
+                let (offset, width, color) =
+                    underline_placement(character, text, layout, scale_factor)?;
+                let label_min = label_transform.translation.truncate() - node.size() / 2.;
+                let position = Vec2::new(
+                    label_min.x + offset + width / 2.,
+                    label_min.y + node.size().y - MNEMONIC_UNDERLINE_THICKNESS / 2.,
+                );
+
+                Some((position, width, color))
+            });
+
+        let Some((position, width, color)) = placement else {
+            if *visibility != Visibility::Hidden {
+                *visibility = Visibility::Hidden;
+            }
+
+            continue;
+        };
+
+        // Layout resets the position every frame, before this system runs
+        transform.translation = position.extend(transform.translation.z);
+        if style.width != Val::Px(width) {
+            style.width = Val::Px(width);
+        }
+        if background_color.0 != color {
+            background_color.0 = color;
+        }
+        if *visibility != Visibility::Inherited {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+/// Horizontal offset from the start of the label and width of the first glyph matching
+/// the character, with the color of its text section
+fn underline_placement(
+    character: char,
+    text: &Text,
+    layout: &TextLayoutInfo,
+    scale_factor: f32,
+) -> Option<(f32, f32, Color)> {
+    let (section_index, byte_index) =
+        text.sections
+            .iter()
+            .enumerate()
+            .find_map(|(section_index, section)| {
+                section
+                    .value
+                    .char_indices()
+                    .find(|(_, c)| c.to_ascii_uppercase() == character)
+                    .map(|(byte_index, _)| (section_index, byte_index))
+            })?;
+
+    let glyph = layout
+        .glyphs
+        .iter()
+        .find(|glyph| glyph.section_index == section_index && glyph.byte_index == byte_index)?;
+
+    Some((
+        (glyph.position.x - glyph.size.x / 2.) / scale_factor,
+        glyph.size.x / scale_factor,
+        text.sections[section_index].style.color,
+    ))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MenuLevelKind {
+    Menu,
+    Submenu,
+    ContextMenu,
+}
+
+/// An open menu panel, from the menu bar's menu down to the innermost open submenu
+#[derive(Clone, Copy, Debug)]
+struct MenuLevel {
+    kind: MenuLevelKind,
+    owner: Entity,
+    container: Entity,
+}
+
+#[derive(SystemParam)]
+struct MenuTree<'w, 's> {
+    menus: Query<'w, 's, (Entity, &'static mut Menu, Option<&'static MenuConfig>)>,
+    menu_bars: Query<'w, 's, &'static Children, With<MenuBar>>,
+    submenus: Query<'w, 's, (Entity, &'static Submenu)>,
+    submenu_containers: Query<
+        'w,
+        's,
+        (
+            &'static mut SubmenuContainer,
+            &'static mut SubmenuContainerState,
+        ),
+    >,
+    context_menus: Query<'w, 's, (Entity, &'static ContextMenu)>,
+    context_menu_generators: Query<'w, 's, &'static mut GenerateContextMenu>,
+    menu_items: Query<'w, 's, &'static mut MenuItem>,
+    toggle_menu_items: Query<'w, 's, &'static mut ToggleMenuItem>,
+    radio_menu_items: Query<'w, 's, &'static mut RadioMenuItem>,
+    mnemonics: Query<'w, 's, &'static MenuMnemonic>,
+    interactions: Query<'w, 's, &'static FluxInteraction>,
+    keep_open: Query<'w, 's, (), With<KeepMenuOpen>>,
+    children: Query<'w, 's, &'static Children>,
+    parents: Query<'w, 's, &'static Parent>,
+}
+
+impl MenuTree<'_, '_> {
+    fn levels(&self) -> Vec<MenuLevel> {
+        let root = self
+            .menus
+            .iter()
+            .find(|(_, menu, _)| menu.is_open())
+            .map(|(entity, menu, _)| MenuLevel {
+                kind: MenuLevelKind::Menu,
+                owner: entity,
+                container: menu.container(),
+            })
+            .or_else(|| {
+                self.context_menus
+                    .iter()
+                    .next()
+                    .map(|(entity, _)| MenuLevel {
+                        kind: MenuLevelKind::ContextMenu,
+                        owner: entity,
+                        container: entity,
+                    })
+            });
+
+        let mut levels = Vec::new();
+        let mut next = root;
+        while let Some(level) = next {
+            levels.push(level);
+            next = self
+                .submenus
+                .iter()
+                .find(|(_, submenu)| {
+                    submenu.external_container() == level.container
+                        && self
+                            .submenu_containers
+                            .get(submenu.container())
+                            .is_ok_and(|(container, _)| container.is_open())
+                })
+                .map(|(entity, submenu)| MenuLevel {
+                    kind: MenuLevelKind::Submenu,
+                    owner: entity,
+                    container: submenu.container(),
+                });
+        }
+
+        levels
+    }
+
+    fn items_of(&self, container: Entity) -> Vec<Entity> {
+        let Ok(children) = self.children.get(container) else {
+            return Vec::new();
+        };
+
+        children
+            .iter()
+            .copied()
+            .filter(|child| {
+                self.menu_items.contains(*child)
+                    || self.toggle_menu_items.contains(*child)
+                    || self.radio_menu_items.contains(*child)
+                    || self.submenus.contains(*child)
+            })
+            .collect()
+    }
+
+    fn first_bar_menu(&self) -> Option<Entity> {
+        self.menu_bars
+            .iter()
+            .flat_map(|children| children.iter())
+            .copied()
+            .find(|child| self.menus.contains(*child))
+    }
+
+    /// The menu next to the given one in its menu bar, wrapping around
+    fn sibling_menu(&self, menu: Entity, offset: isize) -> Option<Entity> {
+        let parent = self.parents.get(menu).ok()?.get();
+        let siblings: Vec<Entity> = self
+            .children
+            .get(parent)
+            .ok()?
+            .iter()
+            .copied()
+            .filter(|child| self.menus.contains(*child))
+            .collect();
+
+        let index = siblings.iter().position(|sibling| *sibling == menu)? as isize;
+        let len = siblings.len() as isize;
+
+        Some(siblings[(index + offset).rem_euclid(len) as usize])
+    }
+
+    fn mnemonic_of(&self, entity: Entity) -> Option<KeyCode> {
+        self.mnemonics.get(entity).ok().map(|mnemonic| mnemonic.key)
+    }
+
+    fn menu_with_mnemonic(&self, key: KeyCode) -> Option<Entity> {
+        self.menus
+            .iter()
+            .find(|(entity, _, config)| {
+                config.is_some_and(|config| config.alt_code == Some(key))
+                    && self
+                        .parents
+                        .get(*entity)
+                        .is_ok_and(|parent| self.menu_bars.contains(parent.get()))
+            })
+            .map(|(entity, _, _)| entity)
+    }
+
+    fn open_menu(&mut self, open: Entity) -> Option<Entity> {
+        let mut container = None;
+        for (entity, mut menu, _) in &mut self.menus {
+            if entity == open {
+                container = menu.container().into();
+                if !menu.is_open() {
+                    menu.set_open(true);
+                }
+            } else if menu.is_open() {
+                menu.set_open(false);
+            }
+        }
+
+        self.items_of(container?).first().copied()
+    }
+
+    fn set_submenu_open(&mut self, submenu: Entity, open: bool) -> Option<Entity> {
+        let container = self.submenus.get(submenu).ok()?.1.container();
+        let (mut submenu_container, mut state) = self.submenu_containers.get_mut(container).ok()?;
+        set_submenu_open(&mut submenu_container, &mut state, open);
+
+        self.items_of(container).first().copied()
+    }
+
+    fn close_context_menu(&mut self, context_menu: Entity) {
+        let Ok((_, context_menu)) = self.context_menus.get(context_menu) else {
+            return;
+        };
+
+        if let Ok(mut generator) = self.context_menu_generators.get_mut(context_menu.context()) {
+            generator.close();
+        }
+    }
+
+    fn close_all(&mut self) {
+        for (_, mut menu, _) in &mut self.menus {
+            if menu.is_open() {
+                menu.set_open(false);
+            }
+        }
+
+        for mut generator in &mut self.context_menu_generators {
+            if generator.is_open() {
+                generator.close();
+            }
+        }
+    }
+
+    /// Activates an item and returns the next highlighted entity
+    fn activate(&mut self, item: Entity, toggle_only: bool) -> Option<Entity> {
+        if self.interactions.get(item) == Ok(&FluxInteraction::Disabled) {
+            return item.into();
+        }
+        let keep_open = self.keep_open.contains(item);
+
+        if self.submenus.contains(item) {
+            return self.set_submenu_open(item, true).or(item.into());
+        }
+
+        if let Ok(mut toggle) = self.toggle_menu_items.get_mut(item) {
+            toggle.checked = !toggle.checked;
+            if toggle_only || keep_open {
+                return item.into();
+            }
+        } else if let Ok(mut radio) = self.radio_menu_items.get_mut(item) {
+            if !radio.selected {
+                radio.selected = true;
+            }
+            if toggle_only || keep_open {
+                return item.into();
+            }
+        } else if let Ok(mut menu_item) = self.menu_items.get_mut(item) {
+            menu_item.activate();
+            if keep_open {
+                return item.into();
+            }
+        }
+
+        self.close_all();
+
+        None
+    }
+
+    /// Handles a key press, returning the entity to highlight
+    fn handle_key(
+        &mut self,
+        key: KeyCode,
+        alt_pressed: bool,
+        levels: &[MenuLevel],
+        highlighted: Option<Entity>,
+    ) -> Option<Entity> {
+        let bar_menu = highlighted.filter(|_| levels.is_empty());
+
+        // Mnemonics: Alt prefers menus of the menu bar, otherwise items of the innermost panel
+        if alt_pressed || bar_menu.is_some() {
+            if let Some(menu) = self.menu_with_mnemonic(key) {
+                return self.open_menu(menu).or(menu.into());
+            }
+        }
+        if let Some(level) = levels.last() {
+            let item = self
+                .items_of(level.container)
+                .into_iter()
+                .find(|item| self.mnemonic_of(*item) == Some(key));
+
+            if let Some(item) = item {
+                return self.activate(item, false);
+            }
+        }
+
+        let Some(level) = levels.last().copied() else {
+            let menu = bar_menu?;
+
+            return match key {
+                KeyCode::ArrowLeft => self.sibling_menu(menu, -1),
+                KeyCode::ArrowRight => self.sibling_menu(menu, 1),
+                KeyCode::ArrowDown | KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space => {
+                    self.open_menu(menu).or(menu.into())
+                }
+                KeyCode::Escape => None,
+                _ => menu.into(),
+            };
+        };
+
+        let items = self.items_of(level.container);
+        let current = highlighted.and_then(|entity| items.iter().position(|item| *item == entity));
+        let root = levels[0];
+
+        match key {
+            KeyCode::ArrowDown | KeyCode::ArrowUp if !items.is_empty() => {
+                let len = items.len() as isize;
+                let index = match (current, key) {
+                    (Some(index), KeyCode::ArrowDown) => (index as isize + 1).rem_euclid(len),
+                    (Some(index), _) => (index as isize - 1).rem_euclid(len),
+                    (None, KeyCode::ArrowDown) => 0,
+                    (None, _) => len - 1,
+                };
+
+                items[index as usize].into()
+            }
+            KeyCode::ArrowRight => match current.map(|index| items[index]) {
+                Some(item) if self.submenus.contains(item) => self.set_submenu_open(item, true),
+                _ if root.kind == MenuLevelKind::Menu => {
+                    let next = self.sibling_menu(root.owner, 1)?;
+                    self.open_menu(next).or(next.into())
+                }
+                _ => highlighted,
+            },
+            KeyCode::ArrowLeft => match level.kind {
+                MenuLevelKind::Submenu => {
+                    self.set_submenu_open(level.owner, false);
+                    level.owner.into()
+                }
+                MenuLevelKind::Menu => {
+                    let previous = self.sibling_menu(level.owner, -1)?;
+                    self.open_menu(previous).or(previous.into())
+                }
+                MenuLevelKind::ContextMenu => highlighted,
+            },
+            KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space => {
+                let Some(item) = current.map(|index| items[index]) else {
+                    return highlighted;
+                };
+
+                self.activate(item, key == KeyCode::Space)
+            }
+            KeyCode::Escape => match level.kind {
+                MenuLevelKind::Submenu => {
+                    self.set_submenu_open(level.owner, false);
+                    level.owner.into()
+                }
+                MenuLevelKind::Menu => {
+                    if let Ok((_, mut menu, _)) = self.menus.get_mut(level.owner) {
+                        menu.set_open(false);
+                    }
+                    level.owner.into()
+                }
+                MenuLevelKind::ContextMenu => {
+                    self.close_context_menu(level.owner);
+                    None
+                }
+            },
+            _ => highlighted,
+        }
+    }
+}
+
+/// The state of keyboard navigation in menus
+#[derive(Resource, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct MenuNavigation {
+    highlighted: Option<Entity>,
+    mnemonics_visible: bool,
+}
+
+impl MenuNavigation {
+    /// The menu or menu item highlighted by keyboard navigation
+    pub fn highlighted(&self) -> Option<Entity> {
+        self.highlighted
+    }
+
+    /// Mnemonics are shown while `Alt` is held or the menus are navigated with the keyboard
+    pub fn mnemonics_visible(&self) -> bool {
+        self.mnemonics_visible
+    }
+}
+
+/// The `alt_code` of a menu or menu item, underlined in its label when mnemonics are visible
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct MenuMnemonic {
+    key: KeyCode,
+    label: Entity,
+    underline: Option<Entity>,
+}
+
+impl MenuMnemonic {
+    pub fn new(key: KeyCode, label: Entity) -> Self {
+        Self {
+            key,
+            label,
+            underline: None,
+        }
+    }
+
+    pub fn key(&self) -> KeyCode {
+        self.key
+    }
+
+    /// The character of the label matching the key, if the key is a letter or digit
+    pub fn character(&self) -> Option<char> {
+        let character = match self.key {
+            KeyCode::KeyA => 'A',
+            KeyCode::KeyB => 'B',
+            KeyCode::KeyC => 'C',
+            KeyCode::KeyD => 'D',
+            KeyCode::KeyE => 'E',
+            KeyCode::KeyF => 'F',
+            KeyCode::KeyG => 'G',
+            KeyCode::KeyH => 'H',
+            KeyCode::KeyI => 'I',
+            KeyCode::KeyJ => 'J',
+            KeyCode::KeyK => 'K',
+            KeyCode::KeyL => 'L',
+            KeyCode::KeyM => 'M',
+            KeyCode::KeyN => 'N',
+            KeyCode::KeyO => 'O',
+            KeyCode::KeyP => 'P',
+            KeyCode::KeyQ => 'Q',
+            KeyCode::KeyR => 'R',
+            KeyCode::KeyS => 'S',
+            KeyCode::KeyT => 'T',
+            KeyCode::KeyU => 'U',
+            KeyCode::KeyV => 'V',
+            KeyCode::KeyW => 'W',
+            KeyCode::KeyX => 'X',
+            KeyCode::KeyY => 'Y',
+            KeyCode::KeyZ => 'Z',
+            KeyCode::Digit0 => '0',
+            KeyCode::Digit1 => '1',
+            KeyCode::Digit2 => '2',
+            KeyCode::Digit3 => '3',
+            KeyCode::Digit4 => '4',
+            KeyCode::Digit5 => '5',
+            KeyCode::Digit6 => '6',
+            KeyCode::Digit7 => '7',
+            KeyCode::Digit8 => '8',
+            KeyCode::Digit9 => '9',
+            _ => return None,
+        };
+
+        Some(character)
+    }
+}
+
+/// Underline of a `MenuMnemonic`, despawned with the mnemonic
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+struct MnemonicUnderline {
+    mnemonic: Entity,
+}
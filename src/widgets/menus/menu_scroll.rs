@@ -0,0 +1,619 @@
+use bevy::{input::mouse::MouseScrollUnit, prelude::*, ui::FocusPolicy, utils::HashMap};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::layout::scroll_view::ScrollAlignment;
+
+use super::{context_menu::ContextMenuUpdate, menu::MenuUpdate, submenu::SubmenuUpdate};
+
+/// The share of the window height a menu panel can take before it starts scrolling
+const MENU_MAX_HEIGHT_RATIO: f32 = 0.7;
+/// Seconds between scroll steps while a scroll arrow is hovered
+const MENU_SCROLL_INTERVAL: f32 = 0.08;
+
+pub struct MenuScrollPlugin;
+
+impl Plugin for MenuScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            MenuScrollUpdate
+                .after(ScrollableUpdate)
+                .after(MenuUpdate)
+                .after(SubmenuUpdate)
+                .after(ContextMenuUpdate),
+        )
+        .add_plugins(ComponentThemePlugin::<MenuScrollArrow>::default())
+        .add_systems(
+            Update,
+            (
+                add_menu_scroll_arrows,
+                scroll_menu_on_arrow_hover,
+                scroll_menu_on_scroll,
+                update_menu_scroll,
+            )
+                .chain()
+                .in_set(MenuScrollUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct MenuScrollUpdate;
+
+fn add_menu_scroll_arrows(
+    mut q_scrolls: Query<(Entity, &mut MenuScroll, &Children), Changed<Children>>,
+    mut commands: Commands,
+) {
+    for (entity, mut scroll, children) in &mut q_scrolls {
+        if scroll.up_arrow == Entity::PLACEHOLDER {
+            scroll.up_arrow =
+                MenuScrollArrow::spawn(&mut commands, entity, MenuScrollDirection::Up);
+            scroll.down_arrow =
+                MenuScrollArrow::spawn(&mut commands, entity, MenuScrollDirection::Down);
+        }
+
+        let items: Vec<Entity> = children
+            .iter()
+            .copied()
+            .filter(|child| *child != scroll.up_arrow && *child != scroll.down_arrow)
+            .collect();
+
+        // Keep the arrows at the ends of the panel as items are added
+        if children.first() != Some(&scroll.up_arrow) || children.last() != Some(&scroll.down_arrow)
+        {
+            let mut ordered = Vec::with_capacity(items.len() + 2);
+            ordered.push(scroll.up_arrow);
+            ordered.extend(items);
+            ordered.push(scroll.down_arrow);
+
+            commands.entity(entity).replace_children(&ordered);
+        }
+    }
+}
+
+fn scroll_menu_on_arrow_hover(
+    r_time: Res<Time>,
+    mut q_arrows: Query<(&mut MenuScrollArrow, &FluxInteraction)>,
+    mut q_scrolls: Query<&mut MenuScroll>,
+) {
+    for (mut arrow, interaction) in &mut q_arrows {
+        if *interaction != FluxInteraction::PointerEnter {
+            if arrow.elapsed != 0. {
+                arrow.elapsed = 0.;
+            }
+            continue;
+        }
+
+        let Ok(mut scroll) = q_scrolls.get_mut(arrow.container) else {
+            continue;
+        };
+
+        arrow.elapsed += r_time.delta_seconds();
+        while arrow.elapsed >= MENU_SCROLL_INTERVAL {
+            arrow.elapsed -= MENU_SCROLL_INTERVAL;
+            scroll.step(arrow.direction);
+        }
+    }
+}
+
+/// Takes wheel scroll from the panel's `Scrollable`, the same way scroll view viewports do.
+///
+/// Menu panels can't clip their content, since submenu panels are nested in them, so they
+/// scroll by whole items instead of being scroll views: a line scrolls one item and pixels
+/// scroll an item once they add up to its height. Menus contain their scroll, none of it is
+/// passed on to scroll views below them.
+fn scroll_menu_on_scroll(
+    mut q_scrolls: Query<(&mut MenuScroll, &Scrollable), Changed<Scrollable>>,
+) {
+    for (mut scroll, scrollable) in &mut q_scrolls {
+        let Some((ScrollAxis::Vertical, diff, unit)) = scrollable.last_change() else {
+            continue;
+        };
+
+        if !scroll.scrollable || diff == 0. {
+            continue;
+        }
+
+        match unit {
+            MouseScrollUnit::Line => {
+                let direction = match diff > 0. {
+                    true => MenuScrollDirection::Down,
+                    false => MenuScrollDirection::Up,
+                };
+                for _ in 0..(diff.abs().round() as usize).max(1) {
+                    scroll.step(direction);
+                }
+            }
+            MouseScrollUnit::Pixel => scroll.pending += diff,
+        }
+    }
+}
+
+fn update_menu_scroll(
+    ui_windows: UiWindows,
+    q_window: Query<&Window>,
+    mut q_scrolls: Query<(Entity, &mut MenuScroll, &Children)>,
+    mut q_nodes: Query<(&Node, &mut Style)>,
+) {
+    for (entity, mut scroll, children) in &mut q_scrolls {
+        let Some(window) = ui_windows.window_of(entity) else {
+            continue;
+        };
+        let Ok(window) = q_window.get(window) else {
+            continue;
+        };
+
+        let (up_arrow, down_arrow) = (scroll.up_arrow, scroll.down_arrow);
+        let items: Vec<Entity> = children
+            .iter()
+            .copied()
+            .filter(|child| *child != up_arrow && *child != down_arrow)
+            .collect();
+
+        // Heights are only known while an item is displayed, so they are remembered
+        for item in items.iter() {
+            let Ok((node, style)) = q_nodes.get(*item) else {
+                continue;
+            };
+
+            let height = node.size().y;
+            if style.display != Display::None && height > 0. {
+                let margin = px(style.margin.top) + px(style.margin.bottom);
+                scroll
+                    .bypass_change_detection()
+                    .heights
+                    .insert(*item, height + margin);
+            }
+        }
+        if let Ok((node, _)) = q_nodes.get(up_arrow) {
+            if node.size().y > 0. {
+                scroll.bypass_change_detection().arrow_height = node.size().y;
+            }
+        }
+
+        let heights: Vec<f32> = items
+            .iter()
+            .map(|item| scroll.heights.get(item).copied().unwrap_or(0.))
+            .collect();
+        let limit = window.height() * MENU_MAX_HEIGHT_RATIO;
+        let scrollable = heights.iter().sum::<f32>() > limit;
+
+        let (first, count) = match scrollable {
+            true => {
+                let available = limit - (2. * scroll.arrow_height);
+                let last_offset =
+                    first_fitting(&heights, heights.len().saturating_sub(1), available);
+                let mut offset = scroll.offset.min(last_offset);
+
+                // Pixel scroll moves by an item once it adds up to the item's height
+                let mut pending = scroll.pending;
+                while pending > 0. && offset < last_offset && pending >= heights[offset] {
+                    pending -= heights[offset];
+                    offset += 1;
+                }
+                while pending < 0. && offset > 0 && -pending >= heights[offset - 1] {
+                    pending += heights[offset - 1];
+                    offset -= 1;
+                }
+                // Scroll past the ends is dropped rather than saved up
+                if (pending > 0. && offset == last_offset) || (pending < 0. && offset == 0) {
+                    pending = 0.;
+                }
+                if scroll.pending != pending {
+                    scroll.bypass_change_detection().pending = pending;
+                }
+
+                if let Some((child, alignment)) = scroll.scroll_into_view.take() {
+                    if let Some(index) = items.iter().position(|item| *item == child) {
+                        offset = aligned_offset(&heights, index, offset, available, alignment)
+                            .min(last_offset);
+                    }
+                }
+
+                (offset, visible_count(&heights, offset, available))
+            }
+            false => {
+                // Wait for the items to be measured, the panel may turn out to be scrollable
+                if scroll.scroll_into_view.is_some() && heights.iter().all(|height| *height > 0.) {
+                    scroll.scroll_into_view = None;
+                }
+
+                (0, items.len())
+            }
+        };
+
+        if scroll.offset != first || scroll.scrollable != scrollable {
+            scroll.offset = first;
+            scroll.scrollable = scrollable;
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            let display = match index >= first && index < first + count {
+                true => Display::Flex,
+                false => Display::None,
+            };
+            set_display(&mut q_nodes, *item, display);
+        }
+
+        let arrow_display = match scrollable {
+            true => Display::Flex,
+            false => Display::None,
+        };
+        set_display(&mut q_nodes, up_arrow, arrow_display);
+        set_display(&mut q_nodes, down_arrow, arrow_display);
+    }
+}
+
+fn set_display(q_nodes: &mut Query<(&Node, &mut Style)>, entity: Entity, display: Display) {
+    if let Ok((_, mut style)) = q_nodes.get_mut(entity) {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
+
+fn px(val: Val) -> f32 {
+    match val {
+        Val::Px(value) => value,
+        _ => 0.,
+    }
+}
+
+/// The number of items that fit from `first` on, at least one unless none are left
+fn visible_count(heights: &[f32], first: usize, available: f32) -> usize {
+    let mut used = 0.;
+    let count = heights
+        .iter()
+        .skip(first)
+        .take_while(|height| {
+            used += *height;
+            used <= available
+        })
+        .count();
+
+    count.max(1).min(heights.len().saturating_sub(first))
+}
+
+/// The first item of the longest run that fits and ends with `last`
+fn first_fitting(heights: &[f32], last: usize, available: f32) -> usize {
+    if heights.is_empty() {
+        return 0;
+    }
+
+    let mut used = 0.;
+    let count = heights[..=last]
+        .iter()
+        .rev()
+        .take_while(|height| {
+            used += *height;
+            used <= available
+        })
+        .count();
+
+    last + 1 - count.max(1)
+}
+
+/// The first visible item that places the item at `index` as `alignment` asks
+fn aligned_offset(
+    heights: &[f32],
+    index: usize,
+    offset: usize,
+    available: f32,
+    alignment: ScrollAlignment,
+) -> usize {
+    match alignment {
+        ScrollAlignment::Start => index,
+        ScrollAlignment::End => first_fitting(heights, index, available),
+        ScrollAlignment::Center => {
+            let above = (available - heights[index]) / 2.;
+            let mut used = 0.;
+            let count = heights[..index]
+                .iter()
+                .rev()
+                .take_while(|height| {
+                    used += *height;
+                    used <= above
+                })
+                .count();
+
+            index - count
+        }
+        ScrollAlignment::Nearest => {
+            if index < offset {
+                index
+            } else if index >= offset + visible_count(heights, offset, available) {
+                first_fitting(heights, index, available)
+            } else {
+                offset
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum MenuScrollDirection {
+    #[default]
+    Up,
+    Down,
+}
+
+/// Constrains the height of a menu panel to the window, showing a scrolled range of its items
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct MenuScroll {
+    offset: usize,
+    scrollable: bool,
+    /// Pixel scroll not yet adding up to an item
+    pending: f32,
+    arrow_height: f32,
+    up_arrow: Entity,
+    down_arrow: Entity,
+    #[reflect(ignore)]
+    heights: HashMap<Entity, f32>,
+    scroll_into_view: Option<(Entity, ScrollAlignment)>,
+}
+
+impl Default for MenuScroll {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            scrollable: false,
+            pending: 0.,
+            arrow_height: 0.,
+            up_arrow: Entity::PLACEHOLDER,
+            down_arrow: Entity::PLACEHOLDER,
+            heights: HashMap::default(),
+            scroll_into_view: None,
+        }
+    }
+}
+
+impl MenuScroll {
+    /// The index of the first visible item
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Whether the items overflow the panel
+    pub fn scrollable(&self) -> bool {
+        self.scrollable
+    }
+
+    /// Scrolls the panel until the item is visible, once the panel is laid out.
+    /// Used by the `scroll_into_view` entity command on menu panels.
+    pub fn scroll_into_view(&mut self, item: Entity, alignment: ScrollAlignment) {
+        self.scroll_into_view = Some((item, alignment));
+    }
+
+    fn step(&mut self, direction: MenuScrollDirection) {
+        self.offset = match direction {
+            MenuScrollDirection::Up => self.offset.saturating_sub(1),
+            MenuScrollDirection::Down => self.offset + 1,
+        };
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct MenuScrollArrow {
+    container: Entity,
+    direction: MenuScrollDirection,
+    icon: Entity,
+    elapsed: f32,
+}
+
+impl Default for MenuScrollArrow {
+    fn default() -> Self {
+        Self {
+            container: Entity::PLACEHOLDER,
+            direction: Default::default(),
+            icon: Entity::PLACEHOLDER,
+            elapsed: 0.,
+        }
+    }
+}
+
+impl UiContext for MenuScrollArrow {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            MenuScrollArrow::ICON => Ok(self.icon),
+            _ => Err(format!(
+                "{} doesn't exist for MenuScrollArrow. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [MenuScrollArrow::ICON].into_iter()
+    }
+}
+
+impl DefaultTheme for MenuScrollArrow {
+    fn default_theme() -> Option<Theme<MenuScrollArrow>> {
+        MenuScrollArrow::theme().into()
+    }
+}
+
+impl MenuScrollArrow {
+    pub const ICON: &'static str = "Icon";
+
+    pub fn direction(&self) -> MenuScrollDirection {
+        self.direction
+    }
+
+    pub fn theme() -> Theme<MenuScrollArrow> {
+        let base_theme = PseudoTheme::deferred_context(None, MenuScrollArrow::primary_style)
+            .depends_on(
+                ThemeDataSections::COLORS
+                    | ThemeDataSections::SPACING
+                    | ThemeDataSections::ICONS
+                    | ThemeDataSections::ANIMATIONS,
+            );
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        arrow: &MenuScrollArrow,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let icon = match arrow.direction {
+            MenuScrollDirection::Up => theme_data.icons.expand_less.clone(),
+            MenuScrollDirection::Down => theme_data.icons.expand_more.clone(),
+        };
+
+        style_builder
+            .width(Val::Percent(100.))
+            .justify_content(JustifyContent::Center)
+            .padding(UiRect::vertical(Val::Px(theme_spacing.gaps.tiny)))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHighest).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(MenuScrollArrow::ICON)
+            .size(Val::Px(theme_spacing.icons.small))
+            .icon(icon.with(colors.on(On::SurfaceVariant), theme_spacing.icons.small));
+    }
+
+    fn spawn(commands: &mut Commands, container: Entity, direction: MenuScrollDirection) -> Entity {
+        let mut arrow = MenuScrollArrow {
+            container,
+            direction,
+            ..default()
+        };
+
+        let id = commands
+            .ui_builder(container)
+            .spawn(MenuScrollArrow::frame(direction))
+            .id();
+        arrow.icon = commands
+            .ui_builder(id)
+            .spawn(MenuScrollArrow::icon_bundle())
+            .id();
+        commands.entity(id).insert(arrow);
+
+        id
+    }
+
+    fn frame(direction: MenuScrollDirection) -> impl Bundle {
+        (
+            Name::new(format!("Scroll {:?}", direction)),
+            NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::from_vec(vec![
+                LockableStyleAttribute::Display,
+                LockableStyleAttribute::FocusPolicy,
+            ]),
+            Interaction::default(),
+            TrackedInteraction::default(),
+        )
+    }
+
+    fn icon_bundle() -> impl Bundle {
+        (
+            Name::new("Icon"),
+            ImageBundle {
+                focus_policy: FocusPolicy::Pass,
+                ..default()
+            },
+            LockedStyleAttributes::lock(LockableStyleAttribute::FocusPolicy),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_list_has_nothing_visible() {
+        assert_eq!(visible_count(&[], 0, 40.), 0);
+        assert_eq!(first_fitting(&[], 0, 40.), 0);
+    }
+
+    #[test]
+    fn item_taller_than_the_viewport_is_shown_alone() {
+        let heights = [10., 50., 10.];
+
+        assert_eq!(visible_count(&heights, 0, 40.), 1);
+        assert_eq!(visible_count(&heights, 1, 40.), 1);
+        assert_eq!(first_fitting(&heights, 1, 40.), 1);
+        assert_eq!(first_fitting(&heights, 2, 40.), 2);
+        assert_eq!(
+            aligned_offset(&heights, 1, 0, 40., ScrollAlignment::Nearest),
+            1
+        );
+    }
+
+    #[test]
+    fn scrolling_to_the_last_item_fills_the_viewport() {
+        let heights = [10.; 10];
+
+        // Three items fit, the last run starts at item 7
+        let last_offset = first_fitting(&heights, 9, 35.);
+        assert_eq!(last_offset, 7);
+        assert_eq!(last_offset + visible_count(&heights, last_offset, 35.), 10);
+        assert_eq!(
+            aligned_offset(&heights, 9, 0, 35., ScrollAlignment::Nearest),
+            7
+        );
+        assert_eq!(visible_count(&heights, 9, 35.), 1);
+    }
+
+    #[test]
+    fn aligned_offset_nearest_scrolls_as_little_as_possible() {
+        let heights = [10.; 10];
+
+        // Already visible, from item 2 to item 5
+        assert_eq!(
+            aligned_offset(&heights, 4, 2, 40., ScrollAlignment::Nearest),
+            2
+        );
+        // Above the visible items, becomes the first one
+        assert_eq!(
+            aligned_offset(&heights, 1, 2, 40., ScrollAlignment::Nearest),
+            1
+        );
+        // Below the visible items, becomes the last one
+        assert_eq!(
+            aligned_offset(&heights, 8, 2, 40., ScrollAlignment::Nearest),
+            5
+        );
+    }
+
+    #[test]
+    fn aligned_offset_places_the_item_at_the_alignment() {
+        let heights = [10.; 10];
+
+        assert_eq!(
+            aligned_offset(&heights, 6, 0, 40., ScrollAlignment::Start),
+            6
+        );
+        assert_eq!(aligned_offset(&heights, 6, 0, 40., ScrollAlignment::End), 3);
+        // 15 pixels above the item fit one item
+        assert_eq!(
+            aligned_offset(&heights, 6, 0, 40., ScrollAlignment::Center),
+            5
+        );
+        assert_eq!(
+            aligned_offset(&heights, 0, 4, 40., ScrollAlignment::Center),
+            0
+        );
+    }
+}
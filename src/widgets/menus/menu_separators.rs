@@ -33,7 +33,8 @@ impl DefaultTheme for MenuSeparator {
 
 impl MenuSeparator {
     pub fn theme() -> Theme<MenuSeparator> {
-        let base_theme = PseudoTheme::deferred(None, MenuSeparator::primary_style);
+        let base_theme = PseudoTheme::deferred(None, MenuSeparator::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
         Theme::new(vec![base_theme])
     }
 
@@ -83,7 +84,8 @@ impl DefaultTheme for MenuItemSeparator {
 
 impl MenuItemSeparator {
     pub fn theme() -> Theme<MenuItemSeparator> {
-        let base_theme = PseudoTheme::deferred(None, MenuItemSeparator::primary_style);
+        let base_theme = PseudoTheme::deferred(None, MenuItemSeparator::primary_style)
+            .depends_on(ThemeDataSections::COLORS | ThemeDataSections::SPACING);
         Theme::new(vec![base_theme])
     }
 
@@ -107,6 +109,16 @@ pub trait UiMenuItemSeparatorExt {
     fn separator(&mut self) -> UiBuilder<Entity>;
 }
 
+impl UiMenuItemSeparatorExt for UiBuilder<'_, Entity> {
+    fn separator(&mut self) -> UiBuilder<Entity> {
+        let id = self
+            .spawn((MenuItemSeparator::separator(), MenuItemSeparator))
+            .id();
+
+        self.commands().ui_builder(id)
+    }
+}
+
 impl UiMenuItemSeparatorExt for UiBuilder<'_, Menu> {
     fn separator(&mut self) -> UiBuilder<Entity> {
         let container_id = self.container();
@@ -0,0 +1,417 @@
+use bevy::{a11y::accesskit::Role, prelude::*};
+
+use sickle_ui_scaffold::prelude::*;
+
+use super::{
+    context_menu::{ContextMenu, UiContextMenuExt},
+    menu::{Menu, UiMenuSubExt},
+    menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate},
+    shortcut::Shortcut,
+    submenu::{Submenu, UiSubmenuSubExt},
+};
+
+pub struct RadioMenuItemPlugin;
+
+impl Plugin for RadioMenuItemPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            RadioMenuItemUpdate
+                .after(MenuItemUpdate)
+                .after(FluxInteractionUpdate),
+        )
+        .add_plugins(ComponentThemePlugin::<RadioMenuItem>::default())
+        .add_event::<RadioMenuGroupChanged>()
+        .add_systems(
+            Update,
+            (
+                update_radio_menu_item_value,
+                update_radio_menu_item_on_shortcut_press,
+                update_radio_menu_item_group,
+                update_radio_menu_item_mark,
+            )
+                .chain()
+                .in_set(RadioMenuItemUpdate),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct RadioMenuItemUpdate;
+
+/// Sent when a different option of a radio menu group gets selected
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct RadioMenuGroupChanged {
+    pub group: String,
+    /// The index of the selected option among the group's items in the same panel
+    pub selected: usize,
+    pub item: Entity,
+}
+
+fn update_radio_menu_item_value(
+    mut q_menu_items: Query<(&mut RadioMenuItem, &FluxInteraction), Changed<FluxInteraction>>,
+) {
+    for (mut radio, interaction) in &mut q_menu_items {
+        if interaction.is_pressed() && !radio.selected {
+            radio.selected = true;
+        }
+    }
+}
+
+fn update_radio_menu_item_on_shortcut_press(
+    mut q_menu_items: Query<(&mut RadioMenuItem, &Shortcut, &FluxInteraction), Changed<Shortcut>>,
+) {
+    for (mut radio, shortcut, interaction) in &mut q_menu_items {
+        if shortcut.pressed() && !radio.selected && *interaction != FluxInteraction::Disabled {
+            radio.selected = true;
+        }
+    }
+}
+
+fn update_radio_menu_item_group(
+    mut q_menu_items: Query<(Entity, &mut RadioMenuItem)>,
+    q_parent: Query<&Parent>,
+    q_children: Query<&Children>,
+    mut writer: EventWriter<RadioMenuGroupChanged>,
+) {
+    let mut selected: Vec<(String, Entity)> = Vec::new();
+    for (entity, mut radio) in &mut q_menu_items {
+        if !radio.is_changed() || radio.selected == radio.applied {
+            continue;
+        }
+
+        radio.applied = radio.selected;
+        if radio.selected {
+            selected.push((radio.group.clone(), entity));
+        }
+    }
+
+    // Groups are scoped to the panel, menus using the same group name don't affect each other
+    for (group, item) in selected {
+        let siblings = q_parent
+            .get(item)
+            .ok()
+            .and_then(|parent| q_children.get(parent.get()).ok());
+        let Some(siblings) = siblings else {
+            writer.send(RadioMenuGroupChanged {
+                group,
+                selected: 0,
+                item,
+            });
+            continue;
+        };
+
+        let mut index = 0;
+        let mut position = 0;
+        for sibling in siblings.iter() {
+            let Ok((entity, mut radio)) = q_menu_items.get_mut(*sibling) else {
+                continue;
+            };
+            if radio.group != group {
+                continue;
+            }
+
+            if entity == item {
+                index = position;
+            } else if radio.selected {
+                radio.selected = false;
+                radio.applied = false;
+            }
+            position += 1;
+        }
+
+        writer.send(RadioMenuGroupChanged {
+            group,
+            selected: index,
+            item,
+        });
+    }
+}
+
+fn update_radio_menu_item_mark(
+    q_menu_items: Query<(Entity, &RadioMenuItem), Changed<RadioMenuItem>>,
+    mut commands: Commands,
+) {
+    for (entity, radio) in &q_menu_items {
+        if radio.selected {
+            commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::Checked);
+        } else {
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Checked);
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RadioMenuItemConfig {
+    pub name: String,
+    pub group: String,
+    pub trailing_icon: IconData,
+    pub alt_code: Option<KeyCode>,
+    pub shortcut: Option<Vec<KeyCode>>,
+    /// Keeps the menu open when the item is clicked or activated
+    pub keep_open: bool,
+    pub initially_selected: bool,
+}
+
+impl Into<MenuItemConfig> for RadioMenuItemConfig {
+    fn into(self) -> MenuItemConfig {
+        MenuItemConfig {
+            name: self.name,
+            alt_code: self.alt_code,
+            shortcut: self.shortcut,
+            trailing_icon: self.trailing_icon,
+            keep_open: self.keep_open,
+            ..default()
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct RadioMenuItem {
+    pub selected: bool,
+    group: String,
+    applied: bool,
+    leading: Entity,
+    label: Entity,
+    shortcut_container: Entity,
+    shortcut: Entity,
+    trailing: Entity,
+    trailing_icon: IconData,
+    alt_code: Option<KeyCode>,
+}
+
+impl Default for RadioMenuItem {
+    fn default() -> Self {
+        Self {
+            selected: Default::default(),
+            group: Default::default(),
+            applied: Default::default(),
+            leading: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+            shortcut_container: Entity::PLACEHOLDER,
+            shortcut: Entity::PLACEHOLDER,
+            trailing: Entity::PLACEHOLDER,
+            trailing_icon: Default::default(),
+            alt_code: Default::default(),
+        }
+    }
+}
+
+impl Into<RadioMenuItem> for MenuItem {
+    fn into(self) -> RadioMenuItem {
+        RadioMenuItem {
+            label: self.label(),
+            leading: self.leading(),
+            shortcut_container: self.shortcut_container(),
+            shortcut: self.shortcut(),
+            trailing: self.trailing(),
+            trailing_icon: self.trailing_icon(),
+            alt_code: self.alt_code(),
+            ..default()
+        }
+    }
+}
+
+impl DefaultTheme for RadioMenuItem {
+    fn default_theme() -> Option<Theme<RadioMenuItem>> {
+        RadioMenuItem::theme().into()
+    }
+}
+
+impl UiContext for RadioMenuItem {
+    fn get(&self, target: &str) -> Result<Entity, String> {
+        match target {
+            MenuItem::LEADING_ICON => Ok(self.leading),
+            MenuItem::LABEL => Ok(self.label),
+            MenuItem::SHORTCUT_CONTAINER => Ok(self.shortcut_container),
+            MenuItem::SHORTCUT => Ok(self.shortcut),
+            MenuItem::TRAILING_ICON => Ok(self.trailing),
+            _ => Err(format!(
+                "{} doesn't exist for MenuItem. Possible contexts: {:?}",
+                target,
+                Vec::from_iter(self.contexts())
+            )),
+        }
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> + '_ {
+        [
+            MenuItem::LEADING_ICON,
+            MenuItem::LABEL,
+            MenuItem::SHORTCUT_CONTAINER,
+            MenuItem::SHORTCUT,
+            MenuItem::TRAILING_ICON,
+        ]
+        .into_iter()
+    }
+}
+
+impl RadioMenuItem {
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    pub fn alt_code(&self) -> Option<KeyCode> {
+        self.alt_code
+    }
+
+    pub fn theme() -> Theme<RadioMenuItem> {
+        let base_theme = PseudoTheme::deferred_context(None, RadioMenuItem::primary_style);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style)
+                .depends_on(ThemeDataSections::COLORS);
+        let checked_theme =
+            PseudoTheme::deferred(vec![PseudoState::Checked], RadioMenuItem::checked_style)
+                .depends_on(ThemeDataSections::NONE);
+        let disabled_theme =
+            PseudoTheme::deferred(vec![PseudoState::Disabled], MenuItem::disabled_style)
+                .depends_on(ThemeDataSections::COLORS);
+        Theme::new(vec![
+            base_theme,
+            focused_theme,
+            checked_theme,
+            disabled_theme,
+        ])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        menu_item: &RadioMenuItem,
+        theme_data: &ThemeData,
+    ) {
+        let leading_icon = theme_data.icons.radio_button_checked.clone();
+        let trailing_icon = menu_item.trailing_icon.clone();
+
+        MenuItem::menu_item_style(style_builder, theme_data, leading_icon, trailing_icon);
+
+        style_builder
+            .switch_target(MenuItem::LEADING_ICON)
+            .visibility(Visibility::Hidden);
+    }
+
+    fn checked_style(style_builder: &mut StyleBuilder, _: &ThemeData) {
+        style_builder
+            .switch_target(MenuItem::LEADING_ICON)
+            .visibility(Visibility::Inherited);
+    }
+}
+
+pub trait UiRadioMenuItemExt {
+    /// A radio menu item in a menu, context menu, or submenu. Selecting it deselects
+    /// the other radio menu items of the same group in the same panel.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Checked` is used when the item is selected
+    /// - `PseudoState::Focused` is used when the item is highlighted by keyboard navigation
+    /// - `PseudoState::Disabled` is used when the item's action is disabled
+    fn radio_menu_item(&mut self, config: RadioMenuItemConfig) -> UiBuilder<Entity>;
+}
+
+impl UiRadioMenuItemExt for UiBuilder<'_, Entity> {
+    fn radio_menu_item(&mut self, config: RadioMenuItemConfig) -> UiBuilder<Entity> {
+        let selected = config.initially_selected;
+        let group = config.group.clone();
+        let accessible = AccessibleWidget::new(Role::MenuItemRadio).named(config.name.clone());
+        let (id, menu_item) = MenuItem::scaffold(self, config.into());
+        let radio_item = RadioMenuItem {
+            selected,
+            group,
+            applied: selected,
+            ..menu_item.into()
+        };
+
+        self.commands()
+            .ui_builder(id)
+            .insert((radio_item, accessible));
+        self.commands().ui_builder(id)
+    }
+}
+
+impl UiRadioMenuItemExt for UiBuilder<'_, Menu> {
+    fn radio_menu_item(&mut self, config: RadioMenuItemConfig) -> UiBuilder<Entity> {
+        let container_id = self.container();
+        let id = self
+            .commands()
+            .ui_builder(container_id)
+            .radio_menu_item(config)
+            .id();
+
+        self.commands().ui_builder(id)
+    }
+}
+
+impl UiRadioMenuItemExt for UiBuilder<'_, Submenu> {
+    fn radio_menu_item(&mut self, config: RadioMenuItemConfig) -> UiBuilder<Entity> {
+        let container_id = self.container();
+        let id = self
+            .commands()
+            .ui_builder(container_id)
+            .radio_menu_item(config)
+            .id();
+
+        self.commands().ui_builder(id)
+    }
+}
+
+impl UiRadioMenuItemExt for UiBuilder<'_, ContextMenu> {
+    fn radio_menu_item(&mut self, config: RadioMenuItemConfig) -> UiBuilder<Entity> {
+        let container_id = self.container();
+        let id = self
+            .commands()
+            .ui_builder(container_id)
+            .radio_menu_item(config)
+            .id();
+
+        self.commands().ui_builder(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn radio(world: &mut World, parent: Entity, selected: bool) -> Entity {
+        let item = world
+            .spawn(RadioMenuItem {
+                selected,
+                group: "mode".into(),
+                applied: selected,
+                ..default()
+            })
+            .id();
+        world.entity_mut(parent).add_child(item);
+
+        item
+    }
+
+    #[test]
+    fn selection_only_deselects_the_group_in_the_same_panel() {
+        let mut world = World::new();
+        world.init_resource::<Events<RadioMenuGroupChanged>>();
+        let first_panel = world.spawn_empty().id();
+        let second_panel = world.spawn_empty().id();
+        let first = radio(&mut world, first_panel, true);
+        let second = radio(&mut world, first_panel, false);
+        let other_panel = radio(&mut world, second_panel, true);
+        world.run_system_once(update_radio_menu_item_group);
+
+        world.get_mut::<RadioMenuItem>(second).unwrap().selected = true;
+        world.run_system_once(update_radio_menu_item_group);
+
+        assert!(!world.get::<RadioMenuItem>(first).unwrap().selected);
+        assert!(world.get::<RadioMenuItem>(second).unwrap().selected);
+        assert!(world.get::<RadioMenuItem>(other_panel).unwrap().selected);
+
+        let events = world.resource::<Events<RadioMenuGroupChanged>>();
+        let last = events.iter_current_update_events().last().unwrap();
+        assert_eq!(last.item, second);
+        assert_eq!(last.selected, 1);
+    }
+}
@@ -1,4 +1,4 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{a11y::accesskit::Role, prelude::*, ui::FocusPolicy};
 
 use sickle_ui_scaffold::prelude::*;
 
@@ -6,13 +6,9 @@ use super::{
     context_menu::{ContextMenu, ContextMenuUpdate, UiContextMenuExt},
     menu::{Menu, MenuUpdate, UiMenuSubExt},
     menu_item::{MenuItem, MenuItemConfig},
+    menu_scroll::MenuScroll,
 };
 
-const MENU_CONTAINER_FADE_TIMEOUT: f32 = 1.;
-const MENU_CONTAINER_SWITCH_TIMEOUT: f32 = 0.3;
-
-// TODO: Add vertically scrollable container and height constraint
-// TODO: Best effort position submenu within window bounds
 pub struct SubmenuPlugin;
 
 impl Plugin for SubmenuPlugin {
@@ -24,17 +20,23 @@ impl Plugin for SubmenuPlugin {
                 .before(MenuUpdate)
                 .before(ContextMenuUpdate),
         )
+        .register_type::<SubmenuBehavior>()
+        .init_resource::<SubmenuBehavior>()
+        .init_resource::<SubmenuAim>()
         .add_plugins(ComponentThemePlugin::<Submenu>::default())
         .add_systems(
             Update,
             (
+                update_submenu_aim,
                 unlock_submenu_container_on_menu_interaction,
                 update_submenu_timeout,
                 open_submenu_on_hover,
+                open_submenu_on_press,
                 close_submenus_on_menu_change,
                 update_open_submenu_containers,
                 update_submenu_state,
                 update_submenu_style,
+                position_open_submenu_containers,
             )
                 .chain()
                 .in_set(SubmenuUpdate),
@@ -45,7 +47,115 @@ impl Plugin for SubmenuPlugin {
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct SubmenuUpdate;
 
+/// Timing of submenus opened by hovering their item
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct SubmenuBehavior {
+    /// Seconds an item needs to be hovered before its submenu opens
+    pub open_delay: f32,
+    /// Seconds an open submenu stays visible after the pointer moved back to its parent menu
+    pub close_delay: f32,
+    /// Keeps the open submenu and holds off opening others while the pointer moves
+    /// diagonally towards the open submenu
+    pub aim_prediction: bool,
+    /// Seconds the pointer may rest while aiming before the prediction is dropped
+    pub aim_timeout: f32,
+}
+
+impl Default for SubmenuBehavior {
+    fn default() -> Self {
+        Self {
+            open_delay: 0.3,
+            close_delay: 1.,
+            aim_prediction: true,
+            aim_timeout: 0.3,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+struct SubmenuAim {
+    last_position: Option<Vec2>,
+    aiming: bool,
+    resting: f32,
+}
+
+/// Tracks whether the pointer moves towards the innermost open submenu, i.e. stays in the
+/// triangle between its previous position and the near edge of the submenu panel
+fn update_submenu_aim(
+    r_behavior: Res<SubmenuBehavior>,
+    r_time: Res<Time>,
+    mut r_aim: ResMut<SubmenuAim>,
+    q_window: Query<&Window>,
+    q_containers: Query<(Entity, &SubmenuContainer, &Node, &GlobalTransform, &Parent)>,
+    q_items: Query<&GlobalTransform>,
+) {
+    let cursor = q_window.iter().find_map(Window::cursor_position);
+    let (Some(cursor), true) = (cursor, r_behavior.aim_prediction) else {
+        r_aim.last_position = None;
+        r_aim.aiming = false;
+        return;
+    };
+
+    let Some(last_position) = r_aim.last_position.replace(cursor) else {
+        return;
+    };
+
+    if last_position == cursor {
+        r_aim.resting += r_time.delta_seconds();
+        if r_aim.aiming && r_aim.resting > r_behavior.aim_timeout {
+            r_aim.aiming = false;
+        }
+        return;
+    }
+
+    let mut innermost = q_containers.iter().filter(|(entity, container, ..)| {
+        container.is_open
+            && !q_containers
+                .iter()
+                .any(|(_, other, ..)| other.is_open && other.external_container == *entity)
+    });
+
+    let aiming = innermost.any(|(_, _, node, transform, parent)| {
+        let panel = Rect::from_center_size(transform.translation().truncate(), node.size());
+        if panel.contains(cursor) {
+            return false;
+        }
+
+        let item_x = q_items
+            .get(parent.get())
+            .map_or(panel.min.x, |item| item.translation().x);
+        let edge = match panel.center().x >= item_x {
+            true => panel.min.x,
+            false => panel.max.x,
+        };
+
+        in_triangle(
+            cursor,
+            last_position,
+            Vec2::new(edge, panel.min.y),
+            Vec2::new(edge, panel.max.y),
+        )
+    });
+
+    r_aim.aiming = aiming;
+    r_aim.resting = 0.;
+}
+
+/// Whether `point` is inside or on an edge of the triangle. Degenerate triangles contain nothing.
+fn in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    if (b - a).perp_dot(c - a).abs() <= f32::EPSILON {
+        return false;
+    }
+
+    let side = |from: Vec2, to: Vec2| (to - from).perp_dot(point - from);
+    let (ab, bc, ca) = (side(a, b), side(b, c), side(c, a));
+
+    (ab >= 0. && bc >= 0. && ca >= 0.) || (ab <= 0. && bc <= 0. && ca <= 0.)
+}
+
 fn unlock_submenu_container_on_menu_interaction(
+    r_behavior: Res<SubmenuBehavior>,
     q_external_interaction: Query<Ref<Interaction>>,
     mut q_containers: Query<(&SubmenuContainer, &mut SubmenuContainerState)>,
 ) {
@@ -58,14 +168,17 @@ fn unlock_submenu_container_on_menu_interaction(
             continue;
         };
 
-        if interaction.is_changed() {
+        // Only the pointer moving back to the parent menu unlocks, as lifting a touch clears it
+        if interaction.is_changed() && *interaction == Interaction::Hovered {
             state.is_locked = false;
+            state.timeout = r_behavior.close_delay;
         }
     }
 }
 
 fn update_submenu_timeout(
     r_time: Res<Time>,
+    r_aim: Res<SubmenuAim>,
     mut q_submenus: Query<(
         &mut SubmenuContainer,
         &mut SubmenuContainerState,
@@ -75,8 +188,7 @@ fn update_submenu_timeout(
     for (mut container, mut state, interaction) in &mut q_submenus {
         if *interaction == FluxInteraction::PointerEnter {
             state.is_locked = true;
-            state.timeout = MENU_CONTAINER_FADE_TIMEOUT;
-        } else if !state.is_locked && state.timeout > 0. {
+        } else if !state.is_locked && !r_aim.aiming && state.timeout > 0. {
             state.timeout -= r_time.delta_seconds();
             if container.is_open && state.timeout < 0. {
                 container.is_open = false;
@@ -86,6 +198,8 @@ fn update_submenu_timeout(
 }
 
 fn open_submenu_on_hover(
+    r_behavior: Res<SubmenuBehavior>,
+    r_aim: Res<SubmenuAim>,
     q_submenus: Query<(
         Entity,
         &Submenu,
@@ -94,6 +208,11 @@ fn open_submenu_on_hover(
     )>,
     mut q_containers: Query<(Entity, &mut SubmenuContainer, &mut SubmenuContainerState)>,
 ) {
+    // Items crossed on the way to an open submenu don't open theirs
+    if r_aim.aiming {
+        return;
+    }
+
     let mut opened: Option<(Entity, Entity)> = None;
     for (entity, submenu, interaction, stopwatch) in &q_submenus {
         if *interaction == FluxInteraction::PointerEnter {
@@ -108,10 +227,9 @@ fn open_submenu_on_hover(
             }
 
             // Open submenu once hovered enough
-            if stopwatch.0.elapsed_secs() > MENU_CONTAINER_SWITCH_TIMEOUT {
+            if stopwatch.0.elapsed_secs() > r_behavior.open_delay {
                 container.is_open = true;
                 state.is_locked = true;
-                state.timeout = MENU_CONTAINER_FADE_TIMEOUT;
 
                 opened = (entity, container.external_container).into();
             }
@@ -132,6 +250,26 @@ fn open_submenu_on_hover(
     }
 }
 
+/// Opens submenus right away when pressed, as touch input has no hover
+fn open_submenu_on_press(
+    q_submenus: Query<(&Submenu, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_containers: Query<(&mut SubmenuContainer, &mut SubmenuContainerState)>,
+) {
+    for (submenu, interaction) in &q_submenus {
+        if !interaction.is_pressed() {
+            continue;
+        }
+
+        let Ok((mut container, mut state)) = q_containers.get_mut(submenu.container) else {
+            continue;
+        };
+
+        if !container.is_open {
+            set_submenu_open(&mut container, &mut state, true);
+        }
+    }
+}
+
 fn close_submenus_on_menu_change(
     q_menus: Query<Entity, Changed<Menu>>,
     mut q_submenus: Query<(&mut SubmenuContainer, &mut SubmenuContainerState)>,
@@ -213,6 +351,53 @@ fn update_submenu_style(
     }
 }
 
+/// Places open submenus next to their item, flipping them to the left or shifting them up
+/// when they would overflow the window
+fn position_open_submenu_containers(
+    mut q_containers: Query<(Entity, &SubmenuContainer, &Node, &Parent, &mut Style)>,
+    q_items: Query<(&Node, &GlobalTransform)>,
+    ui_windows: UiWindows,
+    q_window: Query<&Window>,
+) {
+    for (entity, container, node, parent, mut style) in &mut q_containers {
+        if !container.is_open {
+            continue;
+        }
+
+        let Ok((item_node, item_transform)) = q_items.get(parent.get()) else {
+            continue;
+        };
+        let Some(window) = ui_windows.window_of(entity) else {
+            continue;
+        };
+        let Ok(window) = q_window.get(window) else {
+            continue;
+        };
+
+        let size = node.size();
+        let item_size = item_node.size();
+        let item_position = item_transform.translation().truncate() - (item_size / 2.);
+
+        let overflows_right = item_position.x + item_size.x + size.x > window.width();
+        let left = match overflows_right && item_position.x - size.x >= 0. {
+            true => -size.x,
+            false => item_size.x,
+        };
+
+        let top = match item_position.y + size.y > window.height() {
+            true => (window.height() - size.y).max(0.) - item_position.y,
+            false => 0.,
+        };
+
+        if style.left != Val::Px(left) {
+            style.left = Val::Px(left);
+        }
+        if style.top != Val::Px(top) {
+            style.top = Val::Px(top);
+        }
+    }
+}
+
 fn close_containers_of(world: &mut World, external: Entity) {
     let mut q_all_containers = world.query::<(Entity, &mut SubmenuContainer)>();
     let mut containers_closed: Vec<Entity> =
@@ -237,6 +422,18 @@ pub struct SubmenuContainerState {
     is_locked: bool,
 }
 
+/// Opens or closes a submenu container, locking it open until the pointer interacts with
+/// its parent menu
+pub(crate) fn set_submenu_open(
+    container: &mut SubmenuContainer,
+    state: &mut SubmenuContainerState,
+    open: bool,
+) {
+    container.is_open = open;
+    state.is_locked = open;
+    state.timeout = 0.;
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 #[reflect(Component)]
 pub struct SubmenuContainer {
@@ -244,6 +441,12 @@ pub struct SubmenuContainer {
     external_container: Entity,
 }
 
+impl SubmenuContainer {
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
 impl Default for SubmenuContainer {
     fn default() -> Self {
         Self {
@@ -362,11 +565,31 @@ impl UiContext for Submenu {
 impl Submenu {
     pub const MENU_CONTAINER: &'static str = "MenuContainer";
 
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn container(&self) -> Entity {
+        self.container
+    }
+
+    pub fn external_container(&self) -> Entity {
+        self.external_container
+    }
+
     pub fn theme() -> Theme<Submenu> {
         let base_theme = PseudoTheme::deferred_context(None, Submenu::primary_style);
-        let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Submenu::open_style);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style)
+                .depends_on(ThemeDataSections::COLORS);
+        let open_theme = PseudoTheme::deferred(vec![PseudoState::Open], Submenu::open_style)
+            .depends_on(ThemeDataSections::COLORS);
 
-        Theme::new(vec![base_theme, open_theme])
+        let disabled_theme =
+            PseudoTheme::deferred(vec![PseudoState::Disabled], MenuItem::disabled_style)
+                .depends_on(ThemeDataSections::COLORS);
+
+        Theme::new(vec![base_theme, focused_theme, open_theme, disabled_theme])
     }
 
     fn primary_style(
@@ -384,7 +607,6 @@ impl Submenu {
         style_builder
             .switch_target(Submenu::MENU_CONTAINER)
             .position_type(PositionType::Absolute)
-            .top(Val::Px(0.))
             .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
             .padding(UiRect::all(Val::Px(theme_spacing.gaps.small)))
             .flex_direction(FlexDirection::Column)
@@ -398,18 +620,13 @@ impl Submenu {
             .visibility(Visibility::Hidden);
     }
 
-    fn open_style(style_builder: &mut StyleBuilder, entity: Entity, _: &Submenu, world: &World) {
-        let theme_data = world.resource::<ThemeData>().clone();
+    fn open_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
         let colors = theme_data.colors();
 
         style_builder.background_color(colors.container(Container::SurfaceHighest));
 
-        // Unsafe unwrap: if the menu item doesn't have a node, panic!
-        let node = world.get::<Node>(entity).unwrap();
-
         style_builder
             .switch_target(Submenu::MENU_CONTAINER)
-            .left(Val::Px(node.size().x))
             .display(Display::Flex)
             .visibility(Visibility::Inherited);
     }
@@ -427,10 +644,14 @@ impl Submenu {
             },
             LockedStyleAttributes::from_vec(vec![
                 LockableStyleAttribute::FocusPolicy,
+                LockableStyleAttribute::Left,
                 LockableStyleAttribute::Overflow,
+                LockableStyleAttribute::Top,
             ]),
             Interaction::default(),
             TrackedInteraction::default(),
+            Scrollable::default(),
+            MenuScroll::default(),
             SubmenuContainerState::default(),
             SubmenuContainer {
                 external_container,
@@ -455,6 +676,7 @@ pub trait UiSubmenuExt {
     ///
     /// ### PseudoState usage
     /// - `PseudoState::Open` is used when the submenu panel is visible
+    /// - `PseudoState::Focused` is used when the submenu is highlighted by keyboard navigation
     fn submenu(
         &mut self,
         config: SubmenuConfig,
@@ -473,7 +695,10 @@ impl UiSubmenuExt for UiBuilder<'_, Entity> {
         let container = self
             .commands()
             .ui_builder(id)
-            .spawn(Submenu::container_bundle(external_container))
+            .spawn((
+                Submenu::container_bundle(external_container),
+                AccessibleWidget::new(Role::Menu),
+            ))
             .id();
 
         let submenu = Submenu {
@@ -540,3 +765,43 @@ impl UiSubmenuExt for UiBuilder<'_, ContextMenu> {
         self.commands().ui_builder(id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: Vec2 = Vec2::new(0., 0.);
+    const B: Vec2 = Vec2::new(10., 0.);
+    const C: Vec2 = Vec2::new(0., 10.);
+
+    #[test]
+    fn in_triangle_contains_inner_points_in_either_winding() {
+        assert!(in_triangle(Vec2::new(2., 2.), A, B, C));
+        assert!(in_triangle(Vec2::new(2., 2.), A, C, B));
+    }
+
+    #[test]
+    fn in_triangle_excludes_outer_points() {
+        assert!(!in_triangle(Vec2::new(6., 6.), A, B, C));
+        assert!(!in_triangle(Vec2::new(-1., 2.), A, B, C));
+        assert!(!in_triangle(Vec2::new(20., 0.), A, B, C));
+    }
+
+    #[test]
+    fn in_triangle_contains_edges_and_vertices() {
+        assert!(in_triangle(Vec2::new(5., 0.), A, B, C));
+        assert!(in_triangle(Vec2::new(5., 5.), A, B, C));
+        assert!(in_triangle(B, A, B, C));
+    }
+
+    #[test]
+    fn in_triangle_degenerate_contains_nothing() {
+        // Collinear vertices
+        let d = Vec2::new(20., 0.);
+        assert!(!in_triangle(Vec2::new(5., 0.), A, B, d));
+        assert!(!in_triangle(Vec2::new(30., 0.), A, B, d));
+        // Coincident vertices
+        assert!(!in_triangle(A, A, A, A));
+        assert!(!in_triangle(Vec2::new(5., 0.), A, B, B));
+    }
+}
@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{a11y::accesskit::Role, prelude::*};
 
 use sickle_ui_scaffold::prelude::*;
 
@@ -48,10 +48,10 @@ fn update_toggle_menu_item_value(
 }
 
 fn update_toggle_menu_item_on_shortcut_press(
-    mut q_menu_items: Query<(&mut ToggleMenuItem, &Shortcut), Changed<Shortcut>>,
+    mut q_menu_items: Query<(&mut ToggleMenuItem, &Shortcut, &FluxInteraction), Changed<Shortcut>>,
 ) {
-    for (mut toggle, shortcut) in &mut q_menu_items {
-        if shortcut.pressed() {
+    for (mut toggle, shortcut, interaction) in &mut q_menu_items {
+        if shortcut.pressed() && *interaction != FluxInteraction::Disabled {
             toggle.checked = !toggle.checked;
         }
     }
@@ -80,6 +80,8 @@ pub struct ToggleMenuItemConfig {
     pub trailing_icon: IconData,
     pub alt_code: Option<KeyCode>,
     pub shortcut: Option<Vec<KeyCode>>,
+    /// Keeps the menu open when the item is clicked or activated
+    pub keep_open: bool,
     pub initially_checked: bool,
 }
 
@@ -90,6 +92,7 @@ impl Into<MenuItemConfig> for ToggleMenuItemConfig {
             alt_code: self.alt_code,
             shortcut: self.shortcut,
             trailing_icon: self.trailing_icon,
+            keep_open: self.keep_open,
             ..default()
         }
     }
@@ -175,9 +178,21 @@ impl UiContext for ToggleMenuItem {
 impl ToggleMenuItem {
     pub fn theme() -> Theme<ToggleMenuItem> {
         let base_theme = PseudoTheme::deferred_context(None, ToggleMenuItem::primary_style);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Focused], MenuItem::focused_style)
+                .depends_on(ThemeDataSections::COLORS);
         let checked_theme =
-            PseudoTheme::deferred(vec![PseudoState::Checked], ToggleMenuItem::checked_style);
-        Theme::new(vec![base_theme, checked_theme])
+            PseudoTheme::deferred(vec![PseudoState::Checked], ToggleMenuItem::checked_style)
+                .depends_on(ThemeDataSections::NONE);
+        let disabled_theme =
+            PseudoTheme::deferred(vec![PseudoState::Disabled], MenuItem::disabled_style)
+                .depends_on(ThemeDataSections::COLORS);
+        Theme::new(vec![
+            base_theme,
+            focused_theme,
+            checked_theme,
+            disabled_theme,
+        ])
     }
 
     fn primary_style(
@@ -207,19 +222,24 @@ pub trait UiToggleMenuItemExt {
     ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked` is used when the item is checked
+    /// - `PseudoState::Focused` is used when the item is highlighted by keyboard navigation
+    /// - `PseudoState::Disabled` is used when the item's action is disabled
     fn toggle_menu_item(&mut self, config: ToggleMenuItemConfig) -> UiBuilder<Entity>;
 }
 
 impl UiToggleMenuItemExt for UiBuilder<'_, Entity> {
     fn toggle_menu_item(&mut self, config: ToggleMenuItemConfig) -> UiBuilder<Entity> {
         let checked = config.initially_checked;
+        let accessible = AccessibleWidget::new(Role::MenuItemCheckBox).named(config.name.clone());
         let (id, menu_item) = MenuItem::scaffold(self, config.into());
         let toggle_item = ToggleMenuItem {
             checked,
             ..menu_item.into()
         };
 
-        self.commands().ui_builder(id).insert(toggle_item);
+        self.commands()
+            .ui_builder(id)
+            .insert((toggle_item, accessible));
         self.commands().ui_builder(id)
     }
 }